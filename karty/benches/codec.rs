@@ -0,0 +1,95 @@
+//! Compares compact binary codec with postcard (serde) on bridge deals and sequences of plays.
+//! Run with `cargo bench --features serde_postcard --bench codec`.
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use karty::cards::{Card, STANDARD_DECK};
+use karty::codec::{decode_cards_into, decode_deal, decode_set_colex, encode_cards, encode_deal, encode_set_colex};
+use karty::set::CardSetStd;
+use karty::set::serde_repr::AsMask;
+use karty::symbol::compact::Compact;
+
+const DEALS: usize = 1000;
+
+fn shuffled_decks() -> Vec<[Card; 52]>{
+    let mut state = 0x2545f4914f6cdd1du64;
+    (0..DEALS).map(|_| {
+        let mut deck = STANDARD_DECK;
+        for i in (1..deck.len()).rev(){
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            deck.swap(i, state as usize % (i + 1));
+        }
+        deck
+    }).collect()
+}
+
+fn bridge_deals(decks: &[[Card; 52]]) -> Vec<[CardSetStd; 4]>{
+    decks.iter().map(|deck| core::array::from_fn(|h| CardSetStd::from_cards(&deck[h * 13..(h + 1) * 13]))).collect()
+}
+
+fn bench_deals(c: &mut Criterion){
+    let deals = bridge_deals(&shuffled_decks());
+    let mut buffer = [0u8; 256];
+    let mut group = c.benchmark_group("bridge deal");
+    println!("bridge deal bytes: codec {}, postcard (dotted) {}, postcard (mask) {}",
+        encode_deal(&deals[0], &mut buffer).unwrap(),
+        postcard::to_slice(&deals[0], &mut buffer).unwrap().len(),
+        postcard::to_slice(&deals[0].map(AsMask), &mut buffer).unwrap().len());
+
+    group.bench_function("codec encode+decode", |b| b.iter(|| {
+        for deal in &deals{
+            let length = encode_deal(black_box(deal), &mut buffer).unwrap();
+            black_box(decode_deal::<4>(&buffer[..length]).unwrap());
+        }
+    }));
+    group.bench_function("codec colex hands encode+decode", |b| b.iter(|| {
+        for deal in &deals{
+            for hand in deal{
+                let length = encode_set_colex(black_box(hand), &mut buffer).unwrap();
+                black_box(decode_set_colex(&buffer[..length]).unwrap());
+            }
+        }
+    }));
+    group.bench_function("postcard dotted encode+decode", |b| b.iter(|| {
+        for deal in &deals{
+            let serialized = postcard::to_slice(black_box(deal), &mut buffer).unwrap();
+            black_box(postcard::from_bytes::<[CardSetStd; 4]>(serialized).unwrap());
+        }
+    }));
+    group.bench_function("postcard mask encode+decode", |b| b.iter(|| {
+        for deal in &deals{
+            let serialized = postcard::to_slice(&black_box(deal).map(AsMask), &mut buffer).unwrap();
+            black_box(postcard::from_bytes::<[AsMask; 4]>(serialized).unwrap());
+        }
+    }));
+    group.finish();
+}
+
+fn bench_plays(c: &mut Criterion){
+    let decks = shuffled_decks();
+    let mut buffer = [0u8; 512];
+    let mut decoded = [STANDARD_DECK[0]; 52];
+    let mut group = c.benchmark_group("52 plays");
+    println!("52 plays bytes: codec {}, postcard {}, postcard (compact) {}",
+        encode_cards(&decks[0], &mut buffer).unwrap(),
+        postcard::to_slice(&decks[0][..], &mut buffer).unwrap().len(),
+        postcard::to_slice(&decks[0].map(Compact)[..], &mut buffer).unwrap().len());
+
+    group.bench_function("codec encode+decode", |b| b.iter(|| {
+        for deck in &decks{
+            let length = encode_cards(black_box(deck), &mut buffer).unwrap();
+            black_box(decode_cards_into(&buffer[..length], &mut decoded).unwrap());
+        }
+    }));
+    group.bench_function("postcard encode+decode", |b| b.iter(|| {
+        for deck in &decks{
+            let serialized = postcard::to_slice(&black_box(deck)[..], &mut buffer).unwrap();
+            black_box(postcard::from_bytes::<Vec<Card>>(serialized).unwrap());
+        }
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, bench_deals, bench_plays);
+criterion_main!(benches);
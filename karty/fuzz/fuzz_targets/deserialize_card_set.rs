@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use karty::set::CardSetStd;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else { return };
+    if let Ok(set) = ron::from_str::<CardSetStd>(s){
+        let serialized = ron::to_string(&set).expect("Card set serializes to string");
+        assert_eq!(ron::from_str::<CardSetStd>(&serialized), Ok(set));
+        assert_eq!(ron::from_str::<CardSetStd>(&u64::from(set).to_string()), Ok(set));
+    }
+});
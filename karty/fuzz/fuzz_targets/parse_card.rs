@@ -0,0 +1,26 @@
+#![no_main]
+use std::str::FromStr;
+use libfuzzer_sys::fuzz_target;
+use karty::cards::{Card, Card2SymTrait};
+use karty::cards::parse::parse_card;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else { return };
+    match parse_card(s){
+        Ok((rem, card)) => {
+            assert!(rem.len() < s.len() && s.ends_with(rem));
+            assert_eq!(Card::from_str(s), Ok(card));
+            let (figure, suit) = (card.figure(), card.suit());
+            for repr in [
+                format!("{card:#}"),
+                format!("{figure} {suit}"),
+                format!("{suit}{figure}"),
+                format!("{}{suit:#}", figure.repr_char()),
+                card.to_unicode_char().to_string(),
+            ]{
+                assert_eq!(parse_card(&repr), Ok(("", card)), "card formatted as {repr:?}");
+            }
+        },
+        Err(_) => assert!(Card::from_str(s).is_err())
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+use std::str::FromStr;
+use libfuzzer_sys::fuzz_target;
+use karty::set::{CardSet, CardSetStd};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else { return };
+    if let Ok(set) = CardSetStd::from_str(s){
+        assert!(set.len() <= 52);
+        let serialized = ron::to_string(&set).expect("Card set serializes to string");
+        let dotted = serialized.trim_matches('"');
+        assert_eq!(CardSetStd::from_str(dotted), Ok(set), "set formatted as {dotted:?}");
+        assert_eq!(CardSetStd::from_iterator(set.into_iter()), set);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use karty::figures::parse_figure;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else { return };
+    if let Ok((rem, figure)) = parse_figure(s){
+        assert!(rem.len() < s.len() && s.ends_with(rem));
+        for repr in [format!("{figure}"), format!("{figure:#}"), figure.repr_char().to_string()]{
+            assert_eq!(parse_figure(&repr), Ok(("", figure)), "figure formatted as {repr:?}");
+        }
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+use std::str::FromStr;
+use libfuzzer_sys::fuzz_target;
+use karty::suits::parse::parse_suit;
+use karty::suits::Suit;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else { return };
+    match parse_suit(s){
+        Ok((rem, suit)) => {
+            assert!(rem.len() < s.len() && s.ends_with(rem));
+            assert_eq!(Suit::from_str(s), Ok(suit));
+            for repr in [format!("{suit}"), format!("{suit:#}")]{
+                assert_eq!(parse_suit(&repr), Ok(("", suit)), "suit formatted as {repr:?}");
+            }
+        },
+        Err(_) => assert!(Suit::from_str(s).is_err())
+    }
+});
@@ -0,0 +1,203 @@
+//! Module mapping actions of card-play policies to cards. Policy outputs vector of
+//! [`CardSymbol::SYMBOL_SPACE`] scores (logits or probabilities), action index is
+//! [`CardSymbol::usize_index`] of played symbol. Works for every [`CardSymbol`]: standard cards,
+//! [`TarotCard`](crate::tarot::TarotCard) or custom decks.
+//!
+//! Legal action masks use layout of [`encode_set`](crate::encoding::encode_set), elements can be
+//! `bool`, `u8` or `f32` (see [`EncodingElement`]). Batched masks are rows of masks flattened row-major:
+//! shape `[sets.len() * SYMBOL_SPACE]`.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::encoding::{check_length, encode_set, EncodingElement};
+use crate::error::{ActionError, CardError, EncodingError};
+use crate::set::CardSet;
+use crate::symbol::CardSymbol;
+
+#[cfg(feature = "random")]
+mod sampling;
+#[cfg(feature = "random")]
+pub use sampling::*;
+
+/// Index of action playing symbol.
+/// ```
+/// use karty::action::action_index;
+/// use karty::cards::ACE_SPADES;
+/// assert_eq!(action_index(&ACE_SPADES), 51);
+/// ```
+pub fn action_index<S: CardSymbol>(symbol: &S) -> usize{
+    symbol.usize_index()
+}
+
+/// Symbol played by action of given index.
+/// ```
+/// use karty::action::action_symbol;
+/// use karty::cards::{Card, TWO_CLUBS};
+/// use karty::tarot::TarotCard;
+/// assert_eq!(action_symbol::<Card>(0), Ok(TWO_CLUBS));
+/// assert_eq!(action_symbol::<TarotCard>(77), Ok(TarotCard::Excuse));
+/// assert!(action_symbol::<Card>(52).is_err());
+/// ```
+pub fn action_symbol<S: CardSymbol>(index: usize) -> Result<S, CardError>{
+    S::from_usize_index(index)
+}
+
+/// Builds legal action mask from set of legal cards: element is one (`true`) for actions playing legal card.
+/// ```
+/// use karty::action::legal_action_mask;
+/// use karty::card_set;
+/// use karty::cards::*;
+/// let mut mask = [false; 52];
+/// legal_action_mask(&card_set![TWO_HEARTS, ACE_HEARTS], &mut mask).unwrap();
+/// assert!(mask[26] && mask[38]);
+/// assert_eq!(mask.iter().filter(|legal| **legal).count(), 2);
+/// ```
+pub fn legal_action_mask<C: CardSet, T: EncodingElement>(legal: &C, mask: &mut [T]) -> Result<(), EncodingError>{
+    encode_set(legal, mask)
+}
+
+/// Builds legal action mask from legal symbols given by iterator (for symbols without dedicated set type).
+/// ```
+/// use karty::action::legal_action_mask_from_iter;
+/// use karty::suits::Suit;
+/// let mut mask = [0.0f32; 4];
+/// legal_action_mask_from_iter([Suit::Hearts, Suit::Spades], &mut mask).unwrap();
+/// assert_eq!(mask, [0.0, 0.0, 1.0, 1.0]);
+/// ```
+pub fn legal_action_mask_from_iter<S: CardSymbol, T: EncodingElement, I: IntoIterator<Item = S>>(legal: I, mask: &mut [T]) -> Result<(), EncodingError>{
+    check_length(mask, S::SYMBOL_SPACE)?;
+    mask.fill(T::ZERO);
+    for symbol in legal{
+        mask[symbol.usize_index()] = T::ONE;
+    }
+    Ok(())
+}
+
+/// Builds batch of legal action masks, one row of length [`CardSymbol::SYMBOL_SPACE`] for every set.
+/// ```
+/// use karty::action::legal_action_masks;
+/// use karty::card_set;
+/// use karty::cards::*;
+/// let sets = [card_set![TWO_CLUBS], card_set![ACE_SPADES, THREE_CLUBS]];
+/// let mut masks = [0u8; 2 * 52];
+/// legal_action_masks(&sets, &mut masks).unwrap();
+/// assert_eq!((masks[0], masks[52], masks[53], masks[103]), (1, 0, 1, 1));
+/// ```
+pub fn legal_action_masks<C: CardSet, T: EncodingElement>(legal: &[C], masks: &mut [T]) -> Result<(), EncodingError>{
+    let row = C::CardType::SYMBOL_SPACE;
+    check_length(masks, legal.len() * row)?;
+    for (set, mask) in legal.iter().zip(masks.chunks_mut(row)){
+        encode_set(set, mask)?;
+    }
+    Ok(())
+}
+
+/// Builds batch of legal action masks as array of shape `(sets.len(), SYMBOL_SPACE)`.
+/// ```
+/// use karty::action::legal_action_masks_array;
+/// use karty::card_set;
+/// use karty::cards::*;
+/// let masks = legal_action_masks_array::<_, f32>(&[card_set![TWO_CLUBS], card_set![]]);
+/// assert_eq!(masks.dim(), (2, 52));
+/// assert_eq!(masks.sum(), 1.0);
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn legal_action_masks_array<C: CardSet, T: EncodingElement>(legal: &[C]) -> ndarray::Array2<T>{
+    let mut masks = ndarray::Array2::from_elem((legal.len(), C::CardType::SYMBOL_SPACE), T::ZERO);
+    for (set, mut mask) in legal.iter().zip(masks.rows_mut()){
+        let row = mask.as_slice_mut().expect("Rows of standard layout array are contiguous (should not happen, it is a bug).");
+        encode_set(set, row).expect("Row has length of symbol space (should not happen, it is a bug).");
+    }
+    masks
+}
+
+/// Iterates over symbols played by legal actions of mask.
+/// # Returns:
+/// `Err` if mask has wrong length or value other than zero or one.
+/// ```
+/// use karty::action::legal_actions;
+/// use karty::suits::Suit;
+/// let legal: Vec<Suit> = legal_actions(&[1u8, 0, 0, 1]).unwrap().collect();
+/// assert_eq!(legal, vec![Suit::Clubs, Suit::Spades]);
+/// assert!(legal_actions::<Suit, u8>(&[2, 0, 0, 0]).is_err());
+/// ```
+pub fn legal_actions<S: CardSymbol, T: EncodingElement>(mask: &[T]) -> Result<impl Iterator<Item = S> + '_, EncodingError>{
+    check_length(mask, S::SYMBOL_SPACE)?;
+    if let Some(i) = mask.iter().position(|e| *e != T::ONE && *e != T::ZERO){
+        return Err(EncodingError::InvalidValue(i))
+    }
+    Ok(mask.iter().enumerate()
+        .filter(|(_, e)| **e == T::ONE)
+        .filter_map(|(i, _)| S::from_usize_index(i).ok()))
+}
+
+/// Selects legal action with the highest score (e.g. logit), ties are resolved to the lower action index.
+/// ```
+/// use karty::action::masked_argmax;
+/// use karty::cards::*;
+/// use karty::error::ActionError;
+/// let mut logits = [0.0f32; 52];
+/// logits[51] = 5.0;
+/// logits[38] = 2.0;
+/// let mut mask = [false; 52];
+/// mask[38] = true;
+/// mask[0] = true;
+/// assert_eq!(masked_argmax::<Card, _>(&logits, &mask), Ok(ACE_HEARTS));
+/// assert_eq!(masked_argmax::<Card, _>(&logits, &[false; 52]), Err(ActionError::NoLegalAction));
+/// ```
+pub fn masked_argmax<S: CardSymbol, T: EncodingElement>(scores: &[f32], mask: &[T]) -> Result<S, ActionError>{
+    check_length(scores, S::SYMBOL_SPACE)?;
+    let mut best: Option<(usize, f32)> = None;
+    for i in legal_actions::<S, T>(mask)?.map(|s| s.usize_index()){
+        let score = scores[i];
+        if score.is_nan(){
+            return Err(ActionError::InvalidScore(i))
+        }
+        match best{
+            Some((_, b)) if b >= score => (),
+            _ => best = Some((i, score))
+        }
+    }
+    best.map(|(i, _)| S::from_usize_index(i)
+        .expect("Index lesser than symbol space (should not happen, it is a bug)."))
+        .ok_or(ActionError::NoLegalAction)
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::action::{action_index, action_symbol, legal_action_mask, legal_actions, masked_argmax};
+    use crate::cards::{Card, STANDARD_DECK};
+    use crate::error::{ActionError, EncodingError};
+    use crate::set::{CardSet, CardSetStd};
+    use crate::tarot::{TarotCard, TarotCardSet};
+
+    #[test]
+    fn action_round_trip(){
+        for card in STANDARD_DECK{
+            assert_eq!(action_symbol::<Card>(action_index(&card)), Ok(card));
+        }
+    }
+
+    #[test]
+    fn mask_round_trip(){
+        let legal = CardSetStd::from_iterator(STANDARD_DECK.into_iter().step_by(5));
+        let mut mask = [0.0f32; 52];
+        legal_action_mask(&legal, &mut mask).unwrap();
+        assert_eq!(CardSetStd::from_iterator(legal_actions::<Card, _>(&mask).unwrap()), legal);
+        assert_eq!(legal_action_mask(&legal, &mut mask[1..]), Err(EncodingError::WrongBufferLength{expected: 52, found: 51}));
+    }
+
+    #[test]
+    fn tarot_argmax(){
+        let legal = TarotCardSet::from_iterator([TarotCard::Excuse, TarotCard::Trump(3)].into_iter());
+        let mut mask = [false; 78];
+        legal_action_mask(&legal, &mut mask).unwrap();
+        let mut scores = [1.0f32; 78];
+        scores[action_index(&TarotCard::Trump(3))] = 2.0;
+        assert_eq!(masked_argmax::<TarotCard, _>(&scores, &mask), Ok(TarotCard::Trump(3)));
+        scores[action_index(&TarotCard::Excuse)] = f32::NAN;
+        assert_eq!(masked_argmax::<TarotCard, _>(&scores, &mask), Err(ActionError::InvalidScore(77)));
+    }
+}
@@ -0,0 +1,121 @@
+use rand::Rng;
+use crate::encoding::EncodingElement;
+use crate::error::{ActionError, EncodingError};
+use crate::symbol::CardSymbol;
+use super::legal_actions;
+
+/// Samples legal action from probability vector (of length [`CardSymbol::SYMBOL_SPACE`]) restricted by mask.
+/// Probabilities of legal actions are renormalised, probabilities of illegal actions are ignored.
+/// If all legal actions have zero probability, legal action is sampled uniformly.
+/// ```
+/// use karty::action::sample_masked_action;
+/// use karty::cards::*;
+/// use karty::error::ActionError;
+/// let mut probabilities = [0.0f32; 52];
+/// probabilities[51] = 0.9;
+/// probabilities[50] = 0.1;
+/// let mut mask = [false; 52];
+/// mask[50] = true;
+/// mask[0] = true;
+/// let mut rng = rand::rng();
+/// for _ in 0..20{
+///     assert_eq!(sample_masked_action::<Card, _, _>(&probabilities, &mask, &mut rng), Ok(KING_SPADES));
+/// }
+/// assert_eq!(sample_masked_action::<Card, _, _>(&probabilities, &[0u8; 52], &mut rng), Err(ActionError::NoLegalAction));
+/// ```
+pub fn sample_masked_action<S: CardSymbol, T: EncodingElement, R: Rng + ?Sized>(probabilities: &[f32], mask: &[T], rng: &mut R) -> Result<S, ActionError>{
+    if probabilities.len() != S::SYMBOL_SPACE{
+        return Err(EncodingError::WrongBufferLength{expected: S::SYMBOL_SPACE, found: probabilities.len()}.into())
+    }
+    let mut total = 0.0f32;
+    let mut legal_count = 0usize;
+    for i in legal_actions::<S, T>(mask)?.map(|s| s.usize_index()){
+        let p = probabilities[i];
+        if !p.is_finite() || p < 0.0{
+            return Err(ActionError::InvalidProbability(i))
+        }
+        total += p;
+        legal_count += 1;
+    }
+    if legal_count == 0{
+        return Err(ActionError::NoLegalAction)
+    }
+    let mut legal = legal_actions::<S, T>(mask)?;
+    if total <= 0.0{
+        let chosen = rng.random_range(0..legal_count);
+        return Ok(legal.nth(chosen).expect("Chosen index lesser than number of legal actions (should not happen, it is a bug)."))
+    }
+    let threshold = rng.random::<f32>() * total;
+    let mut cumulative = 0.0f32;
+    let mut last = None;
+    for symbol in legal{
+        let p = probabilities[symbol.usize_index()];
+        if p > 0.0{
+            cumulative += p;
+            if threshold < cumulative{
+                return Ok(symbol)
+            }
+            last = Some(symbol);
+        }
+    }
+    // rounding of cumulative sum may leave threshold above it
+    last.ok_or(ActionError::NoLegalAction)
+}
+
+/// Samples legal action for every row of batch: `probabilities` and `masks` are rows of length
+/// [`CardSymbol::SYMBOL_SPACE`] flattened row-major, sampled actions are written to `actions` (one per row).
+/// ```
+/// use karty::action::{legal_action_masks, sample_masked_actions};
+/// use karty::card_set;
+/// use karty::cards::*;
+/// let mut masks = [false; 2 * 52];
+/// legal_action_masks(&[card_set![TWO_CLUBS], card_set![ACE_SPADES]], &mut masks).unwrap();
+/// let probabilities = [1.0 / 52.0; 2 * 52];
+/// let mut actions = [THREE_HEARTS; 2];
+/// sample_masked_actions(&probabilities, &masks, &mut actions, &mut rand::rng()).unwrap();
+/// assert_eq!(actions, [TWO_CLUBS, ACE_SPADES]);
+/// ```
+pub fn sample_masked_actions<S: CardSymbol, T: EncodingElement, R: Rng + ?Sized>(probabilities: &[f32], masks: &[T], actions: &mut [S], rng: &mut R) -> Result<(), ActionError>{
+    let expected = actions.len() * S::SYMBOL_SPACE;
+    for length in [probabilities.len(), masks.len()]{
+        if length != expected{
+            return Err(EncodingError::WrongBufferLength{expected, found: length}.into())
+        }
+    }
+    let rows = probabilities.chunks(S::SYMBOL_SPACE).zip(masks.chunks(S::SYMBOL_SPACE));
+    for (action, (p, mask)) in actions.iter_mut().zip(rows){
+        *action = sample_masked_action(p, mask, rng)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests{
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::action::sample_masked_action;
+    use crate::error::ActionError;
+    use crate::suits::Suit;
+
+    #[test]
+    fn sampling_follows_probabilities(){
+        let mut rng = StdRng::seed_from_u64(7);
+        let probabilities = [0.5, 0.25, 0.0, 0.25];
+        let mask = [true, true, true, false];
+        let mut counts = [0usize; 4];
+        for _ in 0..3000{
+            counts[sample_masked_action::<Suit, _, _>(&probabilities, &mask, &mut rng).unwrap().usize_index()] += 1;
+        }
+        assert_eq!(counts[Suit::Hearts.usize_index()], 0);
+        assert_eq!(counts[Suit::Spades.usize_index()], 0);
+        assert!(counts[Suit::Clubs.usize_index()] > 1800 && counts[Suit::Diamonds.usize_index()] > 800);
+    }
+
+    #[test]
+    fn zero_probabilities_and_invalid_values(){
+        let mut rng = StdRng::seed_from_u64(11);
+        let mask = [0.0f32, 1.0, 0.0, 0.0];
+        assert_eq!(sample_masked_action::<Suit, _, _>(&[0.0; 4], &mask, &mut rng), Ok(Suit::Diamonds));
+        assert_eq!(sample_masked_action::<Suit, _, _>(&[0.0, -1.0, 0.0, 0.0], &mask, &mut rng), Err(ActionError::InvalidProbability(1)));
+    }
+}
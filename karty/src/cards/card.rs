@@ -1,7 +1,7 @@
-use std::cmp::Ordering;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::marker::PhantomData;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::marker::PhantomData;
 use comparator::Comparator;
 use num_integer::div_rem;
 use crate::figures::{FigureTrait};
@@ -66,9 +66,9 @@ pub trait Card2SymTrait: Debug + Clone + Eq + CardSymbol{
 /// # Example of creation:
 /// ```
 /// // First we create our SuitTrait type:
-/// use std::cmp::Ordering;
-/// use std::fmt::{Debug, Formatter};
-/// use std::hash::{Hash, Hasher};
+/// use core::cmp::Ordering;
+/// use core::fmt::{Debug, Formatter};
+/// use core::hash::{Hash, Hasher};
 /// use karty::cards::{Card2SGen, Card2SymTrait};
 /// use karty::error::CardError;
 /// use karty::figures::FigureTrait;
@@ -229,7 +229,7 @@ impl<F: FigureTrait + Copy, S: SuitTrait + Copy> Copy for Card2SGen<F, S>{}
 
 
 impl<F: FigureTrait, S: SuitTrait> Card2SGen<F, S> {
-    pub fn new(figure: F, suit: S) -> Self{
+    pub const fn new(figure: F, suit: S) -> Self{
         Self{suit, figure}
     }
     /* 
@@ -262,6 +262,40 @@ for CardComparatorGen<F, S, CF, CS>{
 impl<F: FigureTrait + Copy, S: SuitTrait + Copy, CS: Comparator<S> + Default, CF: Comparator<F> + Default>
 CardComparatorGen<F, S, CF, CS>{
 
+    pub fn new(suit_comparator: CS, figure_comparator: CF) -> Self{
+        Self{suit_comparator, figure_comparator, _phantom: PhantomData}
+    }
+
+    /// Sorts cards ascending by suit, then by figure.
+    /// ```
+    /// use core::str::FromStr;
+    /// use karty::cards::*;
+    /// use karty::figures::FigureOrder;
+    /// use karty::suits::SuitOrder;
+    /// let order = CardOrder::new(SuitOrder::from_str("SHCD").unwrap(), FigureOrder::from_str("9JQKTA").unwrap());
+    /// let mut cards = vec![ACE_DIAMONDS, TEN_SPADES, KING_SPADES, NINE_CLUBS, ACE_SPADES];
+    /// order.sort_suit_figure(&mut cards);
+    /// assert_eq!(cards, vec![KING_SPADES, TEN_SPADES, ACE_SPADES, NINE_CLUBS, ACE_DIAMONDS]);
+    /// ```
+    pub fn sort_suit_figure(&self, cards: &mut [Card2SGen<F, S>]){
+        cards.sort_unstable_by(|l, r| self.cmp_suit_figure(l, r))
+    }
+
+    /// Sorts cards ascending by figure, then by suit.
+    /// ```
+    /// use core::str::FromStr;
+    /// use karty::cards::*;
+    /// use karty::figures::FigureOrder;
+    /// use karty::suits::SuitOrder;
+    /// let order = CardOrder::new(SuitOrder::from_str("SHCD").unwrap(), FigureOrder::default());
+    /// let mut cards = vec![ACE_DIAMONDS, TEN_SPADES, ACE_SPADES, TEN_DIAMONDS];
+    /// order.sort_figure_suit(&mut cards);
+    /// assert_eq!(cards, vec![TEN_SPADES, TEN_DIAMONDS, ACE_SPADES, ACE_DIAMONDS]);
+    /// ```
+    pub fn sort_figure_suit(&self, cards: &mut [Card2SGen<F, S>]){
+        cards.sort_unstable_by(|l, r| self.cmp_figure_suit(l, r))
+    }
+
 /// ```
 /// use karty::cards::{STANDARD_DECK};
 /// use karty::figures::FigureComparator;
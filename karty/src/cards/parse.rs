@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use nom::branch::alt;
-use nom::character::complete::space0;
+use nom::character::complete::{anychar, space0};
+use nom::combinator::map_opt;
 use nom::{IResult, Parser};
 use nom::sequence::{separated_pair};
 use crate::cards::Card2SGen;
@@ -19,6 +20,10 @@ fn parse_card_fs(s: &str) -> IResult<&str, Card2SGen<Figure, Suit>>{
 
 }
 
+fn parse_card_unicode(s: &str) -> IResult<&str, Card2SGen<Figure, Suit>>{
+    map_opt(anychar, Card2SGen::from_unicode_char).parse(s)
+}
+
 pub fn parse_card_sf(s: &str) -> IResult<&str, Card2SGen<Figure, Suit>> {
     match separated_pair(parse_suit, space0, parse_figure).parse(s) {
         Ok((i, (suit, figure))) => Ok((i, Card2SGen::new(figure, suit))),
@@ -26,7 +31,7 @@ pub fn parse_card_sf(s: &str) -> IResult<&str, Card2SGen<Figure, Suit>> {
     }
 }
 
-/// Parses card from &str (non delimeted way)
+/// Parses card from &str (non delimeted way). Single glyph from Unicode block _Playing Cards_ is accepted as well.
 /// ```
 /// use karty::cards::Card2SGen;
 /// use karty::figures::{Figure, NumberFigure};
@@ -36,10 +41,11 @@ pub fn parse_card_sf(s: &str) -> IResult<&str, Card2SGen<Figure, Suit>> {
 /// assert_eq!(parse_card("10 dxg"), Ok(("xg", Card2SGen::new(Figure::Numbered(NumberFigure::new(10)), Suit::Diamonds))));
 /// assert_eq!(parse_card("A  sdiax"), Ok(("diax", Card2SGen::new(Figure::Ace, Suit::Spades))));
 /// assert_eq!(parse_card("h  jv"), Ok(("v", Card2SGen::new(Figure::Jack, Suit::Hearts))));
+/// assert_eq!(parse_card("🂫🂪"), Ok(("🂪", Card2SGen::new(Figure::Jack, Suit::Spades))));
 /// assert_eq!(parse_card("A10  sdiax"), Err(nom::Err::Error(nom::error::Error::new("A10  sdiax", ErrorKind::Tag))));
 /// ```
 pub fn parse_card(s: &str) -> IResult<&str, Card2SGen<Figure, Suit>> {
-    alt((parse_card_fs, parse_card_sf)).parse(s)
+    alt((parse_card_unicode, parse_card_fs, parse_card_sf)).parse(s)
 }
 /* In case ever needed to publish
 /// Parses card from &str (delimited way)
@@ -72,6 +78,8 @@ pub fn parse_card_delimited(s: &str) -> IResult<&str, Card<FigureStd, SuitStd>>{
 /// assert_eq!(Card2SGen::from_str("4caa"), Ok(FOUR_CLUBS));
 /// assert!(Card2SGen::from_str("jq").is_err());
 /// assert_eq!(Card2SGen::from_str("9♠"), Ok(NINE_SPADES));
+/// assert_eq!(Card2SGen::from_str("🂩"), Ok(NINE_SPADES));
+/// assert_eq!(Card2SGen::from_str(&format!("{ACE_SPADES:#}")), Ok(ACE_SPADES));
 /// ```
 impl FromStr for Card2SGen<Figure, Suit> {
     type Err = CardError;
@@ -95,6 +103,12 @@ mod tests{
         assert_eq!(parse::parse_card_fs("A10  sdiax"), Err(nom::Err::Error(nom::error::Error::new("10  sdiax", ErrorKind::Tag))));
 
     }
+    #[test]
+    fn parse_card_unicode(){
+        assert_eq!(parse::parse_card_unicode("🂡 x"), Ok((" x", ACE_SPADES)));
+        assert_eq!(parse::parse_card_unicode("🃊"), Ok(("", TEN_DIAMONDS)));
+        assert_eq!(parse::parse_card_unicode("🃌"), Err(nom::Err::Error(nom::error::Error::new("🃌", ErrorKind::MapOpt))));
+    }
     /* In case delimited function should be published
     #[test]
     fn parse_card_fs_delimited(){
@@ -103,4 +117,20 @@ mod tests{
         assert_eq!(parse::parse_card_fs_delimited("\tA10  sdiax  "), Err(nom::Err::Error(nom::error::Error::new("10  sdiax  ", ErrorKind::Tag))));
 
     }*/
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptests{
+    use std::str::FromStr;
+    use proptest::prelude::*;
+    use crate::cards::Card;
+
+    proptest!{
+        #[test]
+        fn format_parse_round_trip(card in any::<Card>()){
+            prop_assert_eq!(Card::from_str(&format!("{}{:#}", card.figure.repr_char(), card.suit)), Ok(card));
+            prop_assert_eq!(Card::from_str(&format!("{} {}", card.suit, card.figure.repr_char())), Ok(card));
+            prop_assert_eq!(Card::from_str(&card.to_unicode_char().to_string()), Ok(card));
+        }
+    }
+}
@@ -1,11 +1,13 @@
-use std::fmt::{Display, Formatter};
-use std::marker::PhantomData;
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
 use crate::symbol::CardSymbol;
 use crate::cards::{Card2SGen, CardComparatorGen};
 //use crate::error::CardError::{WrongMaskFormat, WrongPosition};
-use crate::figures::{Ace, FigureComparator, F10, F2, F3, F4, F5, F6, F7, F8, F9, Figure, Jack, King, Queen};
+use crate::error::CardError;
+use crate::figures::{Ace, FigureComparator, FigureOrder, SkatFigureComparator, F10, F2, F3, F4, F5, F6, F7, F8, F9, Figure, Jack, King, Queen};
+use crate::figures::Figure::Numbered;
 use crate::suits::Suit::*;
-use crate::suits::{ComparatorDCHS, ComparatorCDHS, Suit};
+use crate::suits::{ComparatorDCHS, ComparatorCDHS, ComparatorDHSC, Suit, SuitOrder};
 
 use super::Card2SymTrait;
 //#[cfg(feature = "speedy")]
@@ -26,7 +28,7 @@ impl Card2SGen<Figure, Suit>{
     /// }
     /// assert_eq!(bin_sum, 0x0fffffffffffff)
     /// ```
-    pub fn mask(&self) -> u64{
+    pub const fn mask(&self) -> u64{
 
         //self.figure().mask() << (self.suit().position() * 16)
         1u64<<self.usize_index()
@@ -36,7 +38,7 @@ impl Card2SGen<Figure, Suit>{
     /// assert_eq!(Card::from_mask(0x01).unwrap(), TWO_CLUBS);
     /// assert_eq!(Card::from_mask(0x4000000000000).unwrap(), KING_SPADES);
     /// ```
-    pub fn from_mask(mask: u64) -> Option<Self>{
+    pub const fn from_mask(mask: u64) -> Option<Self>{
 
         if mask.count_ones() != 1{
             return None
@@ -44,7 +46,10 @@ impl Card2SGen<Figure, Suit>{
 
 
         let t0 = mask.trailing_zeros();
-        Some(Self::from_usize_index(t0 as usize).unwrap())
+        match Self::from_usize_index(t0 as usize){
+            Ok(card) => Some(card),
+            Err(_) => None
+        }
         /*
         let suit_mask = t0/16;
         let figure_mask = mask >> (suit_mask * 16);
@@ -63,6 +68,111 @@ impl Card2SGen<Figure, Suit>{
 
 
 
+    }
+
+    /// Const version of [`CardSymbol::usize_index`].
+    /// ```
+    /// use karty::cards::ACE_HEARTS;
+    /// const INDEX: usize = ACE_HEARTS.usize_index();
+    /// assert_eq!(INDEX, 38);
+    /// ```
+    pub const fn usize_index(&self) -> usize {
+        self.suit.usize_index() * Figure::SYMBOL_SPACE + self.figure.usize_index()
+    }
+
+    /// Const version of [`CardSymbol::from_usize_index`].
+    /// ```
+    /// use karty::cards::{Card, QUEEN_SPADES};
+    /// const CARD: Card = match Card::from_usize_index(49){
+    ///     Ok(card) => card,
+    ///     Err(_) => panic!()
+    /// };
+    /// assert_eq!(CARD, QUEEN_SPADES);
+    /// assert!(Card::from_usize_index(52).is_err());
+    /// ```
+    pub const fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        let figure = match Figure::from_usize_index(position % Figure::SYMBOL_SPACE){
+            Ok(figure) => figure,
+            Err(e) => return Err(e)
+        };
+        match Suit::from_usize_index(position / Figure::SYMBOL_SPACE){
+            Ok(suit) => Ok(Self{suit, figure}),
+            Err(e) => Err(e)
+        }
+    }
+
+    /// Returns glyph of the card from Unicode block _Playing Cards_ (`U+1F0A0..U+1F0FF`).
+    /// Code points of knights (`U+1F0AC`, `U+1F0BC`, ...) are skipped, as there are no knights in standard deck.
+    /// ```
+    /// use karty::cards::{ACE_SPADES, KING_HEARTS, TEN_DIAMONDS, TWO_CLUBS};
+    /// assert_eq!(ACE_SPADES.to_unicode_char(), '🂡');
+    /// assert_eq!(KING_HEARTS.to_unicode_char(), '🂾');
+    /// assert_eq!(TEN_DIAMONDS.to_unicode_char(), '🃊');
+    /// assert_eq!(TWO_CLUBS.to_unicode_char(), '🃒');
+    /// ```
+    pub fn to_unicode_char(&self) -> char{
+        let figure_offset = match self.figure{
+            Ace => 0x1,
+            Jack => 0xB,
+            Queen => 0xD,
+            King => 0xE,
+            Numbered(n) => n.usize_index() as u32 + 2
+        };
+        char::from_u32(Self::unicode_suit_base(self.suit) + figure_offset)
+            .expect("Playing card code point out of range (should not happen, it is a bug).")
+    }
+
+    /// Reverse method to [`to_unicode_char`](Self::to_unicode_char).
+    /// Returns `None` for characters that are not glyphs of standard cards (including knights, jokers and card back).
+    /// ```
+    /// use karty::cards::{Card, JACK_CLUBS, QUEEN_SPADES};
+    /// assert_eq!(Card::from_unicode_char('🃛'), Some(JACK_CLUBS));
+    /// assert_eq!(Card::from_unicode_char('🂭'), Some(QUEEN_SPADES));
+    /// assert_eq!(Card::from_unicode_char('🂬'), None);
+    /// assert_eq!(Card::from_unicode_char('🂠'), None);
+    /// assert_eq!(Card::from_unicode_char('Q'), None);
+    /// ```
+    pub fn from_unicode_char(c: char) -> Option<Self>{
+        let code = c as u32;
+        let suit = match code & !0xF{
+            0x1F0A0 => Spades,
+            0x1F0B0 => Hearts,
+            0x1F0C0 => Diamonds,
+            0x1F0D0 => Clubs,
+            _ => return None
+        };
+        let figure = match code & 0xF{
+            0x1 => Ace,
+            n @ 0x2..=0xA => Figure::from_usize_index(n as usize - 2).ok()?,
+            0xB => Jack,
+            0xD => Queen,
+            0xE => King,
+            _ => return None
+        };
+        Some(Self{suit, figure})
+    }
+
+    fn unicode_suit_base(suit: Suit) -> u32{
+        match suit{
+            Spades => 0x1F0A0,
+            Hearts => 0x1F0B0,
+            Diamonds => 0x1F0C0,
+            Clubs => 0x1F0D0
+        }
+    }
+}
+
+/// ```
+/// use karty::cards::{Card, NINE_HEARTS};
+/// use karty::error::CardError;
+/// assert_eq!(Card::try_from('🂹'), Ok(NINE_HEARTS));
+/// assert_eq!(Card::try_from('🃟'), Err(CardError::NotPlayingCardChar('🃟')));
+/// ```
+impl TryFrom<char> for Card{
+    type Error = CardError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Self::from_unicode_char(value).ok_or(CardError::NotPlayingCardChar(value))
     }
 }
 
@@ -162,7 +272,7 @@ impl Card2SymTrait for Card{
 
 
 impl Display for Card {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match f.alternate(){
             true => write!(f, "{:#}{:#}", self.figure(), self.suit()),
             false => write!(f, "{} of {}", self.figure(), self.suit())
@@ -186,6 +296,26 @@ pub const CARD_COMPARATOR_VISUAL: ComparatorCardStd<ComparatorDCHS> =
         figure_comparator: FigureComparator {},
         _phantom: PhantomData{}
     };
+/// Comparator of cards with suit and figure orders chosen at runtime.
+pub type CardOrder = CardComparatorGen<Figure, Suit, FigureOrder, SuitOrder>;
+
+/// Comparator of cards in Skat suit and grand games. Used with
+/// [`cmp_figure_suit`](CardComparatorGen::cmp_figure_suit) orders jacks on top
+/// (`♣J > ♠J > ♥J > ♦J`), followed by aces, tens, kings, queens, nines, eights and sevens.
+/// ```
+/// use karty::cards::*;
+/// use core::cmp::Ordering;
+/// let cmp = CARD_COMPARATOR_SKAT;
+/// assert_eq!(cmp.cmp_figure_suit(&JACK_CLUBS, &JACK_SPADES), Ordering::Greater);
+/// assert_eq!(cmp.cmp_figure_suit(&JACK_DIAMONDS, &ACE_CLUBS), Ordering::Greater);
+/// assert_eq!(cmp.cmp_suit_figure(&TEN_HEARTS, &KING_HEARTS), Ordering::Greater);
+/// ```
+pub const CARD_COMPARATOR_SKAT: CardComparatorGen<Figure, Suit, SkatFigureComparator, ComparatorDHSC> =
+    CardComparatorGen {
+        suit_comparator: ComparatorDHSC {},
+        figure_comparator: SkatFigureComparator {},
+        _phantom: PhantomData{}
+    };
 
 //pub const TWO_CLUBS: Card<FigureStd, SuitStd> = Card { suit: SuitStd::Clubs, figure: Numbered(F2)};
 
@@ -374,7 +504,7 @@ pub const MASK_SPADES: u64 =    0x000fff8000000000;
 
 #[cfg(test)]
 mod tests{
-    use crate::symbol::CardSymbol;
+    use std::format;
     use crate::cards::standard::{ *};
 
     #[test]
@@ -392,6 +522,16 @@ mod tests{
         assert_eq!(Card::from_usize_index(51).unwrap(), ACE_SPADES);
     }
 
+    #[test]
+    fn unicode_round_trip(){
+        for card in STANDARD_DECK{
+            assert_eq!(Card::try_from(card.to_unicode_char()), Ok(card));
+        }
+        for knight in ['🂬', '🂼', '🃌', '🃜']{
+            assert!(Card::try_from(knight).is_err());
+        }
+    }
+
     #[test]
     #[cfg(feature = "speedy")]
     fn test_speedy_card(){
@@ -426,4 +566,22 @@ where SuitOrder: Fn(&SuitStd, &SuitStd) -> Ordering, FigureOrder: Fn(&FigureStd,
     }
 }*/
 
+#[cfg(all(test, feature = "proptest"))]
+mod proptests{
+    use proptest::prelude::*;
+    use crate::cards::Card;
+
+    proptest!{
+        #[test]
+        fn mask_round_trip(card in any::<Card>()){
+            prop_assert_eq!(card.mask().count_ones(), 1);
+            prop_assert_eq!(Card::from_mask(card.mask()), Some(card));
+            prop_assert_eq!(card.mask(), 1u64 << card.usize_index());
+        }
 
+        #[test]
+        fn unicode_round_trip(card in any::<Card>()){
+            prop_assert_eq!(Card::from_unicode_char(card.to_unicode_char()), Some(card));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// Packs values of given bit width into bytes, least significant bits first.
+pub(crate) struct BitWriter<'a>{
+    buffer: &'a mut [u8],
+    position: usize,
+    accumulator: u64,
+    pending: u32,
+}
+
+impl<'a> BitWriter<'a>{
+    pub(crate) fn new(buffer: &'a mut [u8]) -> Self{
+        Self{buffer, position: 0, accumulator: 0, pending: 0}
+    }
+
+    /// Writes `bits` lowest bits of value (at most 32).
+    pub(crate) fn write(&mut self, value: u32, bits: u32){
+        self.accumulator |= (value as u64) << self.pending;
+        self.pending += bits;
+        while self.pending >= 8{
+            self.buffer[self.position] = self.accumulator as u8;
+            self.position += 1;
+            self.accumulator >>= 8;
+            self.pending -= 8;
+        }
+    }
+
+    /// Flushes remaining bits (padded with zeros), returns number of written bytes.
+    pub(crate) fn finish(self) -> usize{
+        match self.pending{
+            0 => self.position,
+            _ => {
+                self.buffer[self.position] = self.accumulator as u8;
+                self.position + 1
+            }
+        }
+    }
+}
+
+/// Reads values packed by [`BitWriter`].
+pub(crate) struct BitReader<'a>{
+    buffer: &'a [u8],
+    position: usize,
+    accumulator: u64,
+    available: u32,
+}
+
+impl<'a> BitReader<'a>{
+    pub(crate) fn new(buffer: &'a [u8]) -> Self{
+        Self{buffer, position: 0, accumulator: 0, available: 0}
+    }
+
+    /// Reads value of `bits` width (at most 32), buffer must hold enough bytes.
+    pub(crate) fn read(&mut self, bits: u32) -> u32{
+        while self.available < bits{
+            self.accumulator |= (self.buffer[self.position] as u64) << self.available;
+            self.position += 1;
+            self.available += 8;
+        }
+        let value = self.accumulator & ((1u64 << bits) - 1);
+        self.accumulator >>= bits;
+        self.available -= bits;
+        value as u32
+    }
+}
@@ -0,0 +1,580 @@
+//! Module with compact binary codec of card sets, deals and sequences of cards (e.g. plays of game record),
+//! independent of serde. Intended for storing large numbers of generated deals and game logs.
+//!
+//! Every record starts with one header byte: [`CODEC_VERSION`] in high nibble and [`RecordKind`] in low nibble.
+//! Decoders reject records of other version or kind.
+//!
+//! | Record                  | Functions                                    | Payload                                         | Length (with header)                          |
+//! |-------------------------|----------------------------------------------|-------------------------------------------------|-----------------------------------------------|
+//! | [`CardSetStd`]          | [`encode_set`], [`decode_set`]               | mask, `u64` little endian                       | [`SET_ENCODED_LEN`] = 9                       |
+//! | [`CardSetStd`]          | [`encode_set_colex`], [`decode_set_colex`]   | size and colex rank of set among sets of size   | [`colex_encoded_len`], 7 for 13 cards         |
+//! | deal of `K` hands       | [`encode_deal`], [`decode_deal`]             | `K` and hand of every card in `⌈log₂K⌉` bits     | [`deal_encoded_len`], 15 for 4 hands          |
+//! | sequence of [`Card`]    | [`encode_cards`], [`decode_cards`]           | length (LEB128) and 6-bit card indices          | [`cards_encoded_len`], 41 for 52 cards        |
+//!
+//! Bridge deal takes 13 bytes of payload (2 bits for every of 52 cards). Hands of deal must partition
+//! whole deck, cards left in stock can be encoded as additional hand.
+//! Encoders write to given buffer and return number of written bytes, decoders return value with
+//! number of read bytes, so records can be concatenated:
+//! ```
+//! use karty::cards::*;
+//! use karty::codec::{decode_cards_into, decode_deal, encode_cards, encode_deal};
+//! use karty::set::CardSetStd;
+//! use karty::suits::Suit;
+//! let deal = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs].map(CardSetStd::of_suit);
+//! let plays = [ACE_SPADES, TWO_HEARTS, THREE_DIAMONDS, FOUR_CLUBS];
+//! let mut record = [0u8; 64];
+//! let mut length = encode_deal(&deal, &mut record).unwrap();
+//! assert_eq!(length, 15);
+//! length += encode_cards(&plays, &mut record[length..]).unwrap();
+//! assert_eq!(length, 15 + 5);
+//!
+//! let (decoded_deal, read) = decode_deal::<4>(&record[..length]).unwrap();
+//! let mut decoded_plays = [ACE_CLUBS; 4];
+//! let (count, _) = decode_cards_into(&record[read..length], &mut decoded_plays).unwrap();
+//! assert_eq!(decoded_deal, deal);
+//! assert_eq!(decoded_plays[..count], plays);
+//! ```
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+mod bits;
+
+use bits::{BitReader, BitWriter};
+use crate::cards::{Card, DECK_SIZE};
+use crate::error::CodecError;
+use crate::set::CardSetStd;
+
+/// Version of codec written in header of every record.
+pub const CODEC_VERSION: u8 = 1;
+/// Length of record header.
+pub const HEADER_LEN: usize = 1;
+/// Length of [`CardSetStd`] encoded as mask.
+pub const SET_ENCODED_LEN: usize = HEADER_LEN + 8;
+/// Width of card index in sequence of cards.
+const CARD_BITS: u32 = 6;
+
+/// Kind of encoded record, written in header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum RecordKind{
+    SetMask = 0,
+    SetColex = 1,
+    Deal = 2,
+    Cards = 3,
+}
+
+impl RecordKind{
+    /// Header byte of record.
+    /// ```
+    /// use karty::codec::RecordKind;
+    /// assert_eq!(RecordKind::Deal.header(), 0x12);
+    /// ```
+    pub const fn header(self) -> u8{
+        (CODEC_VERSION << 4) | self as u8
+    }
+}
+
+/// Number of subsets of size `k` of set of size `n`.
+const fn binomial(n: usize, k: usize) -> u64{
+    if k > n{
+        return 0
+    }
+    let k = if k > n - k { n - k } else { k };
+    let mut result = 1u64;
+    let mut i = 0;
+    while i < k{
+        result = result * (n - i) as u64 / (i + 1) as u64;
+        i += 1;
+    }
+    result
+}
+
+/// Bytes needed to store colex rank of set of given size.
+const fn rank_len(size: usize) -> usize{
+    let max_rank = binomial(DECK_SIZE, size) - 1;
+    (u64::BITS - max_rank.leading_zeros()).div_ceil(8) as usize
+}
+
+/// Bits needed to store index of hand.
+const fn hand_bits(hands: usize) -> u32{
+    match hands{
+        0 | 1 => 0,
+        n => usize::BITS - (n - 1).leading_zeros()
+    }
+}
+
+const fn varint_len(value: usize) -> usize{
+    let mut length = 1;
+    let mut rest = value >> 7;
+    while rest > 0{
+        length += 1;
+        rest >>= 7;
+    }
+    length
+}
+
+/// Length of [`CardSetStd`] of `size` cards encoded with [`encode_set_colex`].
+/// ```
+/// use karty::codec::colex_encoded_len;
+/// assert_eq!(colex_encoded_len(0), 2);
+/// assert_eq!(colex_encoded_len(13), 7);
+/// assert_eq!(colex_encoded_len(26), 9);
+/// ```
+pub const fn colex_encoded_len(size: usize) -> usize{
+    HEADER_LEN + 1 + rank_len(size)
+}
+
+/// Length of deal of `hands` hands encoded with [`encode_deal`].
+/// ```
+/// use karty::codec::deal_encoded_len;
+/// assert_eq!(deal_encoded_len(4), 15);
+/// assert_eq!(deal_encoded_len(3), 15);
+/// assert_eq!(deal_encoded_len(2), 9);
+/// ```
+pub const fn deal_encoded_len(hands: usize) -> usize{
+    HEADER_LEN + 1 + (DECK_SIZE * hand_bits(hands) as usize).div_ceil(8)
+}
+
+/// Length of sequence of `count` cards encoded with [`encode_cards`].
+/// ```
+/// use karty::codec::cards_encoded_len;
+/// assert_eq!(cards_encoded_len(52), 41);
+/// ```
+pub const fn cards_encoded_len(count: usize) -> usize{
+    HEADER_LEN + varint_len(count) + (count * CARD_BITS as usize).div_ceil(8)
+}
+
+fn check_len(buffer: &[u8], expected: usize) -> Result<(), CodecError>{
+    match buffer.len() >= expected{
+        true => Ok(()),
+        false => Err(CodecError::BufferTooShort{expected, found: buffer.len()})
+    }
+}
+
+fn write_header(buffer: &mut [u8], kind: RecordKind, length: usize) -> Result<(), CodecError>{
+    check_len(buffer, length)?;
+    buffer[0] = kind.header();
+    Ok(())
+}
+
+fn read_header(buffer: &[u8], kind: RecordKind) -> Result<(), CodecError>{
+    check_len(buffer, HEADER_LEN)?;
+    let version = buffer[0] >> 4;
+    if version != CODEC_VERSION{
+        return Err(CodecError::UnsupportedVersion(version))
+    }
+    match buffer[0] & 0x0f{
+        found if found == kind as u8 => Ok(()),
+        found => Err(CodecError::WrongKind{expected: kind, found})
+    }
+}
+
+/// Encodes set as mask of cards, returns number of written bytes ([`SET_ENCODED_LEN`]).
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::codec::{decode_set, encode_set, SET_ENCODED_LEN};
+/// let set = card_set![ACE_SPADES, TWO_CLUBS];
+/// let mut buffer = [0u8; SET_ENCODED_LEN];
+/// assert_eq!(encode_set(&set, &mut buffer), Ok(9));
+/// assert_eq!(decode_set(&buffer), Ok((set, 9)));
+/// ```
+pub fn encode_set(set: &CardSetStd, buffer: &mut [u8]) -> Result<usize, CodecError>{
+    write_header(buffer, RecordKind::SetMask, SET_ENCODED_LEN)?;
+    buffer[HEADER_LEN..SET_ENCODED_LEN].copy_from_slice(&u64::from(*set).to_le_bytes());
+    Ok(SET_ENCODED_LEN)
+}
+
+/// Decodes set encoded with [`encode_set`], returns set and number of read bytes.
+pub fn decode_set(buffer: &[u8]) -> Result<(CardSetStd, usize), CodecError>{
+    read_header(buffer, RecordKind::SetMask)?;
+    check_len(buffer, SET_ENCODED_LEN)?;
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buffer[HEADER_LEN..SET_ENCODED_LEN]);
+    let mask = u64::from_le_bytes(bytes);
+    let set = CardSetStd::from_u64(mask);
+    match u64::from(set) == mask{
+        true => Ok((set, SET_ENCODED_LEN)),
+        false => Err(CodecError::InvalidMask(mask))
+    }
+}
+
+/// Encodes set as its size and rank in colexicographic order of sets of the same size,
+/// returns number of written bytes ([`colex_encoded_len`] of set size). Hand of 13 cards takes 7 bytes.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::codec::{decode_set_colex, encode_set_colex};
+/// use karty::set::CardSetStd;
+/// let mut buffer = [0u8; 9];
+/// let set = card_set![TWO_CLUBS, THREE_CLUBS];
+/// assert_eq!(encode_set_colex(&set, &mut buffer), Ok(4));
+/// assert_eq!(buffer[..4], [0x11, 2, 0, 0]);
+/// assert_eq!(decode_set_colex(&buffer), Ok((set, 4)));
+/// let deck = CardSetStd::from_cards(&STANDARD_DECK);
+/// assert_eq!(encode_set_colex(&deck, &mut buffer), Ok(2));
+/// assert_eq!(decode_set_colex(&buffer), Ok((deck, 2)));
+/// ```
+pub fn encode_set_colex(set: &CardSetStd, buffer: &mut [u8]) -> Result<usize, CodecError>{
+    let size = set.len();
+    let length = colex_encoded_len(size);
+    write_header(buffer, RecordKind::SetColex, length)?;
+    let mut mask = u64::from(*set);
+    let mut rank = 0u64;
+    let mut i = 1;
+    while mask != 0{
+        rank += binomial(mask.trailing_zeros() as usize, i);
+        mask &= mask - 1;
+        i += 1;
+    }
+    buffer[HEADER_LEN] = size as u8;
+    buffer[HEADER_LEN + 1..length].copy_from_slice(&rank.to_le_bytes()[..rank_len(size)]);
+    Ok(length)
+}
+
+/// Decodes set encoded with [`encode_set_colex`], returns set and number of read bytes.
+pub fn decode_set_colex(buffer: &[u8]) -> Result<(CardSetStd, usize), CodecError>{
+    read_header(buffer, RecordKind::SetColex)?;
+    check_len(buffer, HEADER_LEN + 1)?;
+    let size = buffer[HEADER_LEN] as usize;
+    if size > DECK_SIZE{
+        return Err(CodecError::InvalidSetSize(size))
+    }
+    let length = colex_encoded_len(size);
+    check_len(buffer, length)?;
+    let mut bytes = [0u8; 8];
+    bytes[..length - HEADER_LEN - 1].copy_from_slice(&buffer[HEADER_LEN + 1..length]);
+    let rank = u64::from_le_bytes(bytes);
+    if rank >= binomial(DECK_SIZE, size){
+        return Err(CodecError::InvalidRank{size, rank})
+    }
+    let mut remaining = rank;
+    let mut index = DECK_SIZE;
+    let mut mask = 0u64;
+    for i in (1..=size).rev(){
+        index -= 1;
+        while binomial(index, i) > remaining{
+            index -= 1;
+        }
+        remaining -= binomial(index, i);
+        mask |= 1 << index;
+    }
+    Ok((CardSetStd::from_u64(mask), length))
+}
+
+/// Encodes deal of `K` hands partitioning standard deck: for every card index of hand holding it,
+/// returns number of written bytes ([`deal_encoded_len`] of `K`).
+/// # Returns:
+/// `Err` if hands overlap or do not cover whole deck.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::codec::encode_deal;
+/// use karty::error::CodecError;
+/// use karty::set::CardSetStd;
+/// let deck = CardSetStd::from_cards(&STANDARD_DECK);
+/// let mut buffer = [0u8; 16];
+/// assert_eq!(encode_deal(&[deck, card_set![]], &mut buffer), Ok(9));
+/// assert_eq!(encode_deal(&[deck, card_set![ACE_SPADES]], &mut buffer), Err(CodecError::NotPartition));
+/// ```
+pub fn encode_deal<const K: usize>(hands: &[CardSetStd; K], buffer: &mut [u8]) -> Result<usize, CodecError>{
+    if K > u8::MAX as usize{
+        return Err(CodecError::TooManyHands(K))
+    }
+    let (mut union, mut total) = (0u64, 0usize);
+    for hand in hands{
+        union |= u64::from(*hand);
+        total += hand.len();
+    }
+    if total != DECK_SIZE || union != u64::from(CardSetStd::from_cards(&crate::cards::STANDARD_DECK)){
+        return Err(CodecError::NotPartition)
+    }
+    let length = deal_encoded_len(K);
+    write_header(buffer, RecordKind::Deal, length)?;
+    buffer[HEADER_LEN] = K as u8;
+    let bits = hand_bits(K);
+    let mut writer = BitWriter::new(&mut buffer[HEADER_LEN + 1..length]);
+    for index in 0..DECK_SIZE{
+        let hand = hands.iter().position(|h| u64::from(*h) & (1 << index) != 0)
+            .expect("Hands partition deck (should not happen, it is a bug).");
+        writer.write(hand as u32, bits);
+    }
+    writer.finish();
+    Ok(length)
+}
+
+/// Decodes deal encoded with [`encode_deal`], returns hands and number of read bytes.
+/// ```
+/// use karty::codec::{decode_deal, encode_deal};
+/// use karty::error::CodecError;
+/// use karty::set::CardSetStd;
+/// use karty::suits::Suit;
+/// let deal = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs].map(CardSetStd::of_suit);
+/// let mut buffer = [0u8; 15];
+/// encode_deal(&deal, &mut buffer).unwrap();
+/// assert_eq!(decode_deal::<4>(&buffer), Ok((deal, 15)));
+/// assert_eq!(decode_deal::<3>(&buffer), Err(CodecError::WrongHandCount{expected: 3, found: 4}));
+/// ```
+pub fn decode_deal<const K: usize>(buffer: &[u8]) -> Result<([CardSetStd; K], usize), CodecError>{
+    read_header(buffer, RecordKind::Deal)?;
+    check_len(buffer, HEADER_LEN + 1)?;
+    let found = buffer[HEADER_LEN] as usize;
+    if found != K{
+        return Err(CodecError::WrongHandCount{expected: K, found})
+    }
+    let length = deal_encoded_len(K);
+    check_len(buffer, length)?;
+    let bits = hand_bits(K);
+    let mut reader = BitReader::new(&buffer[HEADER_LEN + 1..length]);
+    let mut masks = [0u64; K];
+    for index in 0..DECK_SIZE{
+        let hand = reader.read(bits) as usize;
+        match masks.get_mut(hand){
+            Some(mask) => *mask |= 1 << index,
+            None => return Err(CodecError::InvalidHand(hand))
+        }
+    }
+    Ok((masks.map(CardSetStd::from_u64), length))
+}
+
+/// Encodes sequence of cards as its length (LEB128) followed by 6-bit card indices,
+/// returns number of written bytes ([`cards_encoded_len`] of sequence length).
+/// ```
+/// use karty::cards::*;
+/// use karty::codec::{cards_encoded_len, decode_cards_into, encode_cards};
+/// let plays = [KING_HEARTS, ACE_HEARTS, TWO_HEARTS];
+/// let mut buffer = [0u8; 8];
+/// assert_eq!(encode_cards(&plays, &mut buffer), Ok(cards_encoded_len(3)));
+/// let mut decoded = [TWO_CLUBS; 13];
+/// assert_eq!(decode_cards_into(&buffer, &mut decoded), Ok((3, 5)));
+/// assert_eq!(decoded[..3], plays);
+/// ```
+pub fn encode_cards(cards: &[Card], buffer: &mut [u8]) -> Result<usize, CodecError>{
+    let length = cards_encoded_len(cards.len());
+    write_header(buffer, RecordKind::Cards, length)?;
+    let mut count = cards.len();
+    let mut position = HEADER_LEN;
+    loop{
+        let byte = (count & 0x7f) as u8;
+        count >>= 7;
+        if count == 0{
+            buffer[position] = byte;
+            position += 1;
+            break;
+        }
+        buffer[position] = byte | 0x80;
+        position += 1;
+    }
+    let mut writer = BitWriter::new(&mut buffer[position..length]);
+    for card in cards{
+        writer.write(card.usize_index() as u32, CARD_BITS);
+    }
+    writer.finish();
+    Ok(length)
+}
+
+/// Reads header and length of sequence, returns number of cards and position of first card.
+fn read_cards_prefix(buffer: &[u8]) -> Result<(usize, usize), CodecError>{
+    read_header(buffer, RecordKind::Cards)?;
+    let mut count = 0usize;
+    let mut position = HEADER_LEN;
+    loop{
+        check_len(buffer, position + 1)?;
+        let byte = buffer[position];
+        let shift = 7 * (position - HEADER_LEN) as u32;
+        let part = (byte & 0x7f) as usize;
+        if shift >= usize::BITS || (part << shift) >> shift != part{
+            return Err(CodecError::InvalidLength)
+        }
+        count |= part << shift;
+        position += 1;
+        if byte & 0x80 == 0{
+            break;
+        }
+    }
+    let payload = count.checked_mul(CARD_BITS as usize).ok_or(CodecError::InvalidLength)?;
+    check_len(buffer, position + payload.div_ceil(8))?;
+    Ok((count, position))
+}
+
+/// Decodes sequence of cards encoded with [`encode_cards`] into `cards` (which must be long enough),
+/// returns number of decoded cards and number of read bytes.
+pub fn decode_cards_into(buffer: &[u8], cards: &mut [Card]) -> Result<(usize, usize), CodecError>{
+    let (count, position) = read_cards_prefix(buffer)?;
+    if cards.len() < count{
+        return Err(CodecError::BufferTooShort{expected: count, found: cards.len()})
+    }
+    let mut reader = BitReader::new(&buffer[position..]);
+    for card in cards[..count].iter_mut(){
+        let index = reader.read(CARD_BITS) as usize;
+        *card = Card::from_usize_index(index).map_err(|_| CodecError::InvalidCardIndex(index))?;
+    }
+    Ok((count, position + (count * CARD_BITS as usize).div_ceil(8)))
+}
+
+/// Decodes sequence of cards encoded with [`encode_cards`], returns cards and number of read bytes.
+#[cfg(feature = "alloc")]
+pub fn decode_cards(buffer: &[u8]) -> Result<(alloc::vec::Vec<Card>, usize), CodecError>{
+    let (count, position) = read_cards_prefix(buffer)?;
+    let mut reader = BitReader::new(&buffer[position..]);
+    let mut cards = alloc::vec::Vec::with_capacity(count);
+    for _ in 0..count{
+        let index = reader.read(CARD_BITS) as usize;
+        cards.push(Card::from_usize_index(index).map_err(|_| CodecError::InvalidCardIndex(index))?);
+    }
+    Ok((cards, position + (count * CARD_BITS as usize).div_ceil(8)))
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::{Card, STANDARD_DECK};
+    use crate::codec::*;
+    use crate::error::CodecError;
+    use crate::set::CardSetStd;
+
+    /// Deterministic pseudo-random deal of `K` hands of `DECK_SIZE / K` cards (and remainder in last hand).
+    fn deal<const K: usize>(seed: u64) -> [CardSetStd; K]{
+        let mut deck = STANDARD_DECK;
+        let mut state = seed;
+        for i in (1..deck.len()).rev(){
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            deck.swap(i, (state >> 33) as usize % (i + 1));
+        }
+        let size = DECK_SIZE / K;
+        core::array::from_fn(|h| match h == K - 1{
+            true => CardSetStd::from_cards(&deck[h * size..]),
+            false => CardSetStd::from_cards(&deck[h * size..(h + 1) * size])
+        })
+    }
+
+    #[test]
+    fn deal_round_trip(){
+        let mut buffer = [0u8; 64];
+        for seed in 0..100{
+            let bridge = deal::<4>(seed);
+            assert_eq!(encode_deal(&bridge, &mut buffer), Ok(15));
+            assert_eq!(decode_deal::<4>(&buffer), Ok((bridge, 15)));
+            let skat = deal::<3>(seed);
+            assert_eq!(encode_deal(&skat, &mut buffer), Ok(15));
+            assert_eq!(decode_deal::<3>(&buffer), Ok((skat, 15)));
+            let five = deal::<5>(seed);
+            assert_eq!(encode_deal(&five, &mut buffer), Ok(deal_encoded_len(5)));
+            assert_eq!(decode_deal::<5>(&buffer), Ok((five, 22)));
+        }
+        let single = [CardSetStd::from_cards(&STANDARD_DECK)];
+        assert_eq!(encode_deal(&single, &mut buffer), Ok(2));
+        assert_eq!(decode_deal::<1>(&buffer[..2]), Ok((single, 2)));
+    }
+
+    #[test]
+    fn deal_rejects_invalid(){
+        let mut buffer = [0u8; 15];
+        let [a, b, c, d] = deal::<4>(7);
+        assert_eq!(encode_deal(&[a, b, c, d.union(&a)], &mut buffer), Err(CodecError::NotPartition));
+        assert_eq!(encode_deal(&[a, b, c, d], &mut buffer[..14]), Err(CodecError::BufferTooShort{expected: 15, found: 14}));
+        encode_deal(&deal::<3>(7), &mut buffer).unwrap();
+        // third hand of 3 is encoded as 2, hand 3 does not exist
+        buffer[2] |= 0b11;
+        assert_eq!(decode_deal::<3>(&buffer), Err(CodecError::InvalidHand(3)));
+    }
+
+    #[test]
+    fn colex_round_trip(){
+        let mut buffer = [0u8; 16];
+        for seed in 0..50{
+            for hand in deal::<4>(seed).into_iter().chain(deal::<3>(seed)).chain(deal::<2>(seed)){
+                let length = encode_set_colex(&hand, &mut buffer).unwrap();
+                assert_eq!(length, colex_encoded_len(hand.len()));
+                assert_eq!(decode_set_colex(&buffer), Ok((hand, length)));
+            }
+        }
+        for size in 0..=DECK_SIZE{
+            let first = CardSetStd::from_u64((1u64 << size) - 1);
+            let last = CardSetStd::from_u64(((1u64 << size) - 1) << (DECK_SIZE - size));
+            encode_set_colex(&first, &mut buffer).unwrap();
+            assert!(buffer[2..colex_encoded_len(size)].iter().all(|b| *b == 0));
+            assert_eq!(decode_set_colex(&buffer).unwrap().0, first);
+            encode_set_colex(&last, &mut buffer).unwrap();
+            assert_eq!(decode_set_colex(&buffer).unwrap().0, last);
+        }
+    }
+
+    #[test]
+    fn set_rejects_invalid(){
+        let mut buffer = [0u8; SET_ENCODED_LEN];
+        encode_set(&deal::<4>(3)[0], &mut buffer).unwrap();
+        buffer[8] |= 0x80;
+        assert!(matches!(decode_set(&buffer), Err(CodecError::InvalidMask(_))));
+        assert_eq!(decode_set_colex(&[RecordKind::SetColex.header(), 53]), Err(CodecError::InvalidSetSize(53)));
+        assert_eq!(decode_set_colex(&[RecordKind::SetColex.header(), 1, 52]), Err(CodecError::InvalidRank{size: 1, rank: 52}));
+    }
+
+    #[test]
+    fn header_versioning(){
+        let mut buffer = [0u8; SET_ENCODED_LEN];
+        encode_set(&deal::<4>(5)[1], &mut buffer).unwrap();
+        assert_eq!(decode_set_colex(&buffer), Err(CodecError::WrongKind{expected: RecordKind::SetColex, found: 0}));
+        buffer[0] = (CODEC_VERSION + 1) << 4;
+        assert_eq!(decode_set(&buffer), Err(CodecError::UnsupportedVersion(CODEC_VERSION + 1)));
+        assert_eq!(decode_set(&[]), Err(CodecError::BufferTooShort{expected: 1, found: 0}));
+    }
+
+    #[test]
+    fn cards_round_trip(){
+        let mut buffer = [0u8; 256];
+        let mut decoded = [STANDARD_DECK[0]; 300];
+        for count in [0, 1, 3, 4, 52, 127, 128, 300]{
+            let cards: [Card; 300] = core::array::from_fn(|i| STANDARD_DECK[(i * 7 + count) % DECK_SIZE]);
+            let length = encode_cards(&cards[..count], &mut buffer).unwrap();
+            assert_eq!(length, cards_encoded_len(count));
+            assert_eq!(decode_cards_into(&buffer, &mut decoded), Ok((count, length)));
+            assert_eq!(decoded[..count], cards[..count]);
+        }
+        assert_eq!(cards_encoded_len(127), 1 + 1 + 96);
+        assert_eq!(cards_encoded_len(128), 1 + 2 + 96);
+    }
+
+    #[test]
+    fn cards_non_minimal_length_reports_read_bytes(){
+        let mut decoded = [STANDARD_DECK[0]; 1];
+        let empty = [RecordKind::Cards.header(), 0x80, 0x00];
+        assert_eq!(decode_cards_into(&empty, &mut decoded), Ok((0, 3)));
+        let one = [RecordKind::Cards.header(), 0x81, 0x80, 0x00, 5];
+        assert_eq!(decode_cards_into(&one, &mut decoded), Ok((1, 5)));
+        assert_eq!(decoded[0], Card::from_usize_index(5).unwrap());
+        #[cfg(feature = "alloc")]
+        assert_eq!(decode_cards(&one).unwrap().1, 5);
+    }
+
+    #[test]
+    fn cards_reject_invalid(){
+        let mut buffer = [0u8; 8];
+        let length = encode_cards(&STANDARD_DECK[..4], &mut buffer).unwrap();
+        let mut decoded = [STANDARD_DECK[0]; 4];
+        assert_eq!(decode_cards_into(&buffer[..length - 1], &mut decoded), Err(CodecError::BufferTooShort{expected: 5, found: 4}));
+        assert_eq!(decode_cards_into(&buffer, &mut decoded[..3]), Err(CodecError::BufferTooShort{expected: 4, found: 3}));
+        buffer[2] = 63;
+        assert_eq!(decode_cards_into(&buffer, &mut decoded), Err(CodecError::InvalidCardIndex(63)));
+        let overlong = [RecordKind::Cards.header(), 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f];
+        assert_eq!(decode_cards_into(&overlong, &mut decoded), Err(CodecError::InvalidLength));
+    }
+
+    #[cfg(feature = "serde_postcard")]
+    #[test]
+    fn smaller_than_postcard(){
+        let mut buffer = [0u8; 128];
+        let mut postcard_buffer = [0u8; 512];
+        let bridge = deal::<4>(11);
+        let compact = encode_deal(&bridge, &mut buffer).unwrap();
+        assert!(compact < postcard::to_slice(&bridge, &mut postcard_buffer).unwrap().len());
+        let masks = bridge.map(crate::set::serde_repr::AsMask);
+        assert!(compact < postcard::to_slice(&masks, &mut postcard_buffer).unwrap().len());
+        let plays = STANDARD_DECK;
+        let compact = encode_cards(&plays, &mut buffer).unwrap();
+        let compact_plays = plays.map(crate::symbol::compact::Compact);
+        assert!(compact < postcard::to_slice(&plays[..], &mut postcard_buffer).unwrap().len());
+        assert!(compact < postcard::to_slice(&compact_plays[..], &mut postcard_buffer).unwrap().len());
+    }
+}
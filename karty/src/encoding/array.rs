@@ -0,0 +1,83 @@
+use ndarray::{Array2, ArrayView2};
+use crate::error::EncodingError;
+use crate::figures::Figure;
+use crate::set::CardSetStd;
+use crate::suits::Suit;
+use crate::symbol::CardSymbol;
+use super::{decode_sequence, decode_set, encode_sequence, encode_set, EncodingElement};
+
+/// Shape of plane encoding of [`CardSetStd`]: `(suits, figures)`.
+pub const SET_PLANES_SHAPE: (usize, usize) = (Suit::SYMBOL_SPACE, Figure::SYMBOL_SPACE);
+
+fn check_shape<T>(array: &ArrayView2<T>, expected: (usize, usize)) -> Result<(), EncodingError>{
+    match array.dim() == expected{
+        true => Ok(()),
+        false => Err(EncodingError::WrongShape{expected, found: array.dim()})
+    }
+}
+
+/// Encodes set of standard cards as array of shape [`SET_PLANES_SHAPE`] (`4 x 13`),
+/// layout is the same as in [`encode_set_planes`](super::encode_set_planes).
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::encoding::{array_to_set, set_to_array};
+/// let hand = card_set![ACE_SPADES, TWO_CLUBS];
+/// let array = set_to_array::<f32>(&hand);
+/// assert_eq!(array.dim(), (4, 13));
+/// assert_eq!(array[[3, 12]], 1.0);
+/// assert_eq!(array.sum(), 2.0);
+/// assert_eq!(array_to_set(array.view()), Ok(hand));
+/// ```
+pub fn set_to_array<T: EncodingElement>(set: &CardSetStd) -> Array2<T>{
+    let mut buffer = vec![T::ZERO; SET_PLANES_SHAPE.0 * SET_PLANES_SHAPE.1];
+    encode_set(set, &mut buffer).expect("Buffer has length of deck (should not happen, it is a bug).");
+    Array2::from_shape_vec(SET_PLANES_SHAPE, buffer)
+        .expect("Buffer length matches shape (should not happen, it is a bug).")
+}
+
+/// Reverse of [`set_to_array`].
+pub fn array_to_set<T: EncodingElement>(array: ArrayView2<T>) -> Result<CardSetStd, EncodingError>{
+    check_shape(&array, SET_PLANES_SHAPE)?;
+    decode_set(&array.iter().copied().collect::<Vec<T>>())
+}
+
+/// Encodes sequence of symbols as array of shape `(max_len, S::SYMBOL_SPACE)`,
+/// rows as in [`encode_sequence`](super::encode_sequence).
+/// ```
+/// use karty::cards::*;
+/// use karty::encoding::{array_to_sequence, sequence_to_array};
+/// let played = [QUEEN_SPADES, KING_SPADES];
+/// let array = sequence_to_array::<_, u8>(&played, 13).unwrap();
+/// assert_eq!(array.dim(), (13, 52));
+/// assert_eq!(array[[1, 50]], 1);
+/// assert_eq!(array_to_sequence::<Card, _>(array.view()).unwrap(), played.to_vec());
+/// ```
+pub fn sequence_to_array<S: CardSymbol, T: EncodingElement>(symbols: &[S], max_len: usize) -> Result<Array2<T>, EncodingError>{
+    let mut buffer = vec![T::ZERO; max_len * S::SYMBOL_SPACE];
+    encode_sequence(symbols, max_len, &mut buffer)?;
+    Ok(Array2::from_shape_vec((max_len, S::SYMBOL_SPACE), buffer)
+        .expect("Buffer length matches shape (should not happen, it is a bug)."))
+}
+
+/// Reverse of [`sequence_to_array`], number of rows is taken as `max_len`.
+pub fn array_to_sequence<S: CardSymbol, T: EncodingElement>(array: ArrayView2<T>) -> Result<Vec<S>, EncodingError>{
+    let rows = array.nrows();
+    check_shape(&array, (rows, S::SYMBOL_SPACE))?;
+    decode_sequence(&array.iter().copied().collect::<Vec<T>>(), rows)
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::Card;
+    use crate::encoding::{array_to_sequence, array_to_set};
+    use crate::error::EncodingError;
+
+    #[test]
+    fn wrong_shapes(){
+        let array = ndarray::Array2::<f32>::zeros((13, 4));
+        assert_eq!(array_to_set(array.view()), Err(EncodingError::WrongShape{expected: (4, 13), found: (13, 4)}));
+        assert_eq!(array_to_sequence::<Card, _>(array.view()), Err(EncodingError::WrongShape{expected: (13, 52), found: (13, 4)}));
+        assert_eq!(array_to_set(array.t()).map(|s| s.len()), Ok(0));
+    }
+}
@@ -0,0 +1,362 @@
+//! Module with encoding of cards into fixed-shape numeric buffers and decoding them back,
+//! typically used to build observations for machine learning agents.
+//! Every [`CardSymbol`] is encoded using its dense [`CardSymbol::usize_index`] numbering:
+//!
+//! | Encoded                | Function                                   | Shape                            |
+//! |------------------------|--------------------------------------------|----------------------------------|
+//! | single symbol          | [`encode_symbol`] (one-hot)                | `[S::SYMBOL_SPACE]`              |
+//! | set of cards           | [`encode_set`] (multi-hot)                 | `[C::SYMBOL_SPACE]`              |
+//! | [`CardSetStd`] planes  | [`encode_set_planes`]                      | `[4][13]` (suit, figure)         |
+//! | multiset of symbols    | [`encode_counts`] (count plane)            | `[S::SYMBOL_SPACE]`              |
+//! | sequence of symbols    | [`encode_sequence`] (one-hot rows)         | `[max_len * S::SYMBOL_SPACE]`    |
+//! | [`SuitMap`]            | [`encode_suit_map`]                        | `[4]`                            |
+//!
+//! For standard deck flat encoding of set (`[52]`) is row-major flattening of plane encoding (`[4][13]`),
+//! as card index is `suit_index * 13 + figure_index`. Suits are ordered `Clubs, Diamonds, Hearts, Spades`
+//! and figures `2, 3, ..., King, Ace`. Elements can be `f32`, `u8` or `bool` (see [`EncodingElement`]).
+//! With feature `ndarray` set and sequence encodings can be converted to and from `ndarray` arrays.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::error::EncodingError;
+use crate::figures::Figure;
+use crate::set::{CardSet, CardSetStd};
+use crate::suits::{Suit, SuitMap, SUITS};
+use crate::symbol::CardSymbol;
+
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "ndarray")]
+pub use array::*;
+
+/// Numeric type of encoded buffer elements.
+pub trait EncodingElement: Copy + PartialEq{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Element representing count of symbol occurrences.
+    fn from_count(count: u8) -> Self;
+    /// Reverse of [`from_count`](Self::from_count), `None` if element does not represent count.
+    fn to_count(self) -> Option<u8>;
+}
+
+impl EncodingElement for f32{
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_count(count: u8) -> Self {
+        count as f32
+    }
+
+    fn to_count(self) -> Option<u8> {
+        let count = self as u8;
+        (count as f32 == self).then_some(count)
+    }
+}
+
+/// `bool` elements can hold only presence of symbol, counts greater than one are encoded as `true`.
+impl EncodingElement for bool{
+    const ZERO: Self = false;
+    const ONE: Self = true;
+
+    fn from_count(count: u8) -> Self {
+        count > 0
+    }
+
+    fn to_count(self) -> Option<u8> {
+        Some(self as u8)
+    }
+}
+
+impl EncodingElement for u8{
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn from_count(count: u8) -> Self {
+        count
+    }
+
+    fn to_count(self) -> Option<u8> {
+        Some(self)
+    }
+}
+
+/// Checks that buffer has exactly expected length.
+pub(crate) fn check_length<T>(buffer: &[T], expected: usize) -> Result<(), EncodingError>{
+    match buffer.len() == expected{
+        true => Ok(()),
+        false => Err(EncodingError::WrongBufferLength{expected, found: buffer.len()})
+    }
+}
+
+/// Index of the only hot element of row, `None` if all elements are zero.
+/// `offset` is position of row in whole buffer, used in errors.
+fn hot_index<T: EncodingElement>(row: &[T], offset: usize) -> Result<Option<usize>, EncodingError>{
+    let mut hot = None;
+    for (i, e) in row.iter().enumerate(){
+        if *e == T::ONE{
+            match hot{
+                None => hot = Some(i),
+                Some(_) => return Err(EncodingError::NotOneHot(offset))
+            }
+        } else if *e != T::ZERO{
+            return Err(EncodingError::InvalidValue(offset + i))
+        }
+    }
+    Ok(hot)
+}
+
+fn symbol_at<S: CardSymbol>(index: usize) -> S{
+    S::from_usize_index(index)
+        .expect("Index lesser than symbol space (should not happen, it is a bug).")
+}
+
+/// Encodes symbol as one-hot vector of length [`CardSymbol::SYMBOL_SPACE`].
+/// ```
+/// use karty::cards::{Card, THREE_DIAMONDS};
+/// use karty::encoding::{decode_symbol, encode_symbol};
+/// let mut buffer = [0.0f32; 52];
+/// encode_symbol(&THREE_DIAMONDS, &mut buffer).unwrap();
+/// assert_eq!(buffer[14], 1.0);
+/// assert_eq!(buffer.iter().sum::<f32>(), 1.0);
+/// assert_eq!(decode_symbol::<Card, _>(&buffer), Ok(THREE_DIAMONDS));
+/// ```
+pub fn encode_symbol<S: CardSymbol, T: EncodingElement>(symbol: &S, buffer: &mut [T]) -> Result<(), EncodingError>{
+    check_length(buffer, S::SYMBOL_SPACE)?;
+    buffer.fill(T::ZERO);
+    buffer[symbol.usize_index()] = T::ONE;
+    Ok(())
+}
+
+/// Decodes symbol from one-hot vector of length [`CardSymbol::SYMBOL_SPACE`].
+/// ```
+/// use karty::encoding::decode_symbol;
+/// use karty::error::EncodingError;
+/// use karty::suits::Suit;
+/// assert_eq!(decode_symbol::<Suit, u8>(&[0, 0, 0, 1]), Ok(Suit::Spades));
+/// assert_eq!(decode_symbol::<Suit, u8>(&[0, 1, 0, 1]), Err(EncodingError::NotOneHot(0)));
+/// assert_eq!(decode_symbol::<Suit, u8>(&[0, 0, 0, 0]), Err(EncodingError::NotOneHot(0)));
+/// ```
+pub fn decode_symbol<S: CardSymbol, T: EncodingElement>(buffer: &[T]) -> Result<S, EncodingError>{
+    check_length(buffer, S::SYMBOL_SPACE)?;
+    hot_index(buffer, 0)?
+        .map(symbol_at)
+        .ok_or(EncodingError::NotOneHot(0))
+}
+
+/// Encodes set of cards as multi-hot vector of length [`CardSymbol::SYMBOL_SPACE`] of card type.
+/// Works with every [`CardSet`] (e.g. [`CardSetStd`], [`TarotCardSet`](crate::tarot::TarotCardSet)).
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::encoding::{decode_set, encode_set};
+/// use karty::set::CardSetStd;
+/// let hand = card_set![TWO_CLUBS, ACE_SPADES];
+/// let mut buffer = [0u8; 52];
+/// encode_set(&hand, &mut buffer).unwrap();
+/// assert_eq!((buffer[0], buffer[1], buffer[51]), (1, 0, 1));
+/// assert_eq!(decode_set::<CardSetStd, _>(&buffer), Ok(hand));
+/// ```
+pub fn encode_set<C: CardSet, T: EncodingElement>(set: &C, buffer: &mut [T]) -> Result<(), EncodingError>{
+    check_length(buffer, C::CardType::SYMBOL_SPACE)?;
+    buffer.fill(T::ZERO);
+    for card in set.clone(){
+        buffer[card.usize_index()] = T::ONE;
+    }
+    Ok(())
+}
+
+/// Decodes set of cards from multi-hot vector of length [`CardSymbol::SYMBOL_SPACE`] of card type.
+pub fn decode_set<C: CardSet, T: EncodingElement>(buffer: &[T]) -> Result<C, EncodingError>{
+    check_length(buffer, C::CardType::SYMBOL_SPACE)?;
+    let mut set = C::empty();
+    for (i, e) in buffer.iter().enumerate(){
+        match *e{
+            e if e == T::ONE => set.insert_card_noerr(symbol_at(i)),
+            e if e == T::ZERO => (),
+            _ => return Err(EncodingError::InvalidValue(i))
+        }
+    }
+    Ok(set)
+}
+
+/// Encodes set of standard cards as `4 x 13` planes: row for every suit (`Clubs, Diamonds, Hearts, Spades`),
+/// column for every figure (`2, 3, ..., King, Ace`).
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::encoding::{decode_set_planes, encode_set_planes};
+/// let hand = card_set![KING_HEARTS, TWO_DIAMONDS];
+/// let planes = encode_set_planes::<f32>(&hand);
+/// assert_eq!(planes[2][11], 1.0);
+/// assert_eq!(planes[1][0], 1.0);
+/// assert_eq!(planes.iter().flatten().sum::<f32>(), 2.0);
+/// assert_eq!(decode_set_planes(&planes), Ok(hand));
+/// ```
+pub fn encode_set_planes<T: EncodingElement>(set: &CardSetStd) -> [[T; Figure::SYMBOL_SPACE]; Suit::SYMBOL_SPACE]{
+    let mut planes = [[T::ZERO; Figure::SYMBOL_SPACE]; Suit::SYMBOL_SPACE];
+    for card in *set{
+        planes[card.suit.usize_index()][card.figure.usize_index()] = T::ONE;
+    }
+    planes
+}
+
+/// Reverse of [`encode_set_planes`].
+pub fn decode_set_planes<T: EncodingElement>(planes: &[[T; Figure::SYMBOL_SPACE]; Suit::SYMBOL_SPACE]) -> Result<CardSetStd, EncodingError>{
+    decode_set(planes.as_flattened())
+}
+
+/// Encodes multiset of symbols as count plane of length [`CardSymbol::SYMBOL_SPACE`]:
+/// element is number of occurrences of symbol (e.g. cards seen in multi-deck games, suits of cards played).
+/// ```
+/// use karty::cards::*;
+/// use karty::encoding::{decode_counts, encode_counts};
+/// use karty::error::EncodingError;
+/// use karty::suits::Suit;
+/// let mut buffer = [0.0f32; 52];
+/// encode_counts([ACE_SPADES, ACE_SPADES, TWO_CLUBS], &mut buffer).unwrap();
+/// assert_eq!((buffer[51], buffer[0], buffer[1]), (2.0, 1.0, 0.0));
+/// let mut counts = [0u8; 52];
+/// decode_counts::<Card, _>(&buffer, &mut counts).unwrap();
+/// assert_eq!(counts[51], 2);
+/// let mut suits = [0u8; 4];
+/// assert_eq!(encode_counts(std::iter::repeat_n(Suit::Hearts, 256), &mut suits), Err(EncodingError::CountOverflow(2)));
+/// ```
+pub fn encode_counts<S: CardSymbol, T: EncodingElement, I: IntoIterator<Item = S>>(symbols: I, buffer: &mut [T]) -> Result<(), EncodingError>{
+    check_length(buffer, S::SYMBOL_SPACE)?;
+    buffer.fill(T::ZERO);
+    for symbol in symbols{
+        let i = symbol.usize_index();
+        let count = buffer[i].to_count()
+            .expect("Buffer holds counts (should not happen, it is a bug).");
+        buffer[i] = T::from_count(count.checked_add(1).ok_or(EncodingError::CountOverflow(i))?);
+    }
+    Ok(())
+}
+
+/// Reverse of [`encode_counts`], counts are written to `counts` (indexed by [`CardSymbol::usize_index`]).
+pub fn decode_counts<S: CardSymbol, T: EncodingElement>(buffer: &[T], counts: &mut [u8]) -> Result<(), EncodingError>{
+    check_length(buffer, S::SYMBOL_SPACE)?;
+    check_length(counts, S::SYMBOL_SPACE)?;
+    for (i, (e, count)) in buffer.iter().zip(counts.iter_mut()).enumerate(){
+        *count = e.to_count().ok_or(EncodingError::InvalidValue(i))?;
+    }
+    Ok(())
+}
+
+/// Encodes sequence of symbols (e.g. cards played in order) as `max_len` one-hot rows of length
+/// [`CardSymbol::SYMBOL_SPACE`], flattened row-major to buffer of length `max_len * SYMBOL_SPACE`.
+/// Row `i` encodes `i`-th symbol, rows after the end of sequence are all zeros.
+/// ```
+/// use karty::cards::*;
+/// use karty::encoding::encode_sequence;
+/// let mut buffer = [0u8; 4 * 52];
+/// encode_sequence(&[KING_HEARTS, ACE_HEARTS], 4, &mut buffer).unwrap();
+/// assert_eq!(buffer[37], 1);
+/// assert_eq!(buffer[52 + 38], 1);
+/// assert!(buffer[104..].iter().all(|e| *e == 0));
+/// assert!(encode_sequence(&[KING_HEARTS, ACE_HEARTS], 1, &mut buffer[..52]).is_err());
+/// ```
+pub fn encode_sequence<S: CardSymbol, T: EncodingElement>(symbols: &[S], max_len: usize, buffer: &mut [T]) -> Result<(), EncodingError>{
+    check_length(buffer, max_len * S::SYMBOL_SPACE)?;
+    if symbols.len() > max_len{
+        return Err(EncodingError::SequenceTooLong{max: max_len, found: symbols.len()})
+    }
+    buffer.fill(T::ZERO);
+    for (row, symbol) in symbols.iter().enumerate(){
+        buffer[row * S::SYMBOL_SPACE + symbol.usize_index()] = T::ONE;
+    }
+    Ok(())
+}
+
+/// Reverse of [`encode_sequence`]: decodes symbols from rows until the first all-zero row,
+/// all following rows must be zeros as well.
+/// ```
+/// use karty::cards::*;
+/// use karty::encoding::{decode_sequence, encode_sequence};
+/// let trick = [TEN_CLUBS, KING_CLUBS, TWO_DIAMONDS];
+/// let mut buffer = [0.0f32; 4 * 52];
+/// encode_sequence(&trick, 4, &mut buffer).unwrap();
+/// assert_eq!(decode_sequence::<Card, _>(&buffer, 4).unwrap(), trick.to_vec());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_sequence<S: CardSymbol, T: EncodingElement>(buffer: &[T], max_len: usize) -> Result<alloc::vec::Vec<S>, EncodingError>{
+    check_length(buffer, max_len * S::SYMBOL_SPACE)?;
+    let mut symbols = alloc::vec::Vec::new();
+    let mut ended = false;
+    for (row, e) in buffer.chunks(S::SYMBOL_SPACE).enumerate(){
+        let offset = row * S::SYMBOL_SPACE;
+        match (hot_index(e, offset)?, ended){
+            (Some(i), false) => symbols.push(symbol_at(i)),
+            (Some(_), true) => return Err(EncodingError::NotOneHot(offset)),
+            (None, _) => ended = true
+        }
+    }
+    Ok(symbols)
+}
+
+/// Encodes values of [`SuitMap`] as vector of length `4`, in suit order `Clubs, Diamonds, Hearts, Spades`.
+/// ```
+/// use karty::encoding::{decode_suit_map, encode_suit_map};
+/// use karty::suits::SuitMap;
+/// let lengths = SuitMap::new(5u8, 3, 3, 2);
+/// let mut buffer = [0.0f32; 4];
+/// encode_suit_map(&lengths, &mut buffer).unwrap();
+/// assert_eq!(buffer, [2.0, 3.0, 3.0, 5.0]);
+/// assert_eq!(decode_suit_map(&buffer).unwrap(), lengths.map(|v| *v as f32));
+/// ```
+pub fn encode_suit_map<V: Copy, T: From<V>>(map: &SuitMap<V>, buffer: &mut [T]) -> Result<(), EncodingError>{
+    check_length(buffer, Suit::SYMBOL_SPACE)?;
+    for suit in SUITS{
+        buffer[suit.usize_index()] = T::from(map[suit]);
+    }
+    Ok(())
+}
+
+/// Reverse of [`encode_suit_map`].
+pub fn decode_suit_map<T: Copy>(buffer: &[T]) -> Result<SuitMap<T>, EncodingError>{
+    check_length(buffer, Suit::SYMBOL_SPACE)?;
+    Ok(SuitMap::new_from_f(|suit| buffer[suit.usize_index()]))
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::{Card, STANDARD_DECK};
+    use crate::encoding::{decode_set, decode_set_planes, decode_symbol, encode_set, encode_set_planes, encode_symbol};
+    use crate::error::EncodingError;
+    use crate::set::{CardSet, CardSetStd};
+    use crate::symbol::CardSymbol;
+    use crate::tarot::{TarotCard, TarotCardSet, TAROT_DECK};
+
+    #[test]
+    fn symbols_round_trip(){
+        let mut buffer = [0u8; 52];
+        for card in STANDARD_DECK{
+            encode_symbol(&card, &mut buffer).unwrap();
+            assert_eq!(decode_symbol::<Card, _>(&buffer), Ok(card));
+        }
+        assert_eq!(encode_symbol(&STANDARD_DECK[0], &mut buffer[..51]),
+            Err(EncodingError::WrongBufferLength{expected: 52, found: 51}));
+    }
+
+    #[test]
+    fn planes_agree_with_flat_encoding(){
+        let set = CardSetStd::from_iterator(STANDARD_DECK.into_iter().filter(|c| c.usize_index() % 3 == 0));
+        let mut flat = [0.0f32; 52];
+        encode_set(&set, &mut flat).unwrap();
+        let planes = encode_set_planes::<f32>(&set);
+        assert_eq!(planes.as_flattened(), &flat[..]);
+        assert_eq!(decode_set_planes(&planes), Ok(set));
+        flat[5] = 0.5;
+        assert_eq!(decode_set::<CardSetStd, _>(&flat), Err(EncodingError::InvalidValue(5)));
+    }
+
+    #[test]
+    fn tarot_set(){
+        let mut buffer = [0u8; TarotCard::SYMBOL_SPACE];
+        encode_set(&TAROT_DECK, &mut buffer).unwrap();
+        assert!(buffer.iter().all(|e| *e == 1));
+        assert_eq!(decode_set::<TarotCardSet, _>(&buffer), Ok(TAROT_DECK));
+    }
+}
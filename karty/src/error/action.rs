@@ -0,0 +1,18 @@
+use crate::error::EncodingError;
+
+/// Error of mapping between policy actions and symbols, see [`action`](crate::action).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ActionError{
+    /// Mask or vector of scores does not match action space.
+    #[error(transparent)]
+    Encoding(#[from] EncodingError),
+    /// Mask has no legal action.
+    #[error("No legal action in mask")]
+    NoLegalAction,
+    /// Probability at given position is not a finite number or is negative.
+    #[error("Invalid probability at position {0}")]
+    InvalidProbability(usize),
+    /// Score (e.g. logit) at given position is not a number.
+    #[error("Invalid score at position {0}")]
+    InvalidScore(usize),
+}
@@ -0,0 +1,23 @@
+use crate::games::bridge::Bid;
+
+/// Error of operations on bridge primitives: bids, auctions and contracts.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BridgeError{
+    #[error("Bid level must be in range 1-7, found {0}")]
+    WrongBidLevel(u8),
+    #[error("Bid {bid} is not higher than last bid {last}")]
+    InsufficientBid{
+        bid: Bid,
+        last: Bid
+    },
+    #[error("Double is allowed only directly after opponent's bid")]
+    DoubleNotAllowed,
+    #[error("Redouble is allowed only directly after opponent's double")]
+    RedoubleNotAllowed,
+    #[error("Auction is already finished")]
+    AuctionFinished,
+    #[error("Number of tricks must be in range 0-13, found {0}")]
+    WrongTricksNumber(u8),
+    #[error("Parse error")]
+    ParseError
+}
@@ -21,5 +21,17 @@ pub enum CardError{
     #[error("Mask space violated")]
     MaskSpaceViolated,
     #[error("Parse error")]
-    ParseError
+    ParseError,
+    /// Instance indicating that character is not a glyph of standard card in Unicode block _Playing Cards_.
+    #[error("Character {0:?} is not a glyph of standard playing card")]
+    NotPlayingCardChar(char),
+    /// Instance indicating that order of symbols is not valid (symbol repeated or unknown).
+    #[error("Invalid order of symbols (symbol repeated or unknown)")]
+    InvalidOrder,
+    /// Instance indicating that tarot trump number is not in `1..=21`, associated value is violating value.
+    #[error("Invalid tarot trump number {0}, expected 1..=21")]
+    InvalidTrumpNumber(u8),
+    /// Instance indicating that number of tarot suit card is not in `1..=10`, associated value is violating value.
+    #[error("Invalid tarot card number {0}, expected 1..=10")]
+    InvalidTarotNumber(u8)
 }
\ No newline at end of file
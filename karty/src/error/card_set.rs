@@ -20,10 +20,11 @@ pub enum CardSetErrorGen<Crd: CardSymbol>{
     DifferentLengths(usize, usize),
     #[error("Parse error")]
     ParseError,
+    #[cfg(feature = "alloc")]
     #[error("Card sets are not equal (but they were expected to be). Expected: {expected:?}, found: {found:?}")]
     ExpectedEqualCardSets{
-        expected: Vec<Crd>,
-        found: Vec<Crd>
+        expected: alloc::vec::Vec<Crd>,
+        found: alloc::vec::Vec<Crd>
     }
 
 }
@@ -0,0 +1,54 @@
+use crate::codec::RecordKind;
+
+/// Error of compact binary codec, see [`codec`](crate::codec).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum CodecError{
+    /// Buffer is shorter than required by encoded record.
+    #[error("Buffer too short: expected at least {expected} bytes, found {found}")]
+    BufferTooShort{
+        expected: usize,
+        found: usize
+    },
+    /// Record was encoded with unknown version of codec.
+    #[error("Unsupported codec version {0}")]
+    UnsupportedVersion(u8),
+    /// Header announces record of different kind.
+    #[error("Wrong record kind: expected {expected:?}, found {found}")]
+    WrongKind{
+        expected: RecordKind,
+        found: u8
+    },
+    /// Encoded mask has bits not representing any card.
+    #[error("Invalid card set mask {0:#x}")]
+    InvalidMask(u64),
+    /// Encoded set size exceeds deck size.
+    #[error("Invalid card set size {0}")]
+    InvalidSetSize(usize),
+    /// Colex rank is not lesser than number of subsets of given size.
+    #[error("Rank {rank} out of range for set of {size} cards")]
+    InvalidRank{
+        size: usize,
+        rank: u64
+    },
+    /// Hands of deal overlap or do not cover whole deck.
+    #[error("Hands do not partition deck")]
+    NotPartition,
+    /// Deal has more hands than can be encoded.
+    #[error("Deal of {0} hands can not be encoded")]
+    TooManyHands(usize),
+    /// Record holds deal with different number of hands.
+    #[error("Wrong number of hands: expected {expected}, found {found}")]
+    WrongHandCount{
+        expected: usize,
+        found: usize
+    },
+    /// Card is assigned to hand out of range.
+    #[error("Invalid hand index {0}")]
+    InvalidHand(usize),
+    /// Encoded index does not represent any card.
+    #[error("Invalid card index {0}")]
+    InvalidCardIndex(usize),
+    /// Length prefix is malformed or overflows.
+    #[error("Invalid length prefix")]
+    InvalidLength,
+}
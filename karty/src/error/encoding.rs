@@ -0,0 +1,31 @@
+/// Error of encoding cards into numeric buffers or decoding them back, see [`encoding`](crate::encoding).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum EncodingError{
+    /// Buffer has length different from required by encoded shape.
+    #[error("Wrong length of buffer: expected {expected}, found {found}")]
+    WrongBufferLength{
+        expected: usize,
+        found: usize
+    },
+    /// Value at given position of buffer is neither zero nor one (or not a valid count in count planes).
+    #[error("Invalid value in buffer at position {0}")]
+    InvalidValue(usize),
+    /// One-hot row (starting at given position of buffer) does not have exactly one hot element.
+    #[error("Row starting at position {0} is not one-hot")]
+    NotOneHot(usize),
+    /// Sequence is longer than number of rows reserved for it.
+    #[error("Sequence of length {found} does not fit in {max} rows")]
+    SequenceTooLong{
+        max: usize,
+        found: usize
+    },
+    /// Array has shape different from required by encoding.
+    #[error("Wrong shape of array: expected {expected:?}, found {found:?}")]
+    WrongShape{
+        expected: (usize, usize),
+        found: (usize, usize)
+    },
+    /// Count of symbol does not fit in encoded element.
+    #[error("Count at position {0} overflows")]
+    CountOverflow(usize),
+}
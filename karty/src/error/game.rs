@@ -0,0 +1,24 @@
+use crate::cards::Card;
+use crate::error::TrickError;
+
+/// Error of operations on game states (see [`games`](crate::games) and [`TrickRound`](crate::trick::TrickRound)).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GameError{
+    #[error("Card {0:?} is not in hand of current player")]
+    CardNotInHand(Card),
+    #[error("Card {0:?} cannot be legally played now")]
+    IllegalMove(Card),
+    #[error("Action is not allowed in current phase of game")]
+    WrongPhase,
+    #[error("Expected {expected} cards, found {found}")]
+    WrongNumberOfCards{
+        expected: usize,
+        found: usize
+    },
+    #[error("Bid is not allowed: {0}")]
+    WrongBid(String),
+    #[error("Round is already finished")]
+    RoundFinished,
+    #[error("Trick error: {0}")]
+    Trick(#[from] TrickError)
+}
@@ -4,3 +4,25 @@ pub use card::CardError;
 mod card_set;
 
 pub use card_set::{CardSetError, CardSetErrorGen};
+
+mod trick;
+pub use trick::{TrickError, TrickErrorGen};
+
+mod encoding;
+pub use encoding::EncodingError;
+
+mod action;
+pub use action::ActionError;
+
+mod codec;
+pub use codec::CodecError;
+
+#[cfg(feature = "std")]
+mod bridge;
+#[cfg(feature = "std")]
+pub use bridge::BridgeError;
+
+#[cfg(feature = "std")]
+mod game;
+#[cfg(feature = "std")]
+pub use game::GameError;
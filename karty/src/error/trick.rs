@@ -0,0 +1,13 @@
+use crate::cards::Card;
+use crate::symbol::CardSymbol;
+
+/// Error of operations on [`Trick`](crate::trick::Trick).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TrickErrorGen<Crd: CardSymbol>{
+    #[error("Trick is already complete")]
+    TrickFull,
+    #[error("Card {0:?} is already in trick")]
+    CardDuplicated(Crd),
+}
+
+pub type TrickError = TrickErrorGen<Card>;
@@ -0,0 +1,88 @@
+//! Module containing comparators of standard figures implementing non-default orders used in
+//! particular games (default order is implemented by [`FigureComparator`](crate::figures::FigureComparator)).
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use core::cmp::Ordering;
+use comparator::Comparator;
+use crate::figures::{Ace, Figure, FigureComparator, Jack, King, Queen};
+use crate::figures::F10;
+
+/// Comparator of figures used in suit and grand games of Skat:
+/// `7 < 8 < 9 < Queen < King < 10 < Ace < Jack`. Jacks are placed on top, because they are always
+/// trumps, see [`CARD_COMPARATOR_SKAT`](crate::cards::CARD_COMPARATOR_SKAT) for ordering of jacks between themselves.
+/// Figures below 7 (not present in Skat deck) are ordered naturally below 7.
+/// ```
+/// use comparator::Comparator;
+/// use karty::figures::*;
+/// use core::cmp::Ordering;
+/// let cmp = SkatFigureComparator{};
+/// assert_eq!(cmp.compare(&F10, &King), Ordering::Greater);
+/// assert_eq!(cmp.compare(&F10, &Ace), Ordering::Less);
+/// assert_eq!(cmp.compare(&F9, &Queen), Ordering::Less);
+/// assert_eq!(cmp.compare(&Jack, &Ace), Ordering::Greater);
+/// ```
+#[derive(Default, Clone, Copy, Debug)]
+pub struct SkatFigureComparator {
+}
+
+impl SkatFigureComparator{
+    fn rank(figure: &Figure) -> usize{
+        match figure{
+            Jack => 14,
+            Ace => 13,
+            f if f == &F10 => 12,
+            King => 11,
+            Queen => 10,
+            f => f.usize_index()
+        }
+    }
+}
+
+impl Comparator<Figure> for SkatFigureComparator {
+    fn compare(&self, l: &Figure, r: &Figure) -> Ordering {
+        Self::rank(l).cmp(&Self::rank(r))
+    }
+}
+
+/// Comparator of figures with ten placed between ace and king:
+/// `9 < Jack < Queen < King < 10 < Ace`, used in Tysiąc (1000), Schnapsen (66) and similar games.
+/// Figures below 9 are ordered naturally below 9.
+/// ```
+/// use comparator::Comparator;
+/// use karty::figures::*;
+/// use core::cmp::Ordering;
+/// let cmp = AceTenFigureComparator{};
+/// assert_eq!(cmp.compare(&F10, &King), Ordering::Greater);
+/// assert_eq!(cmp.compare(&F10, &Ace), Ordering::Less);
+/// assert_eq!(cmp.compare(&Jack, &F9), Ordering::Greater);
+/// assert_eq!(cmp.compare(&Jack, &Queen), Ordering::Less);
+/// ```
+#[derive(Default, Clone, Copy, Debug)]
+pub struct AceTenFigureComparator {
+}
+
+impl AceTenFigureComparator{
+    fn rank(figure: &Figure) -> usize{
+        match figure{
+            Ace => 13,
+            f if f == &F10 => 12,
+            King => 11,
+            Queen => 10,
+            Jack => 9,
+            f => f.usize_index()
+        }
+    }
+}
+
+impl Comparator<Figure> for AceTenFigureComparator {
+    fn compare(&self, l: &Figure, r: &Figure) -> Ordering {
+        Self::rank(l).cmp(&Self::rank(r))
+    }
+}
+
+/// Comparator of figures used in null games of Skat: `7 < 8 < 9 < 10 < Jack < Queen < King < Ace`.
+/// It is the natural order, so this is alias of [`FigureComparator`].
+pub type NullFigureComparator = FigureComparator;
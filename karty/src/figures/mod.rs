@@ -2,6 +2,10 @@ mod r#trait;
 
 mod standard;
 pub use standard::*;
+mod comparators;
+pub use comparators::*;
+mod order;
+pub use order::*;
 
 pub use r#trait::*;
 
@@ -0,0 +1,188 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use comparator::Comparator;
+use crate::error::CardError;
+use crate::figures::{Figure, FIGURES};
+use crate::symbol::CardSymbol;
+
+/// Order of figures chosen at runtime (e.g. loaded from game configuration).
+/// Figures are listed in ascending order. Not every figure must be listed: figures not listed
+/// (not used in game) are lower than all listed ones and keep natural order between themselves.
+/// [`Default`] order is natural (`23456789TJQKA`).
+/// ```
+/// use comparator::Comparator;
+/// use core::cmp::Ordering;
+/// use core::str::FromStr;
+/// use karty::figures::{FigureOrder, Ace, King, F10, F9, F2};
+/// let ace_ten = FigureOrder::from_str("9JQKTA").unwrap();
+/// assert_eq!(ace_ten.compare(&F10, &King), Ordering::Greater);
+/// assert_eq!(ace_ten.compare(&F10, &Ace), Ordering::Less);
+/// assert_eq!(ace_ten.compare(&F2, &F9), Ordering::Less);
+/// assert_eq!(ace_ten.to_string(), "23456789JQKTA");
+/// assert!(FigureOrder::from_str("9JQKTT").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[u8; 13]", into = "[u8; 13]"))]
+pub struct FigureOrder{
+    /// Rank of figure indexed by [`CardSymbol::usize_index`] of figure, `0` for the lowest
+    ranks: [u8; Figure::SYMBOL_SPACE]
+}
+
+impl FigureOrder{
+    /// Creates order from figures listed in ascending order, unlisted figures are placed below them.
+    /// # Returns:
+    /// `Err` if some figure is listed more than once.
+    pub fn new(ascending: &[Figure]) -> Result<Self, CardError>{
+        let mut ranks = [0u8; Figure::SYMBOL_SPACE];
+        let mut listed = [false; Figure::SYMBOL_SPACE];
+        for figure in ascending{
+            match listed[figure.usize_index()]{
+                true => return Err(CardError::InvalidOrder),
+                false => listed[figure.usize_index()] = true
+            }
+        }
+        let unlisted = FIGURES.iter().filter(|f| !listed[f.usize_index()]);
+        for (rank, figure) in unlisted.chain(ascending.iter()).enumerate(){
+            ranks[figure.usize_index()] = rank as u8;
+        }
+        Ok(Self{ranks})
+    }
+
+    /// Position of figure in order, `0` for the lowest.
+    pub fn rank(&self, figure: &Figure) -> usize{
+        self.ranks[figure.usize_index()] as usize
+    }
+
+    /// All figures in ascending order.
+    pub fn figures(&self) -> [Figure; Figure::SYMBOL_SPACE]{
+        let mut figures = FIGURES;
+        figures.sort_unstable_by_key(|f| self.rank(f));
+        figures
+    }
+}
+
+/// Creates order from ranks indexed by [`CardSymbol::usize_index`] of figure.
+/// # Returns:
+/// `Err` if ranks are not permutation of `0..13`.
+impl TryFrom<[u8; Figure::SYMBOL_SPACE]> for FigureOrder{
+    type Error = CardError;
+
+    fn try_from(ranks: [u8; Figure::SYMBOL_SPACE]) -> Result<Self, Self::Error> {
+        let mut used = [false; Figure::SYMBOL_SPACE];
+        for rank in ranks{
+            match used.get(rank as usize){
+                Some(false) => used[rank as usize] = true,
+                _ => return Err(CardError::InvalidOrder)
+            }
+        }
+        Ok(Self{ranks})
+    }
+}
+
+impl From<FigureOrder> for [u8; Figure::SYMBOL_SPACE]{
+    fn from(order: FigureOrder) -> Self {
+        order.ranks
+    }
+}
+
+impl Default for FigureOrder{
+    fn default() -> Self {
+        Self{ranks: core::array::from_fn(|i| i as u8)}
+    }
+}
+
+impl Comparator<Figure> for FigureOrder{
+    fn compare(&self, l: &Figure, r: &Figure) -> Ordering {
+        self.rank(l).cmp(&self.rank(r))
+    }
+}
+
+/// Parses order from characters `23456789TJQKA` (case insensitive), lowest figure first.
+impl FromStr for FigureOrder{
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut figures = [Figure::Ace; Figure::SYMBOL_SPACE];
+        let mut count = 0;
+        for c in s.trim().chars(){
+            let figure = FIGURES.iter().find(|f| f.repr_char() == c.to_ascii_uppercase())
+                .ok_or(CardError::InvalidOrder)?;
+            *figures.get_mut(count).ok_or(CardError::InvalidOrder)? = *figure;
+            count += 1;
+        }
+        Self::new(&figures[..count])
+    }
+}
+
+/// Implements [`Display`]: characters of all figures in ascending order.
+impl Display for FigureOrder{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for figure in self.figures(){
+            write!(f, "{}", figure.repr_char())?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads order with the same validation as [`TryFrom`].
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for FigureOrder{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let ranks = <[u8; Figure::SYMBOL_SPACE]>::read_from(reader)?;
+        FigureOrder::try_from(ranks).map_err(|e| speedy::Error::custom(e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use core::str::FromStr;
+    use std::string::ToString;
+    use comparator::Comparator;
+    use crate::figures::{AceTenFigureComparator, FigureComparator, FigureOrder, SkatFigureComparator, FIGURES};
+
+    fn assert_same_order<C: Comparator<crate::figures::Figure>>(order: &FigureOrder, comparator: C, figures: &str){
+        for l in FIGURES.iter().filter(|f| figures.contains(f.repr_char())){
+            for r in FIGURES.iter().filter(|f| figures.contains(f.repr_char())){
+                assert_eq!(order.compare(l, r), comparator.compare(l, r));
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_static_comparators(){
+        assert_same_order(&FigureOrder::default(), FigureComparator{}, "23456789TJQKA");
+        assert_same_order(&FigureOrder::from_str("9JQKTA").unwrap(), AceTenFigureComparator{}, "9TJQKA");
+        assert_same_order(&FigureOrder::from_str("789QKTAJ").unwrap(), SkatFigureComparator{}, "789TJQKA");
+        let order = FigureOrder::from_str("789QKTAJ").unwrap();
+        assert_eq!(FigureOrder::from_str(&order.to_string()), Ok(order));
+    }
+
+    #[test]
+    fn try_from_ranks_requires_permutation(){
+        let order = FigureOrder::from_str("9JQKTA").unwrap();
+        assert_eq!(FigureOrder::try_from(<[u8; 13]>::from(order)), Ok(order));
+        assert!(FigureOrder::try_from([0u8; 13]).is_err());
+        assert!(FigureOrder::try_from([13, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_ranks(){
+        let order = FigureOrder::from_str("789QKTAJ").unwrap();
+        assert_eq!(ron::from_str::<FigureOrder>(&ron::to_string(&order).unwrap()).unwrap(), order);
+        assert!(ron::from_str::<FigureOrder>("(0,0,0,0,0,0,0,0,0,0,0,0,0)").is_err());
+    }
+
+    #[cfg(feature = "speedy")]
+    #[test]
+    fn speedy_rejects_invalid_ranks(){
+        use speedy::{Readable, Writable};
+        let order = FigureOrder::from_str("9JQKTA").unwrap();
+        assert_eq!(FigureOrder::read_from_buffer(&order.write_to_vec().unwrap()).unwrap(), order);
+        let repeated = [200u8; 13].write_to_vec().unwrap();
+        assert!(FigureOrder::read_from_buffer(&repeated).is_err());
+    }
+}
@@ -18,19 +18,19 @@ use crate::figures::standard::{Figure, MAX_NUMBER_FIGURE, MIN_NUMBER_FIGURE, Num
 
  */
 fn parse_ace(s: &str) -> IResult<&str, Figure>{
-    alt((tag_no_case("ace"), tag_no_case("a"))).parse(s)
+    alt((tag_no_case("ace"), tag_no_case("a"), tag("𝑨"))).parse(s)
         .map(|(i, _)| (i, Figure::Ace))
 }
 fn parse_king(s: &str) -> IResult<&str, Figure>{
-    alt((tag_no_case("king"), tag_no_case("k"))).parse(s)
+    alt((tag_no_case("king"), tag_no_case("k"), tag("𝑲"))).parse(s)
         .map(|(i, _)| (i, Figure::King))
 }
 fn parse_queen(s: &str) -> IResult<&str, Figure>{
-    alt((tag_no_case("queen"), tag_no_case("q"))).parse(s)
+    alt((tag_no_case("queen"), tag_no_case("q"), tag("𝑸"))).parse(s)
         .map(|(i, _)| (i, Figure::Queen))
 }
 fn parse_jack(s: &str) -> IResult<&str, Figure>{
-    alt((tag_no_case("jack"), tag_no_case("j"))).parse(s)
+    alt((tag_no_case("jack"), tag_no_case("j"), tag("𝑱"))).parse(s)
         .map(|(i, _)| (i, Figure::Jack))
 }
 fn parse_ten(s: &str) -> IResult<&str, Figure>{
@@ -117,6 +117,7 @@ pub fn parse_high_figure(s: &str) -> IResult<&str, Figure>{
 /// assert_eq!(parse_figure("kc"), Ok(("c", Figure::King)));
 /// assert_eq!(parse_figure("qdiamonds"), Ok(("diamonds", Figure::Queen)));
 /// assert_eq!(parse_figure("9hggg"), Ok(("hggg", F9)));
+/// assert_eq!(parse_figure("𝑸♥"), Ok(("♥", Figure::Queen)));
 /// assert_eq!(parse_figure("deadfish"), Err(nom::Err::Error(nom::error::Error::new("deadfish", ErrorKind::Tag))));
 /// ```
 pub fn parse_figure(s: &str) -> IResult<&str, Figure>{
@@ -4,8 +4,8 @@
 //! # Authors:
 //! [morovintaas](mailto:moroviintaas@gmail.com)
 //!
-use std::cmp::Ordering;
-use std::fmt::{Debug, Formatter};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
 use comparator::Comparator;
 #[cfg(feature = "random")]
 use karty_proc_macro::RandomSymbol;
@@ -31,18 +31,23 @@ impl NumberFigure {
     /// # Panics:
     /// When power is lesser than [`MIN_NUMBER_FIGURE`](crate::figures::MIN_NUMBER_FIGURE) or greater than [`MAX_NUMBER_FIGURE`](crate::figures::MAX_NUMBER_FIGURE).
     ///
-    pub fn new(power: u8) -> Self{
+    /// ```
+    /// use karty::figures::{NumberFigure, F7};
+    /// const SEVEN: NumberFigure = NumberFigure::new(7);
+    /// assert_eq!(karty::figures::Figure::Numbered(SEVEN), F7);
+    /// ```
+    pub const fn new(power: u8) -> Self{
         match power{
             legit @MIN_NUMBER_FIGURE..=MAX_NUMBER_FIGURE => Self{power: legit},
-            e => panic!("Invalid power value {e:?}")
+            _ => panic!("Invalid power value of number figure (must be in 2..=10)")
         }
     }
 
     /// Returns order number (could be interpreted as [`CardSymbol`](crate::symbol::CardSymbol) if figure is stand alone symbol).
     /// # Warn!
     /// Might be deleted in the future.
-    pub fn order_number(&self) -> usize {
-        usize::from(self.power - 2 )
+    pub const fn order_number(&self) -> usize {
+        (self.power - 2) as usize
     }
 
 
@@ -61,7 +66,7 @@ impl NumberFigure {
     /// assert_eq!(F10.mask(), 0x400);
     ///
     /// ```
-    pub fn mask(&self) -> u64{
+    pub const fn mask(&self) -> u64{
         1u64<<self.power
     }
 
@@ -71,18 +76,31 @@ impl NumberFigure {
     /// assert_eq!(NumberFigure::new(9).repr_char(), '9');
     /// assert_eq!(NumberFigure::new(10).repr_char(), 'T');
     /// ```
-    pub fn repr_char(&self) -> char{
+    pub const fn repr_char(&self) -> char{
         match self.power{
             10 => 'T',
             legit @ 2..=9 => (0x30 + legit) as char,
-            bad => panic!("Bad number in NumberedFigure:{bad:} (should not happen, it is a bug).")
+            _ => panic!("Bad number in NumberedFigure (should not happen, it is a bug).")
+        }
+    }
+
+    /// Const version of [`CardSymbol::usize_index`].
+    pub const fn usize_index(&self) -> usize {
+        self.order_number()
+    }
+
+    /// Const version of [`CardSymbol::from_usize_index`].
+    pub const fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        match position{
+            p@ 0..=8 => Ok(Self{power: (p + 2) as u8 }),
+            s => Err(CardError::WrongFigurePosition(s))
         }
     }
 
 }
 
-impl std::fmt::Display for NumberFigure {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for NumberFigure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.power)
     }
 }
@@ -109,7 +127,7 @@ impl CardSymbol for NumberFigure {
     /// assert_eq!(NumberFigure::new(10).usize_index(), 8);
     /// ```
     fn usize_index(&self) -> usize {
-        (self.power - 2) as usize
+        NumberFigure::usize_index(self)
     }
 
     ///
@@ -120,10 +138,7 @@ impl CardSymbol for NumberFigure {
     /// assert_eq!(NumberFigure::from_usize_index(3).unwrap(), NumberFigure::new(5));
     /// ```
     fn from_usize_index(position: usize) -> Result<Self, CardError> {
-        match position{
-            p@ 0..=8 => Ok(Self{power: (p + 2) as u8 }),
-            s => Err(CardError::WrongFigurePosition(s))
-        }
+        NumberFigure::from_usize_index(position)
     }
 }
 
@@ -161,7 +176,7 @@ impl Figure {
     /// assert_eq!(F10.mask(),              0b0000010000000000);
     /// assert_eq!(F2.mask(),               0b0000000000000100);
     /// ```
-    pub fn mask(&self) -> u64{
+    pub const fn mask(&self) -> u64{
         match self{
             Figure::Ace => 0x4000,
             Figure::King => 0x2000,
@@ -172,7 +187,14 @@ impl Figure {
         }
     }
 
-    fn power(&self) -> u8{
+    /// Power of figure: `2..=10` for numbered figures, `11` for jack up to `14` for ace.
+    /// ```
+    /// use karty::figures::{Figure, F10};
+    /// const POWER: u8 = Figure::Queen.power();
+    /// assert_eq!(POWER, 12);
+    /// assert_eq!(F10.power(), 10);
+    /// ```
+    pub const fn power(&self) -> u8{
         match self{
             Ace => 14,
             King=> 13,
@@ -181,7 +203,15 @@ impl Figure {
             Numbered(fig) => fig.power
         }
     }
-    fn from_power(power: u8) -> Option<Self>{
+    /// Reverse method to [`power`](Self::power).
+    /// ```
+    /// use karty::figures::{Figure, F2};
+    /// const DEUCE: Option<Figure> = Figure::from_power(2);
+    /// assert_eq!(DEUCE, Some(F2));
+    /// assert_eq!(Figure::from_power(14), Some(Figure::Ace));
+    /// assert_eq!(Figure::from_power(15), None);
+    /// ```
+    pub const fn from_power(power: u8) -> Option<Self>{
         match power{
             14 => Some(Self::Ace),
             13 => Some(Self::King),
@@ -199,7 +229,7 @@ impl Figure {
     /// assert_eq!(Figure::from_mask(0x2000).unwrap(), King);
     ///
     /// ```
-    pub fn from_mask(mask: u64) -> Option<Self>{
+    pub const fn from_mask(mask: u64) -> Option<Self>{
         if mask.count_ones() != 1{
             None
         }
@@ -210,7 +240,7 @@ impl Figure {
         
     }
 
-    pub fn repr_char(&self) -> char{
+    pub const fn repr_char(&self) -> char{
         match self{
             Ace => 'A',
             King => 'K',
@@ -221,6 +251,29 @@ impl Figure {
 
     }
 
+    /// Const version of [`CardSymbol::usize_index`].
+    pub const fn usize_index(&self) -> usize {
+        match self{
+            Ace => 12,
+            King => 11,
+            Queen => 10,
+            Jack => 9,
+            Numbered(fig) => fig.order_number()
+        }
+    }
+
+    /// Const version of [`CardSymbol::from_usize_index`].
+    pub const fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        match position{
+            p@ 0..=8 => Ok(Numbered(NumberFigure{power: (p + 2) as u8})),
+            9 => Ok(Jack),
+            10 => Ok(Queen),
+            11 => Ok(King),
+            12 => Ok(Ace),
+            s => Err(CardError::WrongFigurePosition(s))
+        }
+    }
+
 
 
 
@@ -238,13 +291,7 @@ impl CardSymbol for Figure {
     /// assert_eq!(Queen.usize_index(), 10);
     /// ```
     fn usize_index(&self) -> usize {
-        match self{
-            Ace => 12,
-            King => 11,
-            Queen => 10,
-            Jack => 9,
-            Numbered(fig) => fig.order_number()
-        }
+        Figure::usize_index(self)
     }
     /// ```
     /// use karty::figures::{F5, F8, Figure, King};
@@ -254,14 +301,7 @@ impl CardSymbol for Figure {
     /// assert_eq!(Figure::from_usize_index(11).unwrap(), King);
     /// ```
     fn from_usize_index(position: usize) -> Result<Self, CardError> {
-        match position{
-            p@ 0..=8 => Ok(Numbered(NumberFigure::from_usize_index(p)?)),
-            9 => Ok(Jack),
-            10 => Ok(Queen),
-            11 => Ok(King),
-            12 => Ok(Ace),
-            s => Err(CardError::WrongFigurePosition(s))
-        }
+        Figure::from_usize_index(position)
     }
 }
 
@@ -270,8 +310,8 @@ impl FigureTrait for Figure {
 
 }
 
-impl std::fmt::Display for Figure {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Figure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if f.alternate(){
             match self{
                 Ace => write!(f, "𝑨"),
@@ -302,8 +342,7 @@ pub const FIGURES: [Figure;13] = [Numbered(NumberFigure {power: 2}), Numbered(Nu
 
 impl PartialOrd for Figure {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.power().cmp(&other.power()))
-        //Some()
+        Some(self.cmp(other))
     }
 }
 
@@ -323,7 +362,10 @@ impl Comparator<Figure> for FigureComparator {
 }
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests{
+    use std::format;
+    use std::string::String;
     use crate::figures::{F10, F2};
     use crate::figures::standard::{Figure, NumberFigure};
 
@@ -0,0 +1,172 @@
+use crate::cards::Card;
+use crate::games::blackjack::card_value;
+use crate::register::Register;
+use crate::symbol::CardSymbol;
+
+/// Card counting system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CountingSystem{
+    /// Balanced count: 2-6 count +1, 7-9 count 0, tens and aces count -1
+    #[default]
+    HiLo,
+    /// Knock-out, unbalanced count: 2-7 count +1, 8-9 count 0, tens and aces count -1
+    KnockOut
+}
+
+impl CountingSystem{
+    /// Count value of card.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::games::blackjack::CountingSystem;
+    /// assert_eq!(CountingSystem::HiLo.tag(&SEVEN_CLUBS), 0);
+    /// assert_eq!(CountingSystem::KnockOut.tag(&SEVEN_CLUBS), 1);
+    /// assert_eq!(CountingSystem::HiLo.tag(&ACE_CLUBS), -1);
+    /// ```
+    pub fn tag(&self, card: &Card) -> i32{
+        match (self, card_value(card)){
+            (_, 2..=6) => 1,
+            (CountingSystem::KnockOut, 7) => 1,
+            (_, 10..=11) => -1,
+            _ => 0
+        }
+    }
+
+    /// Initial running count for number of decks: 0 for Hi-Lo, `4 - 4 * decks` for KO.
+    pub fn initial_count(&self, decks: usize) -> i32{
+        match self{
+            CountingSystem::HiLo => 0,
+            CountingSystem::KnockOut => 4 - 4 * decks as i32
+        }
+    }
+}
+
+/// Running count of cards seen from a shoe. It is a [`Register`] of cards:
+/// registering card adds its tag to running count, unregistering card reverts it.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::blackjack::{CardCounter, CountingSystem};
+/// use karty::register::Register;
+/// let mut counter = CardCounter::new(CountingSystem::HiLo, 2);
+/// for card in [TWO_CLUBS, FIVE_HEARTS, KING_SPADES, TWO_CLUBS]{
+///     counter.register(card);
+/// }
+/// assert_eq!(counter.running_count(), 2);
+/// assert!(counter.is_registered(&TWO_CLUBS));
+/// assert_eq!(counter.seen(&TWO_CLUBS), 2);
+/// assert!((counter.true_count() - 2.0 / (100.0 / 52.0)).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardCounter{
+    system: CountingSystem,
+    decks: usize,
+    running_count: i32,
+    seen: Vec<u16>,
+    seen_total: usize,
+}
+
+impl CardCounter{
+    pub fn new(system: CountingSystem, decks: usize) -> Self{
+        Self{
+            system,
+            decks,
+            running_count: system.initial_count(decks),
+            seen: vec![0; Card::SYMBOL_SPACE],
+            seen_total: 0
+        }
+    }
+
+    pub fn system(&self) -> CountingSystem{
+        self.system
+    }
+
+    pub fn decks(&self) -> usize{
+        self.decks
+    }
+
+    pub fn running_count(&self) -> i32{
+        self.running_count
+    }
+
+    /// Number of times card was seen.
+    pub fn seen(&self, card: &Card) -> u16{
+        self.seen[card.usize_index()]
+    }
+
+    /// Number of all cards seen.
+    pub fn seen_total(&self) -> usize{
+        self.seen_total
+    }
+
+    /// Number of decks not yet seen.
+    pub fn decks_remaining(&self) -> f64{
+        (self.decks * Card::SYMBOL_SPACE).saturating_sub(self.seen_total) as f64 / Card::SYMBOL_SPACE as f64
+    }
+
+    /// Running count divided by number of remaining decks (running count itself if no cards remain).
+    pub fn true_count(&self) -> f64{
+        let decks = self.decks_remaining();
+        match decks > 0.0{
+            true => self.running_count as f64 / decks,
+            false => self.running_count as f64
+        }
+    }
+
+    /// Resets count after shoe is reshuffled.
+    pub fn reset(&mut self){
+        *self = Self::new(self.system, self.decks);
+    }
+}
+
+impl Default for CardCounter{
+    fn default() -> Self {
+        Self::new(CountingSystem::default(), 1)
+    }
+}
+
+impl Register<Card> for CardCounter{
+    fn register(&mut self, card: Card) {
+        self.seen[card.usize_index()] += 1;
+        self.seen_total += 1;
+        self.running_count += self.system.tag(&card);
+    }
+
+    fn unregister(&mut self, card: &Card) {
+        let seen = &mut self.seen[card.usize_index()];
+        if *seen > 0{
+            *seen -= 1;
+            self.seen_total -= 1;
+            self.running_count -= self.system.tag(card);
+        }
+    }
+
+    fn is_registered(&self, card: &Card) -> bool {
+        self.seen(card) > 0
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::STANDARD_DECK;
+    use crate::games::blackjack::{CardCounter, CountingSystem};
+    use crate::register::Register;
+
+    #[test]
+    fn full_shoe_counts(){
+        let mut hi_lo = CardCounter::new(CountingSystem::HiLo, 2);
+        let mut ko = CardCounter::new(CountingSystem::KnockOut, 2);
+        for card in STANDARD_DECK.iter().chain(STANDARD_DECK.iter()){
+            hi_lo.register(*card);
+            ko.register(*card);
+        }
+        assert_eq!(hi_lo.running_count(), 0);
+        assert_eq!(ko.running_count(), 4);
+        ko.unregister(&STANDARD_DECK[0]);
+        assert_eq!(ko.running_count(), 3);
+        ko.reset();
+        assert_eq!(ko.running_count(), -4);
+        assert!(!ko.is_registered(&STANDARD_DECK[0]));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::cards::{Card, Card2SymTrait};
+use crate::figures::Ace;
+use crate::valuation::{CardValuation, BLACKJACK_VALUES};
+
+/// Best total a hand may have without busting
+pub const BLACKJACK: u8 = 21;
+
+/// Value of card in blackjack: number cards by their number, figures 10, ace 11 (counted as 1 when needed).
+/// ```
+/// use karty::cards::*;
+/// use karty::games::blackjack::card_value;
+/// assert_eq!(card_value(&SEVEN_HEARTS), 7);
+/// assert_eq!(card_value(&QUEEN_SPADES), 10);
+/// assert_eq!(card_value(&ACE_CLUBS), 11);
+/// ```
+pub fn card_value(card: &Card) -> u8{
+    BLACKJACK_VALUES.value(card) as u8
+}
+
+/// Value of blackjack hand. Hand is soft when it contains an ace counted as 11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandValue{
+    pub total: u8,
+    pub soft: bool,
+}
+
+impl HandValue{
+    /// Computes value of cards, counting as many aces as 11 as possible without busting.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::games::blackjack::HandValue;
+    /// assert_eq!(HandValue::of(&[ACE_HEARTS, SIX_CLUBS]), HandValue{total: 17, soft: true});
+    /// assert_eq!(HandValue::of(&[ACE_HEARTS, SIX_CLUBS, NINE_CLUBS]), HandValue{total: 16, soft: false});
+    /// assert_eq!(HandValue::of(&[ACE_HEARTS, ACE_CLUBS, NINE_CLUBS]), HandValue{total: 21, soft: true});
+    /// ```
+    pub fn of(cards: &[Card]) -> Self{
+        let mut total: u16 = cards.iter().map(|c| card_value(c) as u16).sum();
+        let mut soft_aces = cards.iter().filter(|c| c.figure() == Ace).count();
+        while total > BLACKJACK as u16 && soft_aces > 0{
+            total -= 10;
+            soft_aces -= 1;
+        }
+        Self{total: total.min(u8::MAX as u16) as u8, soft: soft_aces > 0}
+    }
+
+    pub fn is_soft(&self) -> bool{
+        self.soft
+    }
+
+    pub fn is_hard(&self) -> bool{
+        !self.soft
+    }
+
+    pub fn is_bust(&self) -> bool{
+        self.total > BLACKJACK
+    }
+}
+
+/// Checks if cards form a blackjack (natural): ace and ten-valued card as two first cards.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::blackjack::is_blackjack;
+/// assert!(is_blackjack(&[ACE_HEARTS, KING_CLUBS]));
+/// assert!(!is_blackjack(&[SEVEN_HEARTS, FOUR_CLUBS, KING_CLUBS]));
+/// ```
+pub fn is_blackjack(cards: &[Card]) -> bool{
+    cards.len() == 2 && HandValue::of(cards).total == BLACKJACK
+}
+
+/// Checks if value of cards exceeds 21.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::blackjack::is_bust;
+/// assert!(is_bust(&[KING_HEARTS, QUEEN_CLUBS, TWO_CLUBS]));
+/// assert!(!is_bust(&[KING_HEARTS, ACE_CLUBS, ACE_SPADES]));
+/// ```
+pub fn is_bust(cards: &[Card]) -> bool{
+    HandValue::of(cards).is_bust()
+}
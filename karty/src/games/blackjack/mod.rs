@@ -0,0 +1,20 @@
+//! Module with rules of Blackjack: hand values, multi-deck shoe, basic strategy and card counting.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+mod hand;
+mod strategy;
+pub use hand::*;
+pub use strategy::*;
+
+#[cfg(feature = "random")]
+mod shoe;
+#[cfg(feature = "random")]
+pub use shoe::*;
+
+#[cfg(feature = "register")]
+mod count;
+#[cfg(feature = "register")]
+pub use count::*;
@@ -0,0 +1,97 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::cards::{Card, STANDARD_DECK};
+
+/// Shoe of several shuffled standard decks. Cards are dealt until penetration (fraction of dealt cards)
+/// is reached, then shoe should be reshuffled.
+/// ```
+/// use karty::games::blackjack::Shoe;
+/// let mut rng = rand::rng();
+/// let mut shoe = Shoe::new(6, 0.75, &mut rng);
+/// assert_eq!(shoe.remaining(), 312);
+/// for _ in 0..234{
+///     assert!(!shoe.needs_reshuffle());
+///     shoe.draw().unwrap();
+/// }
+/// assert!(shoe.needs_reshuffle());
+/// shoe.reshuffle(&mut rng);
+/// assert_eq!(shoe.remaining(), 312);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shoe{
+    cards: Vec<Card>,
+    dealt: usize,
+    decks: usize,
+    penetration: f64,
+}
+
+impl Shoe{
+    /// Creates shuffled shoe of `decks` decks. Penetration is clamped to `0.0..=1.0`.
+    pub fn new<R: Rng + ?Sized>(decks: usize, penetration: f64, rng: &mut R) -> Self{
+        let mut shoe = Self{
+            cards: STANDARD_DECK.iter().copied().cycle().take(decks * STANDARD_DECK.len()).collect(),
+            dealt: 0,
+            decks,
+            penetration: penetration.clamp(0.0, 1.0)
+        };
+        shoe.reshuffle(rng);
+        shoe
+    }
+
+    pub fn decks(&self) -> usize{
+        self.decks
+    }
+
+    pub fn penetration(&self) -> f64{
+        self.penetration
+    }
+
+    /// Number of cards in shoe (dealt or not).
+    pub fn size(&self) -> usize{
+        self.cards.len()
+    }
+
+    pub fn dealt(&self) -> usize{
+        self.dealt
+    }
+
+    pub fn remaining(&self) -> usize{
+        self.cards.len() - self.dealt
+    }
+
+    /// Deals next card, `None` if shoe is empty.
+    pub fn draw(&mut self) -> Option<Card>{
+        let card = self.cards.get(self.dealt).copied()?;
+        self.dealt += 1;
+        Some(card)
+    }
+
+    /// Checks if penetration has been reached.
+    pub fn needs_reshuffle(&self) -> bool{
+        self.dealt as f64 >= self.penetration * self.cards.len() as f64
+    }
+
+    /// Collects all cards and shuffles them.
+    pub fn reshuffle<R: Rng + ?Sized>(&mut self, rng: &mut R){
+        self.cards.shuffle(rng);
+        self.dealt = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::collections::HashMap;
+    use crate::games::blackjack::Shoe;
+
+    #[test]
+    fn shoe_contains_decks(){
+        let mut shoe = Shoe::new(2, 1.0, &mut rand::rng());
+        let mut counts = HashMap::new();
+        while let Some(card) = shoe.draw(){
+            *counts.entry(card).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 52);
+        assert!(counts.values().all(|c| *c == 2));
+        assert!(shoe.needs_reshuffle());
+    }
+}
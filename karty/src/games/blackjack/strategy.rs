@@ -0,0 +1,194 @@
+use std::fmt::{Display, Formatter};
+use crate::cards::Card;
+use crate::games::blackjack::{card_value, HandValue, BLACKJACK};
+
+/// Number of distinct dealer up-card values: 2-10 and ace
+pub const DEALER_CARDS: usize = 10;
+
+/// Decision of player in blackjack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action{
+    Hit,
+    Stand,
+    /// Double the bet and take exactly one card
+    Double,
+    Split,
+    Surrender
+}
+
+impl Display for Action{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.alternate(){
+            true => match self{
+                Action::Hit => write!(f, "H"),
+                Action::Stand => write!(f, "S"),
+                Action::Double => write!(f, "D"),
+                Action::Split => write!(f, "P"),
+                Action::Surrender => write!(f, "R")
+            },
+            false => write!(f, "{self:?}")
+        }
+    }
+}
+
+/// Index of dealer's up-card in strategy table rows: `0` for two up to `9` for ace.
+fn dealer_index(up_card: &Card) -> usize{
+    card_value(up_card) as usize - 2
+}
+
+/// Strategy table deciding action by player's hand and dealer's up-card.
+/// Hard and soft totals are looked up separately, pairs are checked first for splitting.
+/// [`Default`] table is basic strategy for multi-deck game, dealer standing on soft 17,
+/// double after split allowed, no surrender. Table can be adjusted with setters.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::blackjack::{Action, StrategyTable};
+/// let mut strategy = StrategyTable::default();
+/// assert_eq!(strategy.decide(&[TEN_HEARTS, SIX_CLUBS], &NINE_SPADES), Action::Hit);
+/// assert_eq!(strategy.decide(&[TEN_HEARTS, SIX_CLUBS], &SIX_SPADES), Action::Stand);
+/// assert_eq!(strategy.decide(&[ACE_HEARTS, SEVEN_CLUBS], &FOUR_SPADES), Action::Double);
+/// assert_eq!(strategy.decide(&[EIGHT_HEARTS, EIGHT_CLUBS], &ACE_SPADES), Action::Split);
+/// strategy.set_hard(16, 9, Action::Surrender);
+/// assert_eq!(strategy.decide(&[TEN_HEARTS, SIX_CLUBS], &ACE_SPADES), Action::Surrender);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StrategyTable{
+    /// Actions for hard totals `0..=21`
+    hard: Vec<[Action; DEALER_CARDS]>,
+    /// Actions for soft totals `0..=21` (entries below 12 are unused)
+    soft: Vec<[Action; DEALER_CARDS]>,
+    /// Whether to split pair of cards with value `0..=11` (entries below 2 are unused)
+    pairs: Vec<[bool; DEALER_CARDS]>,
+}
+
+impl StrategyTable{
+    /// Creates table where player hits below `stand_on` and stands otherwise, never doubling nor splitting.
+    pub fn hit_until(stand_on: u8) -> Self{
+        let row = |total: usize| match total < stand_on as usize{
+            true => [Action::Hit; DEALER_CARDS],
+            false => [Action::Stand; DEALER_CARDS]
+        };
+        Self{
+            hard: (0..=BLACKJACK as usize).map(row).collect(),
+            soft: (0..=BLACKJACK as usize).map(row).collect(),
+            pairs: vec![[false; DEALER_CARDS]; 12],
+        }
+    }
+
+    /// Sets action for hard total against dealer's up-card index (`0` for two, `9` for ace).
+    pub fn set_hard(&mut self, total: u8, dealer: usize, action: Action){
+        self.hard[total as usize][dealer] = action;
+    }
+
+    /// Sets action for soft total against dealer's up-card index (`0` for two, `9` for ace).
+    pub fn set_soft(&mut self, total: u8, dealer: usize, action: Action){
+        self.soft[total as usize][dealer] = action;
+    }
+
+    /// Sets whether to split pair of cards with value (`11` for aces) against dealer's up-card index.
+    pub fn set_split(&mut self, card_value: u8, dealer: usize, split: bool){
+        self.pairs[card_value as usize][dealer] = split;
+    }
+
+    pub fn hard(&self, total: u8, dealer: usize) -> Action{
+        self.hard[total as usize][dealer]
+    }
+
+    pub fn soft(&self, total: u8, dealer: usize) -> Action{
+        self.soft[total as usize][dealer]
+    }
+
+    pub fn split(&self, card_value: u8, dealer: usize) -> bool{
+        self.pairs[card_value as usize][dealer]
+    }
+
+    /// Decides action for player's cards against dealer's up-card.
+    /// Pair is split if table says so. Doubling is only possible with two cards,
+    /// otherwise player hits (or stands with soft 18 or more).
+    /// Busted hand stands.
+    pub fn decide(&self, cards: &[Card], dealer_up: &Card) -> Action{
+        let dealer = dealer_index(dealer_up);
+        if let [first, second] = cards{
+            let value = card_value(first);
+            if value == card_value(second) && self.split(value, dealer){
+                return Action::Split
+            }
+        }
+        let value = HandValue::of(cards);
+        if value.is_bust(){
+            return Action::Stand
+        }
+        let action = match value.soft{
+            true => self.soft(value.total, dealer),
+            false => self.hard(value.total, dealer)
+        };
+        match (action, cards.len()){
+            (Action::Double, n) if n > 2 => match value.soft && value.total >= 18{
+                true => Action::Stand,
+                false => Action::Hit
+            },
+            (Action::Surrender, n) if n > 2 => Action::Hit,
+            (action, _) => action
+        }
+    }
+}
+
+impl Default for StrategyTable{
+    fn default() -> Self {
+        use Action::{Double as D, Hit as H, Stand as S};
+        let mut table = Self::hit_until(17);
+        //                dealer:      2  3  4  5  6  7  8  9  T  A
+        table.hard[9] =              [H, D, D, D, D, H, H, H, H, H];
+        table.hard[10] =             [D, D, D, D, D, D, D, D, H, H];
+        table.hard[11] =             [D, D, D, D, D, D, D, D, D, H];
+        table.hard[12] =             [H, H, S, S, S, H, H, H, H, H];
+        for total in 13..=16{
+            table.hard[total] =      [S, S, S, S, S, H, H, H, H, H];
+        }
+        table.soft[13] =             [H, H, H, D, D, H, H, H, H, H];
+        table.soft[14] =             [H, H, H, D, D, H, H, H, H, H];
+        table.soft[15] =             [H, H, D, D, D, H, H, H, H, H];
+        table.soft[16] =             [H, H, D, D, D, H, H, H, H, H];
+        table.soft[17] =             [H, D, D, D, D, H, H, H, H, H];
+        table.soft[18] =             [S, D, D, D, D, S, S, H, H, H];
+        let (y, n) = (true, false);
+        table.pairs[2] =             [y, y, y, y, y, y, n, n, n, n];
+        table.pairs[3] =             [y, y, y, y, y, y, n, n, n, n];
+        table.pairs[4] =             [n, n, n, y, y, n, n, n, n, n];
+        table.pairs[6] =             [y, y, y, y, y, n, n, n, n, n];
+        table.pairs[7] =             [y, y, y, y, y, y, n, n, n, n];
+        table.pairs[8] =             [y; DEALER_CARDS];
+        table.pairs[9] =             [y, y, y, y, y, n, y, y, n, n];
+        table.pairs[11] =            [y; DEALER_CARDS];
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::*;
+    use crate::games::blackjack::{Action, StrategyTable};
+
+    #[test]
+    fn basic_strategy(){
+        let strategy = StrategyTable::default();
+        assert_eq!(strategy.decide(&[TEN_HEARTS, KING_CLUBS], &FIVE_SPADES), Action::Stand);
+        assert_eq!(strategy.decide(&[FIVE_HEARTS, FIVE_CLUBS], &NINE_SPADES), Action::Double);
+        assert_eq!(strategy.decide(&[FIVE_HEARTS, FIVE_CLUBS], &ACE_SPADES), Action::Hit);
+        assert_eq!(strategy.decide(&[NINE_HEARTS, NINE_CLUBS], &SEVEN_SPADES), Action::Stand);
+        assert_eq!(strategy.decide(&[ACE_HEARTS, ACE_CLUBS], &TEN_SPADES), Action::Split);
+        assert_eq!(strategy.decide(&[ACE_HEARTS, FOUR_CLUBS, THREE_CLUBS], &FOUR_SPADES), Action::Stand);
+        assert_eq!(strategy.decide(&[TWO_HEARTS, FOUR_CLUBS, THREE_CLUBS], &FOUR_SPADES), Action::Hit);
+        assert_eq!(strategy.decide(&[TEN_HEARTS, SIX_CLUBS, KING_CLUBS], &FOUR_SPADES), Action::Stand);
+    }
+
+    #[test]
+    fn hit_until(){
+        let strategy = StrategyTable::hit_until(17);
+        assert_eq!(strategy.decide(&[ACE_HEARTS, FIVE_CLUBS], &SIX_SPADES), Action::Hit);
+        assert_eq!(strategy.decide(&[ACE_HEARTS, SIX_CLUBS], &SIX_SPADES), Action::Stand);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::error::BridgeError;
+use crate::games::bridge::{Bid, Call, Contract, Doubling, Seat};
+
+/// Bridge auction. Calls are checked for legality when added.
+/// ```
+/// use karty::games::bridge::{Auction, Bid, Call, Doubling, Seat, Strain};
+/// use karty::suits::Suit;
+/// let mut auction = Auction::new(Seat::North);
+/// auction.add_call(Call::Pass).unwrap();
+/// auction.add_call(Bid::new(1, Strain::Suit(Suit::Hearts)).unwrap().into()).unwrap();
+/// auction.add_call(Call::Double).unwrap();
+/// auction.add_call(Bid::new(4, Strain::Suit(Suit::Hearts)).unwrap().into()).unwrap();
+/// auction.add_call(Call::Pass).unwrap();
+/// auction.add_call(Call::Pass).unwrap();
+/// assert!(!auction.is_finished());
+/// auction.add_call(Call::Double).unwrap();
+/// assert!(auction.add_call(Call::Double).is_err());
+/// for _ in 0..3{
+///     auction.add_call(Call::Pass).unwrap();
+/// }
+/// assert!(auction.is_finished());
+/// let contract = auction.contract().unwrap();
+/// assert_eq!(contract.bid(), Bid::new(4, Strain::Suit(Suit::Hearts)).unwrap());
+/// assert_eq!(contract.declarer(), Seat::East);
+/// assert_eq!(contract.doubling(), Doubling::Doubled);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawAuction"))]
+pub struct Auction{
+    dealer: Seat,
+    calls: Vec<Call>
+}
+
+/// Layout of [`Auction`] as read by deserializers, before calls are checked for legality.
+#[cfg(any(feature = "serde", feature = "speedy"))]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "Auction"))]
+struct RawAuction{
+    dealer: Seat,
+    calls: Vec<Call>
+}
+
+/// Replays calls with [`Auction::add_call`].
+#[cfg(any(feature = "serde", feature = "speedy"))]
+impl TryFrom<RawAuction> for Auction{
+    type Error = BridgeError;
+
+    fn try_from(raw: RawAuction) -> Result<Self, Self::Error> {
+        let mut auction = Self::new(raw.dealer);
+        for call in raw.calls{
+            auction.add_call(call)?;
+        }
+        Ok(auction)
+    }
+}
+
+/// Reads auction checking legality of every call, like serde deserialization.
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for Auction{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        RawAuction::read_from(reader)?.try_into().map_err(|e| speedy::Error::custom(e).into())
+    }
+}
+
+impl Auction{
+    pub fn new(dealer: Seat) -> Self{
+        Self{dealer, calls: Vec::new()}
+    }
+
+    pub fn dealer(&self) -> Seat{
+        self.dealer
+    }
+
+    pub fn calls(&self) -> &[Call]{
+        &self.calls
+    }
+
+    /// Iterates over calls with seats that made them.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Seat, &Call)> + '_{
+        self.calls.iter().enumerate().map(|(i, call)| (self.dealer.next_i(i), call))
+    }
+
+    /// Seat that makes next call.
+    pub fn current_seat(&self) -> Seat{
+        self.dealer.next_i(self.calls.len())
+    }
+
+    /// Auction is finished after three passes following a bid, or after four passes at the start.
+    pub fn is_finished(&self) -> bool{
+        let n = self.calls.len();
+        n >= 4 && self.calls[n-3..].iter().all(|c| c == &Call::Pass)
+    }
+
+    /// Checks if auction ended with four passes.
+    pub fn is_passed_out(&self) -> bool{
+        self.calls.len() == 4 && self.calls.iter().all(|c| c == &Call::Pass)
+    }
+
+    /// Returns the highest bid so far with seat that made it.
+    pub fn last_bid(&self) -> Option<(Seat, Bid)>{
+        self.iter().rev().find_map(|(seat, call)| match call{
+            Call::Bid(bid) => Some((seat, *bid)),
+            _ => None
+        })
+    }
+
+    /// Returns doubling state of the highest bid so far.
+    pub fn doubling(&self) -> Doubling{
+        self.calls.iter().rev()
+            .take_while(|call| !matches!(call, Call::Bid(_)))
+            .fold(Doubling::Undoubled, |acc, call| match (acc, call){
+                (Doubling::Undoubled, Call::Double) => Doubling::Doubled,
+                (_, Call::Redouble) => Doubling::Redoubled,
+                (acc, _) => acc
+            })
+    }
+
+    /// Checks if call can be made now by [`current_seat`](Self::current_seat).
+    /// ```
+    /// use karty::error::BridgeError;
+    /// use karty::games::bridge::{Auction, Bid, Call, Seat, Strain};
+    /// let mut auction = Auction::new(Seat::West);
+    /// let one_nt = Bid::new(1, Strain::NoTrump).unwrap();
+    /// assert_eq!(auction.check_call(&Call::Double), Err(BridgeError::DoubleNotAllowed));
+    /// auction.add_call(Call::Bid(one_nt)).unwrap();
+    /// assert_eq!(auction.check_call(&Call::Bid(one_nt)), Err(BridgeError::InsufficientBid{bid: one_nt, last: one_nt}));
+    /// assert_eq!(auction.check_call(&Call::Redouble), Err(BridgeError::RedoubleNotAllowed));
+    /// auction.add_call(Call::Pass).unwrap();
+    /// // partner's bid cannot be doubled
+    /// assert_eq!(auction.check_call(&Call::Double), Err(BridgeError::DoubleNotAllowed));
+    /// ```
+    pub fn check_call(&self, call: &Call) -> Result<(), BridgeError>{
+        if self.is_finished(){
+            return Err(BridgeError::AuctionFinished)
+        }
+        let current = self.current_seat();
+        let last_active = self.iter().rev().find(|(_, call)| call != &&Call::Pass);
+        match call{
+            Call::Pass => Ok(()),
+            Call::Bid(bid) => match self.last_bid(){
+                Some((_, last)) if bid <= &last => Err(BridgeError::InsufficientBid{bid: *bid, last}),
+                _ => Ok(())
+            },
+            Call::Double => match last_active{
+                Some((seat, Call::Bid(_))) if !seat.is_same_side(&current) => Ok(()),
+                _ => Err(BridgeError::DoubleNotAllowed)
+            },
+            Call::Redouble => match last_active{
+                Some((seat, Call::Double)) if !seat.is_same_side(&current) => Ok(()),
+                _ => Err(BridgeError::RedoubleNotAllowed)
+            }
+        }
+    }
+
+    /// Adds call made by [`current_seat`](Self::current_seat), if it is legal.
+    pub fn add_call(&mut self, call: Call) -> Result<(), BridgeError>{
+        self.check_call(&call)?;
+        self.calls.push(call);
+        Ok(())
+    }
+
+    /// Returns final contract, if auction is finished and not passed out.
+    /// Declarer is the first player of declaring side, who bid strain of the contract.
+    pub fn contract(&self) -> Option<Contract>{
+        if !self.is_finished(){
+            return None
+        }
+        let (last_seat, bid) = self.last_bid()?;
+        let declarer = self.iter()
+            .find_map(|(seat, call)| match call{
+                Call::Bid(b) if b.strain() == bid.strain() && seat.is_same_side(&last_seat) => Some(seat),
+                _ => None
+            })?;
+        Some(Contract::new(bid, declarer, self.doubling()))
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::error::BridgeError;
+    use crate::games::bridge::{Auction, Bid, Call, Doubling, Seat, Strain};
+    use crate::suits::Suit::{Clubs, Spades};
+
+    fn bid(level: u8, strain: Strain) -> Call{
+        Call::Bid(Bid::new(level, strain).unwrap())
+    }
+
+    #[test]
+    fn passed_out(){
+        let mut auction = Auction::new(Seat::South);
+        for _ in 0..3{
+            auction.add_call(Call::Pass).unwrap();
+            assert!(!auction.is_finished());
+        }
+        auction.add_call(Call::Pass).unwrap();
+        assert!(auction.is_finished());
+        assert!(auction.is_passed_out());
+        assert_eq!(auction.contract(), None);
+        assert_eq!(auction.add_call(Call::Pass), Err(BridgeError::AuctionFinished));
+    }
+
+    #[test]
+    fn declarer_is_first_to_bid_strain(){
+        let mut auction = Auction::new(Seat::North);
+        auction.add_call(bid(1, Strain::Suit(Spades))).unwrap(); // N
+        auction.add_call(bid(2, Strain::Suit(Clubs))).unwrap(); // E
+        auction.add_call(bid(3, Strain::Suit(Spades))).unwrap(); // S
+        auction.add_call(Call::Double).unwrap(); // W
+        auction.add_call(Call::Redouble).unwrap(); // N
+        assert_eq!(auction.doubling(), Doubling::Redoubled);
+        auction.add_call(Call::Pass).unwrap();
+        auction.add_call(bid(4, Strain::Suit(Spades))).unwrap(); // S
+        assert_eq!(auction.doubling(), Doubling::Undoubled);
+        for _ in 0..3{
+            auction.add_call(Call::Pass).unwrap();
+        }
+        let contract = auction.contract().unwrap();
+        assert_eq!(contract.declarer(), Seat::North);
+        assert_eq!(contract.doubling(), Doubling::Undoubled);
+        assert_eq!(contract.bid().level(), 4);
+    }
+
+    #[test]
+    fn redouble_only_by_doubled_side(){
+        let mut auction = Auction::new(Seat::North);
+        auction.add_call(bid(1, Strain::NoTrump)).unwrap(); // N
+        auction.add_call(Call::Double).unwrap(); // E
+        assert_eq!(auction.check_call(&Call::Redouble), Ok(())); // S
+        auction.add_call(Call::Pass).unwrap(); // S
+        assert_eq!(auction.check_call(&Call::Redouble), Err(BridgeError::RedoubleNotAllowed)); // W
+        auction.add_call(Call::Pass).unwrap(); // W
+        assert_eq!(auction.check_call(&Call::Redouble), Ok(())); // N
+        assert_eq!(auction.check_call(&Call::Double), Err(BridgeError::DoubleNotAllowed)); // N
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_ron_round_trip(){
+        let mut auction = Auction::new(Seat::East);
+        auction.add_call(bid(1, Strain::NoTrump)).unwrap();
+        auction.add_call(Call::Double).unwrap();
+        let serialized = ron::to_string(&auction).unwrap();
+        assert_eq!(ron::from_str::<Auction>(&serialized).unwrap(), auction);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_illegal_calls(){
+        assert!(ron::from_str::<Auction>("(dealer:North,calls:[Double,Redouble,Pass,Pass,Pass,Pass,Pass,Pass])").is_err());
+        assert!(ron::from_str::<Auction>("(dealer:North,calls:[Pass,Pass,Pass,Pass,Pass])").is_err());
+        assert!(ron::from_str::<Auction>("(dealer:North,calls:[Bid((level:1,strain:NoTrump)),Bid((level:2,strain:NoTrump))])").is_ok());
+        assert!(ron::from_str::<Auction>("(dealer:North,calls:[Bid((level:2,strain:NoTrump)),Bid((level:1,strain:NoTrump))])").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "speedy")]
+    fn speedy_rejects_illegal_calls(){
+        use speedy::{Readable, Writable};
+        let mut auction = Auction::new(Seat::West);
+        auction.add_call(bid(2, Strain::Suit(Spades))).unwrap();
+        auction.add_call(Call::Double).unwrap();
+        auction.add_call(Call::Redouble).unwrap();
+        let serialized = auction.write_to_vec().unwrap();
+        assert_eq!(Auction::read_from_buffer(&serialized).unwrap(), auction);
+        let illegal = (Seat::West, vec![Call::Pass, Call::Double]).write_to_vec().unwrap();
+        assert!(Auction::read_from_buffer(&illegal).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use crate::error::{BridgeError, CardError};
+use crate::suits::Suit;
+use crate::symbol::CardSymbol;
+
+/// Minimal level of bid
+pub const MIN_BID_LEVEL: u8 = 1;
+/// Maximal level of bid
+pub const MAX_BID_LEVEL: u8 = 7;
+
+/// Strain (denomination) of bid: one of suits or no trump.
+/// Implemented order is `Clubs < Diamonds < Hearts < Spades < NoTrump`.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strain{
+    Suit(Suit),
+    NoTrump
+}
+
+impl Strain{
+    /// Returns trump suit of game played in this strain.
+    pub fn trump(&self) -> Option<Suit>{
+        match self{
+            Strain::Suit(suit) => Some(*suit),
+            Strain::NoTrump => None
+        }
+    }
+}
+
+impl From<Suit> for Strain{
+    fn from(suit: Suit) -> Self {
+        Strain::Suit(suit)
+    }
+}
+
+impl CardSymbol for Strain{
+    const SYMBOL_SPACE: usize = 5;
+
+    /// ```
+    /// use karty::games::bridge::Strain;
+    /// use karty::suits::Suit;
+    /// use karty::symbol::CardSymbol;
+    /// assert_eq!(Strain::Suit(Suit::Clubs).usize_index(), 0);
+    /// assert_eq!(Strain::NoTrump.usize_index(), 4);
+    /// ```
+    fn usize_index(&self) -> usize {
+        match self{
+            Strain::Suit(suit) => suit.usize_index(),
+            Strain::NoTrump => Suit::SYMBOL_SPACE
+        }
+    }
+
+    fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        match position{
+            4 => Ok(Strain::NoTrump),
+            p => Suit::from_usize_index(p).map(Strain::Suit)
+        }
+    }
+}
+
+impl PartialOrd for Strain{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Strain{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.usize_index().cmp(&other.usize_index())
+    }
+}
+
+/// Implements [`Display`] with suit symbols, alternate form uses ASCII letters.
+/// ```
+/// use karty::games::bridge::Strain;
+/// use karty::suits::Suit;
+/// assert_eq!(format!("{}", Strain::Suit(Suit::Hearts)), "♥");
+/// assert_eq!(format!("{:#}", Strain::Suit(Suit::Hearts)), "H");
+/// assert_eq!(format!("{}", Strain::NoTrump), "NT");
+/// ```
+impl Display for Strain{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self, f.alternate()){
+            (Strain::NoTrump, _) => write!(f, "NT"),
+            (Strain::Suit(suit), false) => write!(f, "{suit:#}"),
+            (Strain::Suit(suit), true) => write!(f, "{}", match suit{
+                Suit::Spades => "S",
+                Suit::Hearts => "H",
+                Suit::Diamonds => "D",
+                Suit::Clubs => "C"
+            })
+        }
+    }
+}
+
+/// Bid in bridge auction: level (1-7) and strain.
+/// Bids are ordered by level and then by strain.
+/// ```
+/// use karty::games::bridge::{Bid, Strain};
+/// use karty::suits::Suit;
+/// let one_nt = Bid::new(1, Strain::NoTrump).unwrap();
+/// let two_clubs = Bid::new(2, Strain::Suit(Suit::Clubs)).unwrap();
+/// assert!(two_clubs > one_nt);
+/// assert!(Bid::new(8, Strain::NoTrump).is_err());
+/// ```
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawBid"))]
+pub struct Bid{
+    level: u8,
+    strain: Strain
+}
+
+/// Layout of [`Bid`] as read by deserializers, before level is checked with [`Bid::new`].
+#[cfg(any(feature = "serde", feature = "speedy"))]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "Bid"))]
+struct RawBid{
+    level: u8,
+    strain: Strain
+}
+
+#[cfg(any(feature = "serde", feature = "speedy"))]
+impl TryFrom<RawBid> for Bid{
+    type Error = BridgeError;
+
+    fn try_from(raw: RawBid) -> Result<Self, Self::Error> {
+        Self::new(raw.level, raw.strain)
+    }
+}
+
+/// Reads bid with the same level check as [`Bid::new`].
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for Bid{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        RawBid::read_from(reader)?.try_into().map_err(|e| speedy::Error::custom(e).into())
+    }
+}
+
+impl Bid{
+    pub fn new(level: u8, strain: Strain) -> Result<Self, BridgeError>{
+        match level{
+            MIN_BID_LEVEL..=MAX_BID_LEVEL => Ok(Self{level, strain}),
+            e => Err(BridgeError::WrongBidLevel(e))
+        }
+    }
+
+    pub fn level(&self) -> u8{
+        self.level
+    }
+
+    pub fn strain(&self) -> Strain{
+        self.strain
+    }
+
+    /// Number of tricks declarer must take to fulfill contract (`6 + level`).
+    pub fn tricks_required(&self) -> u8{
+        self.level + 6
+    }
+}
+
+impl PartialOrd for Bid{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bid{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.level.cmp(&other.level).then(self.strain.cmp(&other.strain))
+    }
+}
+
+/// ```
+/// use karty::games::bridge::{Bid, Strain};
+/// use karty::suits::Suit;
+/// assert_eq!(format!("{}", Bid::new(4, Strain::Suit(Suit::Spades)).unwrap()), "4♠");
+/// assert_eq!(format!("{:#}", Bid::new(4, Strain::Suit(Suit::Spades)).unwrap()), "4S");
+/// assert_eq!(format!("{}", Bid::new(1, Strain::NoTrump).unwrap()), "1NT");
+/// ```
+impl Display for Bid{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.alternate(){
+            true => write!(f, "{}{:#}", self.level, self.strain),
+            false => write!(f, "{}{}", self.level, self.strain)
+        }
+    }
+}
+
+/// Call made in auction.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Call{
+    Bid(Bid),
+    Pass,
+    Double,
+    Redouble
+}
+
+impl From<Bid> for Call{
+    fn from(bid: Bid) -> Self {
+        Call::Bid(bid)
+    }
+}
+
+/// ```
+/// use karty::games::bridge::{Bid, Call, Strain};
+/// assert_eq!(format!("{}", Call::Pass), "P");
+/// assert_eq!(format!("{}", Call::Redouble), "XX");
+/// assert_eq!(format!("{}", Call::Bid(Bid::new(3, Strain::NoTrump).unwrap())), "3NT");
+/// ```
+impl Display for Call{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self, f.alternate()){
+            (Call::Bid(bid), false) => write!(f, "{bid}"),
+            (Call::Bid(bid), true) => write!(f, "{bid:#}"),
+            (Call::Pass, _) => write!(f, "P"),
+            (Call::Double, _) => write!(f, "X"),
+            (Call::Redouble, _) => write!(f, "XX"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    #[cfg(any(feature = "serde", feature = "speedy"))]
+    use crate::games::bridge::{Bid, Strain};
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_checks_level(){
+        let bid = Bid::new(7, Strain::NoTrump).unwrap();
+        assert_eq!(ron::from_str::<Bid>(&ron::to_string(&bid).unwrap()).unwrap(), bid);
+        assert!(ron::from_str::<Bid>("(level:250,strain:NoTrump)").is_err());
+        assert!(ron::from_str::<Bid>("(level:0,strain:NoTrump)").is_err());
+    }
+
+    #[cfg(feature = "speedy")]
+    #[test]
+    fn speedy_checks_level(){
+        use speedy::{Readable, Writable};
+        let bid = Bid::new(3, Strain::NoTrump).unwrap();
+        let mut serialized = bid.write_to_vec().unwrap();
+        assert_eq!(Bid::read_from_buffer(&serialized).unwrap(), bid);
+        serialized[0] = 8;
+        assert!(Bid::read_from_buffer(&serialized).is_err());
+    }
+}
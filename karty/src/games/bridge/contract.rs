@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+use crate::games::bridge::{Bid, Seat};
+
+/// Doubling state of contract.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Doubling{
+    #[default]
+    Undoubled,
+    Doubled,
+    Redoubled
+}
+
+impl Display for Doubling{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Doubling::Undoubled => Ok(()),
+            Doubling::Doubled => write!(f, "X"),
+            Doubling::Redoubled => write!(f, "XX")
+        }
+    }
+}
+
+/// Final contract of bridge auction.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contract{
+    bid: Bid,
+    declarer: Seat,
+    doubling: Doubling
+}
+
+impl Contract{
+    pub fn new(bid: Bid, declarer: Seat, doubling: Doubling) -> Self{
+        Self{bid, declarer, doubling}
+    }
+
+    pub fn bid(&self) -> Bid{
+        self.bid
+    }
+
+    pub fn declarer(&self) -> Seat{
+        self.declarer
+    }
+
+    pub fn doubling(&self) -> Doubling{
+        self.doubling
+    }
+}
+
+/// Implements [`Display`] in form `<bid><doubling> <declarer>`, alternate form uses ASCII letters for strain.
+/// ```
+/// use karty::games::bridge::{Bid, Contract, Doubling, Seat, Strain};
+/// use karty::suits::Suit;
+/// let contract = Contract::new(Bid::new(4, Strain::Suit(Suit::Spades)).unwrap(), Seat::South, Doubling::Doubled);
+/// assert_eq!(format!("{contract}"), "4♠X S");
+/// assert_eq!(format!("{contract:#}"), "4SX S");
+/// ```
+impl Display for Contract{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.alternate(){
+            true => write!(f, "{:#}{} {:#}", self.bid, self.doubling, self.declarer),
+            false => write!(f, "{}{} {:#}", self.bid, self.doubling, self.declarer)
+        }
+    }
+}
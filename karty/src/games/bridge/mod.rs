@@ -0,0 +1,20 @@
+//! Module with contract bridge primitives: seats, bids, calls, auction, contract and duplicate scoring.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+mod seat;
+mod bid;
+mod auction;
+mod contract;
+mod scoring;
+
+pub use seat::*;
+pub use bid::*;
+pub use auction::*;
+pub use contract::*;
+pub use scoring::*;
+
+#[cfg(feature = "parse")]
+pub mod parse;
@@ -0,0 +1,197 @@
+//! Module containing parsing functions for bridge primitives.
+//! # Enable:
+//! Use feature `parse`.
+//!
+use std::str::FromStr;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{one_of, space0, space1};
+use nom::combinator::{map, map_res, opt, value};
+use nom::{IResult, Parser};
+use nom::sequence::preceded;
+use crate::error::BridgeError;
+use crate::games::bridge::{Bid, Call, Contract, Doubling, Seat, Strain};
+use crate::suits::parse::parse_suit;
+
+/// Parses strain: no trump (`NT`, `N`, `notrump`) or suit (see [`parse_suit`]).
+/// ```
+/// use karty::games::bridge::parse::parse_strain;
+/// use karty::games::bridge::Strain;
+/// use karty::suits::Suit;
+/// assert_eq!(parse_strain("NTx"), Ok(("x", Strain::NoTrump)));
+/// assert_eq!(parse_strain("♠"), Ok(("", Strain::Suit(Suit::Spades))));
+/// ```
+pub fn parse_strain(s: &str) -> IResult<&str, Strain>{
+    alt((
+        value(Strain::NoTrump, alt((tag_no_case("notrump"), tag_no_case("nt"), tag_no_case("n")))),
+        map(parse_suit, Strain::Suit)
+    )).parse(s)
+}
+
+/// Parses bid: level followed by strain, e.g. `1NT`, `4♠`, `3 h`.
+/// ```
+/// use karty::games::bridge::parse::parse_bid;
+/// use karty::games::bridge::{Bid, Strain};
+/// use karty::suits::Suit;
+/// assert_eq!(parse_bid("4♠X"), Ok(("X", Bid::new(4, Strain::Suit(Suit::Spades)).unwrap())));
+/// assert!(parse_bid("8NT").is_err());
+/// ```
+pub fn parse_bid(s: &str) -> IResult<&str, Bid>{
+    map_res(
+        (one_of("1234567"), space0, parse_strain),
+        |(level, _, strain)| Bid::new(level as u8 - b'0', strain)
+    ).parse(s)
+}
+
+/// Parses doubling suffix: `XX` (redoubled), `X` (doubled) or nothing (undoubled).
+pub fn parse_doubling(s: &str) -> IResult<&str, Doubling>{
+    map(opt(alt((
+        value(Doubling::Redoubled, tag_no_case("xx")),
+        value(Doubling::Doubled, tag_no_case("x")),
+    ))), |d| d.unwrap_or_default()).parse(s)
+}
+
+/// Parses call: bid, pass (`P`, `pass`), double (`X`, `dbl`, `double`) or redouble (`XX`, `rdbl`, `redouble`).
+/// ```
+/// use karty::games::bridge::parse::parse_call;
+/// use karty::games::bridge::Call;
+/// assert_eq!(parse_call("P"), Ok(("", Call::Pass)));
+/// assert_eq!(parse_call("xx"), Ok(("", Call::Redouble)));
+/// assert_eq!(parse_call("dbl"), Ok(("", Call::Double)));
+/// ```
+pub fn parse_call(s: &str) -> IResult<&str, Call>{
+    alt((
+        map(parse_bid, Call::Bid),
+        value(Call::Pass, alt((tag_no_case("pass"), tag_no_case("p")))),
+        value(Call::Redouble, alt((tag_no_case("redouble"), tag_no_case("rdbl"), tag_no_case("xx")))),
+        value(Call::Double, alt((tag_no_case("double"), tag_no_case("dbl"), tag_no_case("x")))),
+    )).parse(s)
+}
+
+/// Parses seat: full name or first letter (case insensitive).
+pub fn parse_seat(s: &str) -> IResult<&str, Seat>{
+    alt((
+        value(Seat::North, alt((tag_no_case("north"), tag_no_case("n")))),
+        value(Seat::East, alt((tag_no_case("east"), tag_no_case("e")))),
+        value(Seat::South, alt((tag_no_case("south"), tag_no_case("s")))),
+        value(Seat::West, alt((tag_no_case("west"), tag_no_case("w")))),
+    )).parse(s)
+}
+
+/// Parses contract in form produced by it's [`Display`](std::fmt::Display): `<bid><doubling> <declarer>`.
+/// Word `by` may separate doubling and declarer.
+/// ```
+/// use karty::games::bridge::parse::parse_contract;
+/// use karty::games::bridge::{Doubling, Seat};
+/// let (_, contract) = parse_contract("4♠X S").unwrap();
+/// assert_eq!(contract.declarer(), Seat::South);
+/// assert_eq!(contract.doubling(), Doubling::Doubled);
+/// let (_, contract) = parse_contract("3NT by West").unwrap();
+/// assert_eq!(contract.declarer(), Seat::West);
+/// ```
+pub fn parse_contract(s: &str) -> IResult<&str, Contract>{
+    map(
+        (parse_bid, parse_doubling, space1, opt((tag("by"), space1)), parse_seat),
+        |(bid, doubling, _, _, declarer)| Contract::new(bid, declarer, doubling)
+    ).parse(s)
+}
+
+fn parse_whole<T>(s: &str, parser: fn(&str) -> IResult<&str, T>) -> Result<T, BridgeError>{
+    match preceded(space0, parser).parse(s){
+        Ok((rem, t)) if rem.trim().is_empty() => Ok(t),
+        _ => Err(BridgeError::ParseError)
+    }
+}
+
+/// ```
+/// use std::str::FromStr;
+/// use karty::games::bridge::Strain;
+/// assert_eq!(Strain::from_str("NT"), Ok(Strain::NoTrump));
+/// assert!(Strain::from_str("NTT").is_err());
+/// ```
+impl FromStr for Strain{
+    type Err = BridgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_whole(s, parse_strain)
+    }
+}
+
+/// ```
+/// use std::str::FromStr;
+/// use karty::games::bridge::{Bid, Strain};
+/// assert_eq!(Bid::from_str("1NT"), Ok(Bid::new(1, Strain::NoTrump).unwrap()));
+/// ```
+impl FromStr for Bid{
+    type Err = BridgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_whole(s, parse_bid)
+    }
+}
+
+/// ```
+/// use std::str::FromStr;
+/// use karty::games::bridge::Call;
+/// assert_eq!(Call::from_str("X"), Ok(Call::Double));
+/// ```
+impl FromStr for Call{
+    type Err = BridgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_whole(s, parse_call)
+    }
+}
+
+impl FromStr for Seat{
+    type Err = BridgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_whole(s, parse_seat)
+    }
+}
+
+impl FromStr for Contract{
+    type Err = BridgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_whole(s, parse_contract)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::str::FromStr;
+    use crate::games::bridge::{Bid, Call, Contract, Doubling, Seat, Strain, SEATS};
+    use crate::suits::Suit;
+    use crate::symbol::CardSymbol;
+
+    #[test]
+    fn display_parse_round_trip(){
+        for strain in Strain::iterator(){
+            for level in 1..=7{
+                let bid = Bid::new(level, strain).unwrap();
+                assert_eq!(Bid::from_str(&format!("{bid}")), Ok(bid));
+                assert_eq!(Bid::from_str(&format!("{bid:#}")), Ok(bid));
+                for declarer in SEATS{
+                    for doubling in [Doubling::Undoubled, Doubling::Doubled, Doubling::Redoubled]{
+                        let contract = Contract::new(bid, declarer, doubling);
+                        assert_eq!(Contract::from_str(&format!("{contract}")), Ok(contract));
+                        assert_eq!(Contract::from_str(&format!("{contract:#}")), Ok(contract));
+                    }
+                }
+            }
+        }
+        for call in [Call::Pass, Call::Double, Call::Redouble]{
+            assert_eq!(Call::from_str(&format!("{call}")), Ok(call));
+        }
+    }
+
+    #[test]
+    fn parse_calls_lowercase(){
+        assert_eq!(Call::from_str("2d"), Ok(Call::Bid(Bid::new(2, Strain::Suit(Suit::Diamonds)).unwrap())));
+        assert_eq!(Call::from_str("pass"), Ok(Call::Pass));
+        assert_eq!(Seat::from_str("e"), Ok(Seat::East));
+        assert!(Call::from_str("0S").is_err());
+    }
+}
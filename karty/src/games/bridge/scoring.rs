@@ -0,0 +1,241 @@
+use crate::error::BridgeError;
+use crate::games::bridge::{Contract, Doubling, Seat, Strain};
+use crate::suits::Suit;
+
+/// Number of tricks in bridge deal
+pub const TRICKS_IN_DEAL: u8 = 13;
+
+/// Lower bounds of score differences for consecutive IMPs (1, 2, ..., 24)
+const IMP_THRESHOLDS: [u32; 24] = [20, 50, 90, 130, 170, 220, 270, 320, 370, 430, 500, 600, 750, 900, 1100,
+    1300, 1500, 1750, 2000, 2250, 2500, 3000, 3500, 4000];
+
+/// Vulnerability of sides on the board.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Vulnerability{
+    #[default]
+    None,
+    NorthSouth,
+    EastWest,
+    Both
+}
+
+impl Vulnerability{
+    /// ```
+    /// use karty::games::bridge::{Seat, Vulnerability};
+    /// assert!(Vulnerability::EastWest.is_vulnerable(&Seat::West));
+    /// assert!(!Vulnerability::EastWest.is_vulnerable(&Seat::North));
+    /// ```
+    pub fn is_vulnerable(&self, seat: &Seat) -> bool{
+        match self{
+            Vulnerability::None => false,
+            Vulnerability::NorthSouth => matches!(seat, Seat::North | Seat::South),
+            Vulnerability::EastWest => matches!(seat, Seat::East | Seat::West),
+            Vulnerability::Both => true
+        }
+    }
+}
+
+impl Contract{
+    fn doubling_multiplier(&self) -> i32{
+        match self.doubling(){
+            Doubling::Undoubled => 1,
+            Doubling::Doubled => 2,
+            Doubling::Redoubled => 4
+        }
+    }
+
+    /// Points for contracted tricks (below the line), including doubling.
+    fn trick_score(&self) -> i32{
+        let level = self.bid().level() as i32;
+        let base = match self.bid().strain(){
+            Strain::Suit(Suit::Clubs | Suit::Diamonds) => 20 * level,
+            Strain::Suit(_) => 30 * level,
+            Strain::NoTrump => 30 * level + 10
+        };
+        base * self.doubling_multiplier()
+    }
+
+    /// Computes duplicate score of declaring side.
+    /// Result is positive when contract is made and negative when it is defeated.
+    /// ```
+    /// use karty::games::bridge::{Bid, Contract, Doubling, Seat, Strain};
+    /// use karty::suits::Suit;
+    /// let four_spades = Contract::new(Bid::new(4, Strain::Suit(Suit::Spades)).unwrap(), Seat::South, Doubling::Undoubled);
+    /// assert_eq!(four_spades.score(false, 10), Ok(420));
+    /// assert_eq!(four_spades.score(true, 11), Ok(650));
+    /// assert_eq!(four_spades.score(true, 8), Ok(-200));
+    /// assert!(four_spades.score(true, 14).is_err());
+    /// ```
+    pub fn score(&self, vulnerable: bool, tricks: u8) -> Result<i32, BridgeError>{
+        if tricks > TRICKS_IN_DEAL{
+            return Err(BridgeError::WrongTricksNumber(tricks))
+        }
+        let required = self.bid().tricks_required() as i32;
+        let difference = tricks as i32 - required;
+        match difference >= 0{
+            true => Ok(self.made_score(vulnerable, difference)),
+            false => Ok(-self.penalty(vulnerable, -difference))
+        }
+    }
+
+    /// Computes duplicate score from North-South perspective.
+    /// ```
+    /// use karty::games::bridge::{Bid, Contract, Doubling, Seat, Strain, Vulnerability};
+    /// let three_nt = Contract::new(Bid::new(3, Strain::NoTrump).unwrap(), Seat::East, Doubling::Undoubled);
+    /// assert_eq!(three_nt.score_ns(Vulnerability::EastWest, 10), Ok(-630));
+    /// assert_eq!(three_nt.score_ns(Vulnerability::EastWest, 8), Ok(100));
+    /// ```
+    pub fn score_ns(&self, vulnerability: Vulnerability, tricks: u8) -> Result<i32, BridgeError>{
+        let score = self.score(vulnerability.is_vulnerable(&self.declarer()), tricks)?;
+        match self.declarer(){
+            Seat::North | Seat::South => Ok(score),
+            Seat::East | Seat::West => Ok(-score)
+        }
+    }
+
+    fn made_score(&self, vulnerable: bool, overtricks: i32) -> i32{
+        let trick_score = self.trick_score();
+        let game_bonus = match (trick_score >= 100, vulnerable){
+            (false, _) => 50,
+            (true, false) => 300,
+            (true, true) => 500
+        };
+        let slam_bonus = match (self.bid().level(), vulnerable){
+            (6, false) => 500,
+            (6, true) => 750,
+            (7, false) => 1000,
+            (7, true) => 1500,
+            _ => 0
+        };
+        let insult = match self.doubling(){
+            Doubling::Undoubled => 0,
+            Doubling::Doubled => 50,
+            Doubling::Redoubled => 100
+        };
+        let overtrick_value = match (self.doubling(), self.bid().strain(), vulnerable){
+            (Doubling::Undoubled, Strain::Suit(Suit::Clubs | Suit::Diamonds), _) => 20,
+            (Doubling::Undoubled, _, _) => 30,
+            (_, _, false) => 50 * self.doubling_multiplier(),
+            (_, _, true) => 100 * self.doubling_multiplier()
+        };
+        trick_score + game_bonus + slam_bonus + insult + overtricks * overtrick_value
+    }
+
+    fn penalty(&self, vulnerable: bool, undertricks: i32) -> i32{
+        match self.doubling(){
+            Doubling::Undoubled => undertricks * match vulnerable{
+                false => 50,
+                true => 100
+            },
+            _ => {
+                let doubled = match vulnerable{
+                    false => 100 + 200 * (undertricks - 1).min(2) + 300 * (undertricks - 3).max(0),
+                    true => 200 + 300 * (undertricks - 1)
+                };
+                doubled * self.doubling_multiplier() / 2
+            }
+        }
+    }
+}
+
+/// Converts score difference to International Match Points.
+/// ```
+/// use karty::games::bridge::imps;
+/// assert_eq!(imps(10), 0);
+/// assert_eq!(imps(620 - 170), 10);
+/// assert_eq!(imps(-1430), -16);
+/// assert_eq!(imps(5000), 24);
+/// ```
+pub fn imps(score_difference: i32) -> i32{
+    let imps = IMP_THRESHOLDS.iter()
+        .take_while(|threshold| score_difference.unsigned_abs() >= **threshold)
+        .count() as i32;
+    imps * score_difference.signum()
+}
+
+/// Computes matchpoints for every result on the traveller of a board.
+/// Scores are expected from the same side perspective (typically North-South).
+/// Each result gets 2 points for every other result it beats and 1 point for every tie,
+/// so top is `2 * (scores.len() - 1)`.
+/// ```
+/// use karty::games::bridge::matchpoints;
+/// assert_eq!(matchpoints(&[420, 450, 420, -50]), vec![3, 6, 3, 0]);
+/// ```
+pub fn matchpoints(scores: &[i32]) -> Vec<u32>{
+    scores.iter().map(|score|{
+        scores.iter().map(|other| match score.cmp(other){
+            std::cmp::Ordering::Greater => 2,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Less => 0
+        }).sum::<u32>() - 1
+    }).collect()
+}
+
+/// Computes matchpoint percentages for every result on the traveller of a board (see [`matchpoints`]).
+/// Percentages of the opposite side are `100 - p`. Single result scores `50%`.
+/// ```
+/// use karty::games::bridge::matchpoint_percentages;
+/// assert_eq!(matchpoint_percentages(&[420, 450, 420, -50]), vec![50.0, 100.0, 50.0, 0.0]);
+/// assert_eq!(matchpoint_percentages(&[-100]), vec![50.0]);
+/// ```
+pub fn matchpoint_percentages(scores: &[i32]) -> Vec<f64>{
+    let top = 2 * scores.len().saturating_sub(1);
+    matchpoints(scores).into_iter().map(|mp| match top{
+        0 => 50.0,
+        top => 100.0 * mp as f64 / top as f64
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::games::bridge::{imps, Bid, Contract, Doubling, Seat, Strain};
+    use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
+
+    fn contract(level: u8, strain: Strain, doubling: Doubling) -> Contract{
+        Contract::new(Bid::new(level, strain).unwrap(), Seat::North, doubling)
+    }
+
+    #[test]
+    fn made_contracts(){
+        use Doubling::*;
+        assert_eq!(contract(1, Strain::Suit(Clubs), Undoubled).score(false, 7), Ok(70));
+        assert_eq!(contract(2, Strain::Suit(Hearts), Undoubled).score(false, 9), Ok(140));
+        assert_eq!(contract(3, Strain::NoTrump, Undoubled).score(true, 10), Ok(630));
+        assert_eq!(contract(5, Strain::Suit(Diamonds), Undoubled).score(false, 11), Ok(400));
+        assert_eq!(contract(6, Strain::Suit(Clubs), Undoubled).score(false, 12), Ok(920));
+        assert_eq!(contract(6, Strain::Suit(Spades), Undoubled).score(true, 13), Ok(1460));
+        assert_eq!(contract(7, Strain::NoTrump, Undoubled).score(true, 13), Ok(2220));
+        assert_eq!(contract(1, Strain::NoTrump, Doubled).score(false, 7), Ok(180));
+        assert_eq!(contract(2, Strain::Suit(Spades), Doubled).score(false, 8), Ok(470));
+        assert_eq!(contract(2, Strain::Suit(Spades), Doubled).score(true, 9), Ok(870));
+        assert_eq!(contract(1, Strain::NoTrump, Redoubled).score(false, 8), Ok(760));
+        assert_eq!(contract(1, Strain::Suit(Clubs), Redoubled).score(true, 7), Ok(230));
+    }
+
+    #[test]
+    fn defeated_contracts(){
+        use Doubling::*;
+        assert_eq!(contract(1, Strain::Suit(Clubs), Undoubled).score(true, 6), Ok(-100));
+        assert_eq!(contract(4, Strain::Suit(Hearts), Undoubled).score(false, 7), Ok(-150));
+        assert_eq!(contract(4, Strain::Suit(Hearts), Doubled).score(false, 9), Ok(-100));
+        assert_eq!(contract(4, Strain::Suit(Hearts), Doubled).score(false, 7), Ok(-500));
+        assert_eq!(contract(4, Strain::Suit(Hearts), Doubled).score(false, 6), Ok(-800));
+        assert_eq!(contract(4, Strain::Suit(Hearts), Doubled).score(true, 7), Ok(-800));
+        assert_eq!(contract(4, Strain::Suit(Hearts), Redoubled).score(false, 6), Ok(-1600));
+        assert_eq!(contract(7, Strain::NoTrump, Doubled).score(true, 0), Ok(-3800));
+    }
+
+    #[test]
+    fn imp_table_bounds(){
+        assert_eq!(imps(0), 0);
+        assert_eq!(imps(19), 0);
+        assert_eq!(imps(20), 1);
+        assert_eq!(imps(-40), -1);
+        assert_eq!(imps(490), 10);
+        assert_eq!(imps(500), 11);
+        assert_eq!(imps(3990), 23);
+        assert_eq!(imps(4000), 24);
+    }
+}
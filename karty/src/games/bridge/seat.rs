@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter};
+use crate::games::bridge::Seat::{East, North, South, West};
+
+/// Seat at bridge table.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Seat{
+    North,
+    East,
+    South,
+    West
+}
+
+/// Array of seats in clockwise order, starting with North
+pub const SEATS: [Seat; 4] = [North, East, South, West];
+
+impl Seat{
+    /// Returns seat next in clockwise order.
+    /// ```
+    /// use karty::games::bridge::Seat;
+    /// assert_eq!(Seat::West.next(), Seat::North);
+    /// ```
+    pub fn next(&self) -> Self{
+        self.next_i(1)
+    }
+
+    /// Returns seat `i` places further in clockwise order.
+    pub fn next_i(&self, i: usize) -> Self{
+        SEATS[(self.index() + i) % 4]
+    }
+
+    /// ```
+    /// use karty::games::bridge::Seat;
+    /// assert_eq!(Seat::East.partner(), Seat::West);
+    /// ```
+    pub fn partner(&self) -> Self{
+        self.next_i(2)
+    }
+
+    /// Checks if seats belong to the same side (are the same seat or partners).
+    /// ```
+    /// use karty::games::bridge::Seat;
+    /// assert!(Seat::North.is_same_side(&Seat::South));
+    /// assert!(!Seat::North.is_same_side(&Seat::East));
+    /// ```
+    pub fn is_same_side(&self, other: &Seat) -> bool{
+        self == other || &self.partner() == other
+    }
+
+    /// Index of seat in [`SEATS`].
+    pub fn index(&self) -> usize{
+        match self{
+            North => 0,
+            East => 1,
+            South => 2,
+            West => 3
+        }
+    }
+}
+
+/// ```
+/// use karty::games::bridge::Seat;
+/// assert_eq!(format!("{}", Seat::South), "South");
+/// assert_eq!(format!("{:#}", Seat::South), "S");
+/// ```
+impl Display for Seat{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate(){
+            match self{
+                North => write!(f, "N"),
+                East => write!(f, "E"),
+                South => write!(f, "S"),
+                West => write!(f, "W")
+            }
+        }
+        else{
+            match self{
+                North => write!(f, "North"),
+                East => write!(f, "East"),
+                South => write!(f, "South"),
+                West => write!(f, "West")
+            }
+        }
+    }
+}
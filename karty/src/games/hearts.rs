@@ -0,0 +1,339 @@
+//! Module with rules of Hearts for four players: passing, breaking hearts, penalty points and shooting the moon.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::card_set;
+use crate::cards::{Card, QUEEN_SPADES, TWO_CLUBS};
+use crate::error::GameError;
+use crate::set::{CardSetStd, HAND_OF_HEARTS};
+use crate::trick::{legal_moves, Trick, TrickRound};
+use crate::valuation::{CardValuation, HEARTS_PENALTIES};
+pub use crate::valuation::QUEEN_OF_SPADES_PENALTY;
+
+/// Number of players in Hearts
+pub const HEARTS_PLAYERS: usize = 4;
+/// Number of cards passed by every player before play
+pub const CARDS_TO_PASS: usize = 3;
+/// Sum of penalty points in deal, taking all of them is shooting the moon
+pub const MOON_POINTS: u32 = 26;
+
+/// Direction of passing cards before play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PassDirection{
+    Left,
+    Right,
+    Across,
+    Hold
+}
+
+impl PassDirection{
+    /// Direction used in round numbered from 0, cycling `Left`, `Right`, `Across`, `Hold`.
+    /// ```
+    /// use karty::games::hearts::PassDirection;
+    /// assert_eq!(PassDirection::for_round(2), PassDirection::Across);
+    /// assert_eq!(PassDirection::for_round(7), PassDirection::Hold);
+    /// ```
+    pub fn for_round(round: usize) -> Self{
+        match round % 4{
+            0 => Self::Left,
+            1 => Self::Right,
+            2 => Self::Across,
+            _ => Self::Hold
+        }
+    }
+
+    /// Player receiving cards passed by `player`.
+    /// ```
+    /// use karty::games::hearts::PassDirection;
+    /// assert_eq!(PassDirection::Left.target(3), 0);
+    /// assert_eq!(PassDirection::Right.target(0), 3);
+    /// assert_eq!(PassDirection::Across.target(1), 3);
+    /// ```
+    pub fn target(&self, player: usize) -> usize{
+        let shift = match self{
+            Self::Left => 1,
+            Self::Across => 2,
+            Self::Right => 3,
+            Self::Hold => 0
+        };
+        (player + shift) % HEARTS_PLAYERS
+    }
+}
+
+/// Penalty points of cards: one for every heart and [`QUEEN_OF_SPADES_PENALTY`] for queen of spades.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::hearts::penalty_points;
+/// assert_eq!(penalty_points(&card_set![QUEEN_SPADES, TWO_HEARTS, ACE_HEARTS, ACE_CLUBS]), 15);
+/// ```
+pub fn penalty_points(cards: &CardSetStd) -> u32{
+    HEARTS_PENALTIES.set_value(cards)
+}
+
+/// State of single deal of Hearts.
+/// Players are numbered `0..4` clockwise. After passing (skipped when [`PassDirection::Hold`]),
+/// holder of two of clubs leads it to the first trick. On first trick player void in led suit
+/// cannot discard hearts nor queen of spades, unless having nothing else. Hearts cannot be led
+/// until they are broken (discarded to some trick), unless player has nothing else.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::hearts::{HeartsRound, PassDirection};
+/// let round = HeartsRound::new([
+///     card_set![TWO_CLUBS, ACE_HEARTS],
+///     card_set![ACE_CLUBS, KING_HEARTS],
+///     card_set![KING_CLUBS, QUEEN_HEARTS],
+///     card_set![QUEEN_SPADES, JACK_HEARTS]], PassDirection::Hold);
+/// assert_eq!(round.current_player(), Some(0));
+/// assert_eq!(round.legal_moves(), card_set![TWO_CLUBS]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeartsRound{
+    pass_direction: PassDirection,
+    passed: [Option<CardSetStd>; HEARTS_PLAYERS],
+    play: TrickRound<HEARTS_PLAYERS>,
+    hearts_broken: bool,
+}
+
+impl HeartsRound{
+    pub fn new(hands: [CardSetStd; HEARTS_PLAYERS], pass_direction: PassDirection) -> Self{
+        let lead = Self::two_clubs_holder(&hands);
+        Self{
+            pass_direction,
+            passed: [None; HEARTS_PLAYERS],
+            play: TrickRound::new(hands, lead),
+            hearts_broken: false
+        }
+    }
+
+    fn two_clubs_holder(hands: &[CardSetStd; HEARTS_PLAYERS]) -> usize{
+        hands.iter().position(|h| h.contains(&TWO_CLUBS)).unwrap_or(0)
+    }
+
+    pub fn pass_direction(&self) -> PassDirection{
+        self.pass_direction
+    }
+
+    /// Checks if round waits for players to pass cards.
+    pub fn is_passing(&self) -> bool{
+        self.pass_direction != PassDirection::Hold && self.passed.iter().any(|p| p.is_none())
+    }
+
+    /// Checks if player has already chosen cards to pass.
+    pub fn has_passed(&self, player: usize) -> bool{
+        self.passed[player].is_some()
+    }
+
+    /// Chooses [`CARDS_TO_PASS`] cards from hand of player to pass.
+    /// When last player passes, cards are exchanged and play begins.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::hearts::{HeartsRound, PassDirection};
+    /// let mut round = HeartsRound::new([
+    ///     card_set![TWO_CLUBS, THREE_CLUBS, FOUR_CLUBS, ACE_SPADES],
+    ///     card_set![TWO_HEARTS, THREE_HEARTS, FOUR_HEARTS, KING_SPADES],
+    ///     card_set![TWO_DIAMONDS, THREE_DIAMONDS, FOUR_DIAMONDS, QUEEN_SPADES],
+    ///     card_set![TWO_SPADES, THREE_SPADES, FOUR_SPADES, JACK_SPADES]], PassDirection::Left);
+    /// round.pass_cards(0, card_set![TWO_CLUBS, THREE_CLUBS, FOUR_CLUBS]).unwrap();
+    /// round.pass_cards(1, card_set![TWO_HEARTS, THREE_HEARTS, FOUR_HEARTS]).unwrap();
+    /// round.pass_cards(2, card_set![TWO_DIAMONDS, THREE_DIAMONDS, FOUR_DIAMONDS]).unwrap();
+    /// assert!(round.is_passing());
+    /// round.pass_cards(3, card_set![TWO_SPADES, THREE_SPADES, FOUR_SPADES]).unwrap();
+    /// assert!(!round.is_passing());
+    /// assert_eq!(round.hand(1), &card_set![TWO_CLUBS, THREE_CLUBS, FOUR_CLUBS, KING_SPADES]);
+    /// assert_eq!(round.current_player(), Some(1));
+    /// ```
+    pub fn pass_cards(&mut self, player: usize, cards: CardSetStd) -> Result<(), GameError>{
+        if !self.is_passing() || self.passed[player].is_some(){
+            return Err(GameError::WrongPhase)
+        }
+        if cards.len() != CARDS_TO_PASS{
+            return Err(GameError::WrongNumberOfCards{expected: CARDS_TO_PASS, found: cards.len()})
+        }
+        let hand = self.play.hand(player);
+        if let Some(card) = cards.into_iter().find(|c| !hand.contains(c)){
+            return Err(GameError::CardNotInHand(card))
+        }
+        self.passed[player] = Some(cards);
+        if !self.is_passing(){
+            let mut hands = *self.play.hands();
+            for (player, passed) in self.passed.iter().enumerate(){
+                let passed = passed.expect("All players have passed (should not happen, it is a bug).");
+                hands[player] = hands[player].difference(&passed);
+            }
+            for (player, passed) in self.passed.iter().enumerate(){
+                let target = self.pass_direction.target(player);
+                hands[target] = hands[target].union(&passed.expect("All players have passed (should not happen, it is a bug)."));
+            }
+            self.play = TrickRound::new(hands, Self::two_clubs_holder(&hands));
+        }
+        Ok(())
+    }
+
+    pub fn hand(&self, player: usize) -> &CardSetStd{
+        self.play.hand(player)
+    }
+
+    pub fn trick(&self) -> &Trick<Card, HEARTS_PLAYERS>{
+        self.play.trick()
+    }
+
+    /// Playing phase of round: hands, tricks and taken cards.
+    pub fn trick_round(&self) -> &TrickRound<HEARTS_PLAYERS>{
+        &self.play
+    }
+
+    pub fn hearts_broken(&self) -> bool{
+        self.hearts_broken
+    }
+
+    /// Player expected to play card, `None` during passing or when round is finished.
+    pub fn current_player(&self) -> Option<usize>{
+        match self.is_passing(){
+            true => None,
+            false => self.play.current_player()
+        }
+    }
+
+    pub fn is_finished(&self) -> bool{
+        !self.is_passing() && self.play.is_finished()
+    }
+
+    /// Cards that current player may legally play, empty when no one is to play.
+    pub fn legal_moves(&self) -> CardSetStd{
+        let player = match self.current_player(){
+            None => return CardSetStd::empty(),
+            Some(p) => p
+        };
+        let hand = self.play.hand(player);
+        let trick = self.play.trick();
+        if self.play.completed_tricks().is_empty(){
+            if trick.is_empty(){
+                return match hand.contains(&TWO_CLUBS){
+                    true => card_set![TWO_CLUBS],
+                    false => *hand
+                }
+            }
+            let follow = legal_moves(hand, trick);
+            if follow != *hand{
+                return follow
+            }
+            let safe = hand.difference(&HAND_OF_HEARTS).difference(&card_set![QUEEN_SPADES]);
+            return match safe.is_empty(){
+                true => *hand,
+                false => safe
+            }
+        }
+        if trick.is_empty() && !self.hearts_broken{
+            let not_hearts = hand.difference(&HAND_OF_HEARTS);
+            if !not_hearts.is_empty(){
+                return not_hearts
+            }
+        }
+        legal_moves(hand, trick)
+    }
+
+    /// Plays card of current player.
+    /// # Returns:
+    /// `Some(winner)` if the card completed a trick, `None` otherwise.
+    pub fn play(&mut self, card: Card) -> Result<Option<usize>, GameError>{
+        if self.is_passing(){
+            return Err(GameError::WrongPhase)
+        }
+        let legal = self.legal_moves();
+        let result = self.play.play(card, &legal, None)?;
+        if HAND_OF_HEARTS.contains(&card){
+            self.hearts_broken = true;
+        }
+        Ok(result)
+    }
+
+    /// Player who took all penalty cards, if any.
+    pub fn moon_shooter(&self) -> Option<usize>{
+        (0..HEARTS_PLAYERS).find(|&p| penalty_points(self.play.taken(p)) == MOON_POINTS)
+    }
+
+    /// Penalty points of players in this round. Player shooting the moon scores 0,
+    /// while every other player scores [`MOON_POINTS`].
+    pub fn penalty_points(&self) -> [u32; HEARTS_PLAYERS]{
+        match self.moon_shooter(){
+            Some(shooter) => std::array::from_fn(|p| if p == shooter {0} else {MOON_POINTS}),
+            None => std::array::from_fn(|p| penalty_points(self.play.taken(p)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::error::GameError;
+    use crate::games::hearts::{HeartsRound, PassDirection, MOON_POINTS};
+    use crate::set::{HAND_OF_CLUBS, HAND_OF_DIAMONDS, HAND_OF_HEARTS, HAND_OF_SPADES};
+
+    fn round() -> HeartsRound{
+        HeartsRound::new([
+            card_set![TWO_CLUBS, ACE_HEARTS, ACE_SPADES],
+            card_set![ACE_CLUBS, KING_HEARTS, TWO_SPADES],
+            card_set![KING_CLUBS, QUEEN_HEARTS, THREE_SPADES],
+            card_set![QUEEN_SPADES, JACK_HEARTS, FOUR_DIAMONDS]], PassDirection::Hold)
+    }
+
+    #[test]
+    fn first_trick_and_breaking_hearts(){
+        let mut round = round();
+        assert_eq!(round.play(ACE_HEARTS), Err(GameError::IllegalMove(ACE_HEARTS)));
+        round.play(TWO_CLUBS).unwrap();
+        round.play(ACE_CLUBS).unwrap();
+        round.play(KING_CLUBS).unwrap();
+        assert_eq!(round.legal_moves(), card_set![FOUR_DIAMONDS]);
+        assert_eq!(round.play(FOUR_DIAMONDS), Ok(Some(1)));
+        assert!(!round.hearts_broken());
+        assert_eq!(round.legal_moves(), card_set![TWO_SPADES]);
+        round.play(TWO_SPADES).unwrap();
+        round.play(THREE_SPADES).unwrap();
+        assert_eq!(round.legal_moves(), card_set![QUEEN_SPADES]);
+        round.play(QUEEN_SPADES).unwrap();
+        assert_eq!(round.play(ACE_SPADES), Ok(Some(0)));
+        assert_eq!(round.legal_moves(), card_set![ACE_HEARTS]);
+        round.play(ACE_HEARTS).unwrap();
+        assert!(round.hearts_broken());
+        round.play(KING_HEARTS).unwrap();
+        round.play(QUEEN_HEARTS).unwrap();
+        assert_eq!(round.play(JACK_HEARTS), Ok(Some(0)));
+        assert!(round.is_finished());
+        assert_eq!(round.penalty_points(), [17, 0, 0, 0]);
+    }
+
+    #[test]
+    fn shooting_the_moon(){
+        let mut round = HeartsRound::new([HAND_OF_CLUBS, HAND_OF_HEARTS, HAND_OF_SPADES, HAND_OF_DIAMONDS],
+            PassDirection::Hold);
+        while let Some(card) = round.legal_moves().into_iter().next(){
+            round.play(card).unwrap();
+        }
+        assert!(round.is_finished());
+        assert_eq!(round.moon_shooter(), Some(0));
+        assert_eq!(round.penalty_points(), [0, MOON_POINTS, MOON_POINTS, MOON_POINTS]);
+    }
+
+    #[test]
+    fn passing_errors(){
+        let mut round = HeartsRound::new([
+            card_set![TWO_CLUBS, THREE_CLUBS, FOUR_CLUBS],
+            card_set![TWO_HEARTS, THREE_HEARTS, FOUR_HEARTS],
+            card_set![TWO_DIAMONDS, THREE_DIAMONDS, FOUR_DIAMONDS],
+            card_set![TWO_SPADES, THREE_SPADES, FOUR_SPADES]], PassDirection::Across);
+        assert_eq!(round.play(TWO_CLUBS), Err(GameError::WrongPhase));
+        assert_eq!(round.pass_cards(0, card_set![TWO_CLUBS]), Err(GameError::WrongNumberOfCards{expected: 3, found: 1}));
+        assert_eq!(round.pass_cards(0, card_set![TWO_CLUBS, THREE_CLUBS, TWO_HEARTS]), Err(GameError::CardNotInHand(TWO_HEARTS)));
+        round.pass_cards(0, card_set![TWO_CLUBS, THREE_CLUBS, FOUR_CLUBS]).unwrap();
+        assert_eq!(round.pass_cards(0, card_set![TWO_CLUBS, THREE_CLUBS, FOUR_CLUBS]), Err(GameError::WrongPhase));
+    }
+}
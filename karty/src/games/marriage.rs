@@ -0,0 +1,58 @@
+//! Module with marriages (king and queen of the same suit) used in Tysiąc, Schnapsen and similar games.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::cards::{Card, Card2SymTrait};
+use crate::figures::{King, Queen};
+use crate::set::{CardSet, CardSetStd};
+use crate::suits::{Suit, SUITS};
+
+/// Set of king and queen of suit.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::marriage::marriage_cards;
+/// use karty::suits::Suit;
+/// assert_eq!(marriage_cards(Suit::Hearts), card_set![KING_HEARTS, QUEEN_HEARTS]);
+/// ```
+pub fn marriage_cards(suit: Suit) -> CardSetStd{
+    CardSetStd::from_iterator([King, Queen].into_iter().map(|f| Card::from_figure_and_suit(f, suit)))
+}
+
+/// Checks if hand contains both king and queen of suit.
+pub fn has_marriage(hand: &CardSetStd, suit: Suit) -> bool{
+    let cards = marriage_cards(suit);
+    hand.intersection(&cards) == cards
+}
+
+/// Suits of marriages in hand, in order of [`SUITS`].
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::marriage::marriages;
+/// use karty::suits::Suit;
+/// let hand = card_set![KING_HEARTS, QUEEN_HEARTS, KING_SPADES, QUEEN_CLUBS, KING_CLUBS];
+/// assert_eq!(marriages(&hand), vec![Suit::Clubs, Suit::Hearts]);
+/// ```
+pub fn marriages(hand: &CardSetStd) -> Vec<Suit>{
+    SUITS.into_iter().filter(|s| has_marriage(hand, *s)).collect()
+}
+
+/// If card is king or queen and hand holds its partner, returns suit of marriage.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::marriage::marriage_of;
+/// use karty::suits::Suit;
+/// let hand = card_set![KING_HEARTS, QUEEN_HEARTS, KING_SPADES];
+/// assert_eq!(marriage_of(&hand, &QUEEN_HEARTS), Some(Suit::Hearts));
+/// assert_eq!(marriage_of(&hand, &KING_SPADES), None);
+/// ```
+pub fn marriage_of(hand: &CardSetStd, card: &Card) -> Option<Suit>{
+    match card.figure(){
+        King | Queen if has_marriage(hand, card.suit()) => Some(card.suit()),
+        _ => None
+    }
+}
@@ -0,0 +1,27 @@
+//! Module with rules of particular card games built on top of crate's cards, sets and tricks.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+
+/// Contract bridge: auction, contracts and scoring
+pub mod bridge;
+/// Hearts: passing, breaking hearts, penalty points and shooting the moon
+pub mod hearts;
+/// Spades: bids including nil, bags and partnership scoring
+pub mod spades;
+/// Whist: trick play with trump and scoring over book
+pub mod whist;
+/// Skat: trumps with jacks, card points, matadors and game value
+pub mod skat;
+/// Marriages (king and queen of the same suit)
+pub mod marriage;
+/// Tysiąc (1000): card points, marriages and trump switching
+pub mod tysiac;
+/// Schnapsen (66): card points, marriages and game points
+pub mod schnapsen;
+/// Blackjack: hand values, shoe, basic strategy and card counting
+pub mod blackjack;
+/// Rummy and Gin Rummy: sets, runs, jokers and minimal deadwood
+pub mod rummy;
@@ -0,0 +1,298 @@
+//! Module with meld detection for Rummy family games: sets, runs, jokers and minimal deadwood (Gin Rummy).
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::cards::{Card, Card2SymTrait};
+use crate::figures::{Figure, FIGURES};
+use crate::set::{CardSet, CardSetStd, HAND_OF_CLUBS};
+use crate::suits::{Suit, SUITS};
+use crate::valuation::{CardValuation, DEADWOOD_VALUES};
+
+const FIGURES_IN_SUIT: usize = 13;
+
+/// Position of ace in runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AcePosition{
+    /// Ace is below two (`A 2 3`), as in Gin Rummy
+    #[default]
+    Low,
+    /// Ace is above king (`Q K A`)
+    High,
+    /// Ace can be either below two or above king, but runs do not wrap around (`K A 2` is not a run)
+    Both
+}
+
+/// Kind of meld.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MeldKind{
+    /// Cards of the same figure
+    Set(Figure),
+    /// Consecutive cards of the same suit
+    Run(Suit)
+}
+
+/// Meld of natural cards, possibly completed with jokers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Meld{
+    pub kind: MeldKind,
+    pub cards: CardSetStd,
+    pub jokers: usize,
+}
+
+impl Meld{
+    /// Number of cards in meld, including jokers.
+    pub fn len(&self) -> usize{
+        self.cards.len() + self.jokers
+    }
+
+    pub fn is_empty(&self) -> bool{
+        self.len() == 0
+    }
+}
+
+/// Division of hand into melds and deadwood with minimal deadwood value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeldArrangement{
+    pub melds: Vec<Meld>,
+    /// Natural cards not used in melds
+    pub deadwood: CardSetStd,
+    /// Jokers not used in melds
+    pub unused_jokers: usize,
+    /// Value of deadwood cards and unused jokers
+    pub deadwood_value: u32,
+}
+
+/// Deadwood value of card in Gin Rummy: ace 1, number cards by number, figures 10.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::rummy::deadwood_value;
+/// assert_eq!(deadwood_value(&ACE_SPADES), 1);
+/// assert_eq!(deadwood_value(&SEVEN_SPADES), 7);
+/// assert_eq!(deadwood_value(&KING_SPADES), 10);
+/// ```
+pub fn deadwood_value(card: &Card) -> u32{
+    DEADWOOD_VALUES.value(card)
+}
+
+/// Rules of forming melds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeldRules{
+    pub ace: AcePosition,
+    /// Minimal length of run
+    pub min_run: usize,
+    /// Minimal size of set (maximal is 4)
+    pub min_set: usize,
+    /// Deadwood value of joker left out of melds
+    pub joker_deadwood: u32,
+}
+
+impl Default for MeldRules{
+    fn default() -> Self {
+        Self{ace: AcePosition::Low, min_run: 3, min_set: 3, joker_deadwood: 0}
+    }
+}
+
+impl MeldRules{
+    pub fn new(ace: AcePosition) -> Self{
+        Self{ace, ..Default::default()}
+    }
+
+    pub fn with_min_run(mut self, min_run: usize) -> Self{
+        self.min_run = min_run;
+        self
+    }
+
+    pub fn with_joker_deadwood(mut self, joker_deadwood: u32) -> Self{
+        self.joker_deadwood = joker_deadwood;
+        self
+    }
+
+    /// Range of run positions: position `0` is low ace, `1..=12` are two to king, `13` is high ace.
+    fn run_positions(&self) -> (usize, usize){
+        match self.ace{
+            AcePosition::Low => (0, 12),
+            AcePosition::High => (1, 13),
+            AcePosition::Both => (0, 13)
+        }
+    }
+
+    /// Mask of cards (in clubs) occupying run positions `first..=last`.
+    fn window_mask(first: usize, last: usize) -> u64{
+        (first..=last).fold(0u64, |mask, p| mask | (1u64 << ((p + FIGURES_IN_SUIT - 1) % FIGURES_IN_SUIT)))
+    }
+
+    /// Returns every meld that can be formed from cards of hand and given number of jokers.
+    /// Melds overlap, every subset of set and every sub-run is listed. Melds made of jokers only are omitted.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::rummy::MeldRules;
+    /// let hand = card_set![FIVE_HEARTS, SIX_HEARTS, SEVEN_HEARTS, EIGHT_HEARTS, SEVEN_CLUBS, SEVEN_SPADES];
+    /// let melds = MeldRules::default().find_melds(&hand, 0);
+    /// // 5-6-7, 6-7-8, 5-6-7-8 and 7-7-7
+    /// assert_eq!(melds.len(), 4);
+    /// let melds = MeldRules::default().find_melds(&card_set![FIVE_HEARTS, SEVEN_HEARTS], 1);
+    /// assert_eq!(melds.len(), 1);
+    /// assert_eq!(melds[0].len(), 3);
+    /// ```
+    pub fn find_melds(&self, hand: &CardSetStd, jokers: usize) -> Vec<Meld>{
+        let mut melds = Vec::new();
+        for suit in SUITS{
+            let offset = suit.usize_index() * FIGURES_IN_SUIT;
+            let in_suit = (hand.cards >> offset) & HAND_OF_CLUBS.cards;
+            let (low, high) = self.run_positions();
+            for first in low..=high{
+                for last in (first + self.min_run.max(1) - 1)..=high{
+                    let window = Self::window_mask(first, last);
+                    let natural = in_suit & window;
+                    let length = last - first + 1;
+                    let missing = length - natural.count_ones() as usize;
+                    if natural == 0 || missing > jokers || length > FIGURES_IN_SUIT{
+                        continue
+                    }
+                    let meld = Meld{kind: MeldKind::Run(suit), cards: CardSetStd{cards: natural << offset}, jokers: missing};
+                    if !melds.contains(&meld){
+                        melds.push(meld);
+                    }
+                }
+            }
+        }
+        for figure in FIGURES{
+            let cards: Vec<Card> = SUITS.iter().map(|s| Card::from_figure_and_suit(figure, *s))
+                .filter(|c| hand.contains(c)).collect();
+            for subset in 1u32..(1 << cards.len()){
+                let natural = CardSetStd::from_iterator(cards.iter().enumerate()
+                    .filter(|(i, _)| subset & (1 << i) != 0).map(|(_, c)| *c));
+                for used_jokers in 0..=jokers.min(SUITS.len() - natural.len()){
+                    if natural.len() + used_jokers >= self.min_set{
+                        melds.push(Meld{kind: MeldKind::Set(figure), cards: natural, jokers: used_jokers});
+                    }
+                }
+            }
+        }
+        melds
+    }
+
+    /// Checks if cards and jokers form valid meld.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::rummy::{AcePosition, MeldKind, MeldRules};
+    /// use karty::suits::Suit;
+    /// let rules = MeldRules::new(AcePosition::Both);
+    /// assert_eq!(rules.meld_kind(&card_set![QUEEN_CLUBS, KING_CLUBS, ACE_CLUBS], 0), Some(MeldKind::Run(Suit::Clubs)));
+    /// assert_eq!(rules.meld_kind(&card_set![KING_CLUBS, ACE_CLUBS, TWO_CLUBS], 0), None);
+    /// assert!(rules.meld_kind(&card_set![KING_CLUBS, KING_HEARTS], 1).is_some());
+    /// ```
+    pub fn meld_kind(&self, cards: &CardSetStd, jokers: usize) -> Option<MeldKind>{
+        self.find_melds(cards, jokers).into_iter()
+            .find(|m| m.cards == *cards && m.jokers == jokers)
+            .map(|m| m.kind)
+    }
+
+    /// Finds arrangement of hand (with given number of jokers) into disjoint melds with minimal deadwood.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::rummy::MeldRules;
+    /// let hand = card_set![FIVE_HEARTS, SIX_HEARTS, SEVEN_HEARTS, SEVEN_CLUBS, SEVEN_SPADES,
+    ///     SEVEN_DIAMONDS, KING_CLUBS, TWO_CLUBS];
+    /// let arrangement = MeldRules::default().best_arrangement(&hand, 0);
+    /// // 5-6-7 of hearts and set of three other sevens
+    /// assert_eq!(arrangement.melds.len(), 2);
+    /// assert_eq!(arrangement.deadwood, card_set![KING_CLUBS, TWO_CLUBS]);
+    /// assert_eq!(arrangement.deadwood_value, 12);
+    /// ```
+    pub fn best_arrangement(&self, hand: &CardSetStd, jokers: usize) -> MeldArrangement{
+        let melds = self.find_melds(hand, jokers);
+        let mut chosen = Vec::new();
+        let mut best = (0, Vec::new());
+        self.search(&melds, 0, CardSetStd::empty(), jokers, 0, &mut chosen, &mut best);
+        let used = best.1.iter().fold(CardSetStd::empty(), |acc, m: &Meld| acc.union(&m.cards));
+        let unused_jokers = jokers - best.1.iter().map(|m| m.jokers).sum::<usize>();
+        let deadwood = hand.difference(&used);
+        MeldArrangement{
+            deadwood_value: deadwood.into_iter().map(|c| deadwood_value(&c)).sum::<u32>()
+                + unused_jokers as u32 * self.joker_deadwood,
+            melds: best.1,
+            deadwood,
+            unused_jokers,
+        }
+    }
+
+    /// Value removed from deadwood by meld.
+    fn meld_value(&self, meld: &Meld) -> u32{
+        meld.cards.into_iter().map(|c| deadwood_value(&c)).sum::<u32>() + meld.jokers as u32 * self.joker_deadwood
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn search(&self, melds: &[Meld], start: usize, used: CardSetStd, jokers: usize, value: u32,
+              chosen: &mut Vec<Meld>, best: &mut (u32, Vec<Meld>)){
+        if value > best.0{
+            *best = (value, chosen.clone());
+        }
+        for (i, meld) in melds.iter().enumerate().skip(start){
+            if meld.jokers <= jokers && used.intersection(&meld.cards).is_empty(){
+                chosen.push(*meld);
+                self.search(melds, i + 1, used.union(&meld.cards), jokers - meld.jokers,
+                    value + self.meld_value(meld), chosen, best);
+                chosen.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::figures::King;
+    use crate::games::rummy::{AcePosition, MeldKind, MeldRules};
+    use crate::set::CardSetStd;
+    use crate::suits::Suit;
+
+    #[test]
+    fn ace_positions(){
+        let hand = card_set![ACE_SPADES, TWO_SPADES, THREE_SPADES, QUEEN_SPADES, KING_SPADES];
+        let low = MeldRules::new(AcePosition::Low).find_melds(&hand, 0);
+        assert_eq!(low.len(), 1);
+        assert_eq!(low[0].cards, card_set![ACE_SPADES, TWO_SPADES, THREE_SPADES]);
+        let high = MeldRules::new(AcePosition::High).find_melds(&hand, 0);
+        assert_eq!(high.len(), 1);
+        assert_eq!(high[0].cards, card_set![ACE_SPADES, QUEEN_SPADES, KING_SPADES]);
+        assert_eq!(MeldRules::new(AcePosition::Both).find_melds(&hand, 0).len(), 2);
+    }
+
+    #[test]
+    fn jokers_fill_gaps(){
+        let hand = card_set![FIVE_HEARTS, EIGHT_HEARTS, KING_CLUBS, KING_DIAMONDS];
+        let rules = MeldRules::default().with_joker_deadwood(15);
+        let arrangement = rules.best_arrangement(&hand, 3);
+        assert_eq!(arrangement.deadwood_value, 0);
+        assert_eq!(arrangement.unused_jokers, 0);
+        assert_eq!(arrangement.melds.len(), 2);
+        assert!(arrangement.melds.iter().any(|m| m.kind == MeldKind::Run(Suit::Hearts) && m.jokers == 2));
+        assert_eq!(rules.best_arrangement(&hand, 2).deadwood_value, 13);
+        assert_eq!(rules.meld_kind(&card_set![KING_CLUBS, KING_DIAMONDS], 1), Some(MeldKind::Set(King)));
+        assert_eq!(rules.best_arrangement(&CardSetStd::empty(), 1).deadwood_value, 15);
+    }
+
+    #[test]
+    fn minimal_deadwood_prefers_high_cards(){
+        // 9-10-J of clubs or set of jacks
+        let hand = card_set![NINE_CLUBS, TEN_CLUBS, JACK_CLUBS, JACK_HEARTS, JACK_SPADES, QUEEN_CLUBS];
+        let arrangement = MeldRules::default().best_arrangement(&hand, 0);
+        assert_eq!(arrangement.deadwood, card_set![JACK_HEARTS, JACK_SPADES]);
+        assert_eq!(arrangement.deadwood_value, 20);
+    }
+}
@@ -0,0 +1,169 @@
+//! Module with rules of Schnapsen (Sixty-six for two players with 20-card deck): `A 10 K Q J` order,
+//! card points, marriages and game points.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::cards::Card;
+use crate::figures::AceTenFigureComparator;
+use crate::games::marriage::marriage_of;
+use crate::set::CardSetStd;
+use crate::suits::Suit;
+use crate::trick::{Trick, TrickRules};
+
+/// Card points are the same as in Tysiąc: ace 11, ten 10, king 4, queen 3, jack 2.
+pub use crate::games::tysiac::{card_points, points};
+
+/// Number of players in Schnapsen
+pub const SCHNAPSEN_PLAYERS: usize = 2;
+/// Number of points needed to win the deal
+pub const WINNING_POINTS: u32 = 66;
+/// Bonus for declaring marriage in plain suit
+pub const MARRIAGE_BONUS: u32 = 20;
+/// Bonus for declaring marriage in trump suit
+pub const TRUMP_MARRIAGE_BONUS: u32 = 40;
+/// Sum of card points in Schnapsen deck
+pub const SCHNAPSEN_TOTAL_POINTS: u32 = 120;
+
+/// Set of 20 cards used in Schnapsen (ten, jack, queen, king and ace in every suit).
+/// ```
+/// use karty::games::schnapsen::SCHNAPSEN_DECK;
+/// use karty::set::CardSet;
+/// use karty::cards::*;
+/// assert_eq!(SCHNAPSEN_DECK.len(), 20);
+/// assert!(SCHNAPSEN_DECK.contains(&TEN_HEARTS));
+/// assert!(!SCHNAPSEN_DECK.contains(&NINE_HEARTS));
+/// ```
+pub const SCHNAPSEN_DECK: CardSetStd = {
+    const SUIT: u64 = 0x1f << 8;
+    CardSetStd{cards: SUIT | (SUIT << 13) | (SUIT << 26) | (SUIT << 39)}
+};
+
+/// Game points for winner of deal, depending on loser: 3 if loser took no trick,
+/// 2 if loser has less than 33 points, 1 otherwise.
+/// ```
+/// use karty::games::schnapsen::game_points;
+/// assert_eq!(game_points(40, true), 1);
+/// assert_eq!(game_points(32, true), 2);
+/// assert_eq!(game_points(0, false), 3);
+/// ```
+pub fn game_points(loser_points: u32, loser_took_trick: bool) -> u8{
+    match (loser_took_trick, loser_points){
+        (false, _) => 3,
+        (true, p) if p < WINNING_POINTS / 2 => 2,
+        _ => 1
+    }
+}
+
+/// Rules of playing tricks in Schnapsen with fixed trump.
+/// While talon is open any card may be played. When talon is closed or exhausted player must follow suit
+/// and beat the trick if able, when void must play trump.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::schnapsen::SchnapsenPlay;
+/// use karty::suits::Suit;
+/// use karty::trick::Trick;
+/// let mut play = SchnapsenPlay::new(Suit::Clubs);
+/// let hand = card_set![ACE_HEARTS, QUEEN_HEARTS, JACK_CLUBS];
+/// let mut trick = Trick::<Card, 2>::new(0);
+/// trick.add_card(KING_HEARTS).unwrap();
+/// assert_eq!(play.legal_moves(&hand, &trick), hand);
+/// play.close();
+/// assert_eq!(play.legal_moves(&hand, &trick), card_set![ACE_HEARTS]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchnapsenPlay{
+    trump: Suit,
+    closed: bool,
+}
+
+impl SchnapsenPlay{
+    pub fn new(trump: Suit) -> Self{
+        Self{trump, closed: false}
+    }
+
+    pub fn trump(&self) -> Suit{
+        self.trump
+    }
+
+    pub fn is_closed(&self) -> bool{
+        self.closed
+    }
+
+    /// Closes talon (or marks it exhausted), strict rules of following suit apply since.
+    pub fn close(&mut self){
+        self.closed = true;
+    }
+
+    /// Rules of trick play, depending on state of talon.
+    pub fn rules(&self) -> TrickRules{
+        TrickRules::new(Some(self.trump)).with_must_trump(self.closed).with_must_overtake(self.closed)
+    }
+
+    /// Returns cards from hand that can be legally played to trick.
+    pub fn legal_moves<const N: usize>(&self, hand: &CardSetStd, trick: &Trick<Card, N>) -> CardSetStd{
+        match self.closed{
+            false => *hand,
+            true => self.rules().legal_moves_by(hand, trick, &AceTenFigureComparator{})
+        }
+    }
+
+    /// Returns player taking the trick (currently, if trick is not complete).
+    pub fn trick_winner<const N: usize>(&self, trick: &Trick<Card, N>) -> Option<usize>{
+        trick.winner_by(Some(self.trump), &AceTenFigureComparator{})
+    }
+
+    /// Declares marriage when card is led to empty trick: if card is king or queen and hand holds its partner,
+    /// returns bonus ([`TRUMP_MARRIAGE_BONUS`] in trump suit, [`MARRIAGE_BONUS`] otherwise).
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::schnapsen::SchnapsenPlay;
+    /// use karty::suits::Suit;
+    /// use karty::trick::Trick;
+    /// let play = SchnapsenPlay::new(Suit::Clubs);
+    /// let hand = card_set![KING_HEARTS, QUEEN_HEARTS, KING_CLUBS, QUEEN_CLUBS];
+    /// let trick = Trick::<Card, 2>::new(1);
+    /// assert_eq!(play.declare_marriage(&hand, &trick, &KING_HEARTS), Some(20));
+    /// assert_eq!(play.declare_marriage(&hand, &trick, &QUEEN_CLUBS), Some(40));
+    /// ```
+    pub fn declare_marriage<const N: usize>(&self, hand: &CardSetStd, trick: &Trick<Card, N>, card: &Card) -> Option<u32>{
+        if !trick.is_empty(){
+            return None
+        }
+        match marriage_of(hand, card)?{
+            suit if suit == self.trump => Some(TRUMP_MARRIAGE_BONUS),
+            _ => Some(MARRIAGE_BONUS)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::games::schnapsen::{points, SchnapsenPlay, SCHNAPSEN_DECK, SCHNAPSEN_TOTAL_POINTS};
+    use crate::suits::Suit;
+    use crate::trick::Trick;
+
+    #[test]
+    fn deck_points(){
+        assert_eq!(points(&SCHNAPSEN_DECK), SCHNAPSEN_TOTAL_POINTS);
+    }
+
+    #[test]
+    fn closed_talon_must_trump(){
+        let mut play = SchnapsenPlay::new(Suit::Spades);
+        play.close();
+        let hand = card_set![TEN_SPADES, JACK_SPADES, ACE_DIAMONDS];
+        let mut trick = Trick::<Card, 2>::new(0);
+        trick.add_card(TEN_HEARTS).unwrap();
+        assert_eq!(play.legal_moves(&hand, &trick), card_set![TEN_SPADES, JACK_SPADES]);
+        trick.add_card(JACK_SPADES).unwrap();
+        assert_eq!(play.trick_winner(&trick), Some(1));
+    }
+}
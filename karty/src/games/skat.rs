@@ -0,0 +1,362 @@
+//! Module with rules of Skat: 32-card deck, trumps with jacks, card points, matadors and game value.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use comparator::Comparator;
+use crate::cards::*;
+use crate::figures::{Ace, Figure, FigureComparator, Jack, King, Queen, F10, F7, F8, F9};
+use crate::set::{CardSet, CardSetStd};
+use crate::suits::{ComparatorDHSC, Suit};
+use crate::trick::Trick;
+use crate::valuation::{CardValuation, ACE_TEN_POINTS};
+
+/// Number of players in Skat
+pub const SKAT_PLAYERS: usize = 3;
+/// Sum of card points in Skat deck
+pub const SKAT_TOTAL_POINTS: u32 = 120;
+/// Figures of Skat deck in descending order of suit games: `A 10 K Q 9 8 7` (jacks are trumps)
+pub const SKAT_SUIT_FIGURES: [Figure; 7] = [Ace, F10, King, Queen, F9, F8, F7];
+/// Jacks in descending trump order: `♣J ♠J ♥J ♦J`
+pub const SKAT_JACKS: [Card; 4] = [JACK_CLUBS, JACK_SPADES, JACK_HEARTS, JACK_DIAMONDS];
+
+/// Set of 32 cards used in Skat (seven to ace in every suit).
+/// ```
+/// use karty::games::skat::SKAT_DECK;
+/// use karty::set::CardSet;
+/// use karty::cards::*;
+/// assert_eq!(SKAT_DECK.len(), 32);
+/// assert!(SKAT_DECK.contains(&SEVEN_HEARTS));
+/// assert!(!SKAT_DECK.contains(&SIX_HEARTS));
+/// ```
+pub const SKAT_DECK: CardSetStd = {
+    const SUIT: u64 = 0xff << 5;
+    CardSetStd{cards: SUIT | (SUIT << 13) | (SUIT << 26) | (SUIT << 39)}
+};
+
+/// Card points in Skat: ace 11, ten 10, king 4, queen 3, jack 2, other cards 0.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::skat::card_points;
+/// assert_eq!(card_points(&TEN_SPADES), 10);
+/// assert_eq!(card_points(&JACK_CLUBS), 2);
+/// assert_eq!(card_points(&NINE_CLUBS), 0);
+/// ```
+pub fn card_points(card: &Card) -> u32{
+    ACE_TEN_POINTS.value(card)
+}
+
+/// Sum of card points of cards in set.
+pub fn points(cards: &CardSetStd) -> u32{
+    ACE_TEN_POINTS.set_value(cards)
+}
+
+/// Type of Skat game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkatGame{
+    /// Jacks and cards of suit are trumps
+    Suit(Suit),
+    /// Only jacks are trumps
+    Grand,
+    /// No trumps, natural order of figures, declarer must not take any trick
+    Null
+}
+
+/// Matadors: number of top trumps held (`With`) or missing (`Without`) in sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Matadors{
+    With(u8),
+    Without(u8)
+}
+
+impl Matadors{
+    pub fn count(&self) -> u8{
+        match self{
+            Matadors::With(n) | Matadors::Without(n) => *n
+        }
+    }
+}
+
+impl Display for Matadors{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Matadors::With(n) => write!(f, "with {n}"),
+            Matadors::Without(n) => write!(f, "without {n}")
+        }
+    }
+}
+
+/// Additional game levels raising multiplier of suit and grand games
+/// (and selecting value of null game with `hand` and `ouvert`).
+/// Every flag set adds one to multiplier, flags are counted as given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkatModifiers{
+    pub hand: bool,
+    pub schneider: bool,
+    pub schneider_announced: bool,
+    pub schwarz: bool,
+    pub schwarz_announced: bool,
+    pub ouvert: bool,
+}
+
+impl SkatGame{
+    /// Checks if card is trump in this game.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::games::skat::SkatGame;
+    /// use karty::suits::Suit;
+    /// assert!(SkatGame::Grand.is_trump(&JACK_HEARTS));
+    /// assert!(!SkatGame::Grand.is_trump(&ACE_HEARTS));
+    /// assert!(SkatGame::Suit(Suit::Hearts).is_trump(&ACE_HEARTS));
+    /// assert!(!SkatGame::Null.is_trump(&JACK_HEARTS));
+    /// ```
+    pub fn is_trump(&self, card: &Card) -> bool{
+        match self{
+            SkatGame::Null => false,
+            SkatGame::Grand => card.figure() == Jack,
+            SkatGame::Suit(suit) => card.figure() == Jack || card.suit() == *suit
+        }
+    }
+
+    /// Trumps of this game in descending order.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::games::skat::SkatGame;
+    /// use karty::suits::Suit;
+    /// let trumps = SkatGame::Suit(Suit::Diamonds).trumps();
+    /// assert_eq!(trumps.len(), 11);
+    /// assert_eq!(&trumps[3..6], &[JACK_DIAMONDS, ACE_DIAMONDS, TEN_DIAMONDS]);
+    /// ```
+    pub fn trumps(&self) -> Vec<Card>{
+        match self{
+            SkatGame::Null => Vec::new(),
+            SkatGame::Grand => Vec::from(SKAT_JACKS),
+            SkatGame::Suit(suit) => SKAT_JACKS.iter().copied()
+                .chain(SKAT_SUIT_FIGURES.iter().map(|f| Card::from_figure_and_suit(*f, *suit)))
+                .collect()
+        }
+    }
+
+    /// Trumps of this game as set.
+    pub fn trump_set(&self) -> CardSetStd{
+        CardSetStd::from_iterator(self.trumps().into_iter())
+    }
+
+    /// Matadors in set of cards (declarer's hand with skat), `None` for null game.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::skat::{Matadors, SkatGame};
+    /// use karty::suits::Suit;
+    /// let cards = card_set![JACK_CLUBS, JACK_SPADES, ACE_HEARTS, SEVEN_HEARTS];
+    /// assert_eq!(SkatGame::Grand.matadors(&cards), Some(Matadors::With(2)));
+    /// let cards = card_set![JACK_HEARTS, ACE_HEARTS, SEVEN_HEARTS];
+    /// assert_eq!(SkatGame::Suit(Suit::Hearts).matadors(&cards), Some(Matadors::Without(2)));
+    /// assert_eq!(SkatGame::Null.matadors(&cards), None);
+    /// ```
+    pub fn matadors(&self, cards: &CardSetStd) -> Option<Matadors>{
+        let trumps = self.trumps();
+        let top = cards.contains(trumps.first()?);
+        let count = trumps.iter().take_while(|c| cards.contains(c) == top).count() as u8;
+        Some(match top{
+            true => Matadors::With(count),
+            false => Matadors::Without(count)
+        })
+    }
+
+    /// Base value of game: ♦ 9, ♥ 10, ♠ 11, ♣ 12, grand 24, null 23.
+    pub fn base_value(&self) -> u32{
+        match self{
+            SkatGame::Suit(Suit::Diamonds) => 9,
+            SkatGame::Suit(Suit::Hearts) => 10,
+            SkatGame::Suit(Suit::Spades) => 11,
+            SkatGame::Suit(Suit::Clubs) => 12,
+            SkatGame::Grand => 24,
+            SkatGame::Null => 23
+        }
+    }
+
+    /// Value of game played with declarer's cards (hand with skat).
+    /// For suit and grand games it is base value multiplied by number of matadors increased by one for game
+    /// and by one for every level set in modifiers. Null game is worth 23, 35 when hand, 46 when ouvert
+    /// and 59 when hand and ouvert.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::skat::{SkatGame, SkatModifiers};
+    /// use karty::suits::Suit;
+    /// let cards = card_set![JACK_CLUBS, JACK_SPADES, ACE_SPADES];
+    /// let hand = SkatModifiers{hand: true, ..Default::default()};
+    /// assert_eq!(SkatGame::Grand.game_value(&cards, &hand), 24 * 4);
+    /// let cards = card_set![JACK_SPADES, ACE_CLUBS];
+    /// assert_eq!(SkatGame::Suit(Suit::Clubs).game_value(&cards, &SkatModifiers::default()), 12 * 2);
+    /// assert_eq!(SkatGame::Null.game_value(&cards, &hand), 35);
+    /// ```
+    pub fn game_value(&self, cards: &CardSetStd, modifiers: &SkatModifiers) -> u32{
+        match self.matadors(cards){
+            None => match (modifiers.hand, modifiers.ouvert){
+                (false, false) => 23,
+                (true, false) => 35,
+                (false, true) => 46,
+                (true, true) => 59
+            },
+            Some(matadors) => {
+                let levels = [modifiers.hand, modifiers.schneider, modifiers.schneider_announced,
+                    modifiers.schwarz, modifiers.schwarz_announced, modifiers.ouvert]
+                    .iter().filter(|l| **l).count() as u32;
+                self.base_value() * (matadors.count() as u32 + 1 + levels)
+            }
+        }
+    }
+
+    /// Suit that must be followed when card is led: `None` for trumps.
+    fn led_suit(&self, card: &Card) -> Option<Suit>{
+        match self.is_trump(card){
+            true => None,
+            false => Some(card.suit())
+        }
+    }
+
+    /// Returns cards from hand that can be legally played to trick: player must follow led suit
+    /// (jacks do not belong to their suits, unless null game) or play trump if trump was led.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::skat::SkatGame;
+    /// use karty::suits::Suit;
+    /// use karty::trick::Trick;
+    /// let hand = card_set![JACK_HEARTS, ACE_HEARTS, SEVEN_CLUBS];
+    /// let mut trick = Trick::<Card, 3>::new(0);
+    /// trick.add_card(TEN_HEARTS).unwrap();
+    /// assert_eq!(SkatGame::Grand.legal_moves(&hand, &trick), card_set![ACE_HEARTS]);
+    /// assert_eq!(SkatGame::Null.legal_moves(&hand, &trick), card_set![JACK_HEARTS, ACE_HEARTS]);
+    /// assert_eq!(SkatGame::Suit(Suit::Clubs).legal_moves(&hand, &trick), card_set![ACE_HEARTS]);
+    /// ```
+    pub fn legal_moves<const N: usize>(&self, hand: &CardSetStd, trick: &Trick<Card, N>) -> CardSetStd{
+        let led = match trick.led_card(){
+            None => return *hand,
+            Some(c) => self.led_suit(c)
+        };
+        let following = CardSetStd::from_iterator(hand.into_iter().filter(|c| self.led_suit(c) == led));
+        match following.is_empty(){
+            true => *hand,
+            false => following
+        }
+    }
+
+    /// Returns player taking the trick (currently, if trick is not complete).
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::games::skat::SkatGame;
+    /// use karty::suits::Suit;
+    /// use karty::trick::Trick;
+    /// let mut trick = Trick::<Card, 3>::new(0);
+    /// trick.add_card(ACE_HEARTS).unwrap();
+    /// trick.add_card(TEN_HEARTS).unwrap();
+    /// trick.add_card(JACK_DIAMONDS).unwrap();
+    /// assert_eq!(SkatGame::Grand.trick_winner(&trick), Some(2));
+    /// assert_eq!(SkatGame::Null.trick_winner(&trick), Some(0));
+    /// ```
+    pub fn trick_winner<const N: usize>(&self, trick: &Trick<Card, N>) -> Option<usize>{
+        let comparator = SkatCardComparator{game: *self};
+        trick.iter().reduce(|best, challenger| {
+            let beats = match (self.led_suit(best.1), self.led_suit(challenger.1)){
+                (Some(b), Some(c)) if b != c => false,
+                _ => comparator.compare(challenger.1, best.1) == Ordering::Greater
+            };
+            match beats{
+                true => challenger,
+                false => best
+            }
+        }).map(|(player, _)| player)
+    }
+}
+
+/// Comparator of cards in given Skat game: trumps are above other cards, other cards are ordered
+/// by suit (`♦ < ♥ < ♠ < ♣`) and then by figure. In suit and grand games figures are ordered with
+/// [`SkatFigureComparator`], in null game with natural order.
+/// ```
+/// use karty::cards::*;
+/// use comparator::Comparator;
+/// use karty::games::skat::{SkatCardComparator, SkatGame};
+/// use karty::suits::Suit;
+/// let mut hand = vec![SEVEN_DIAMONDS, ACE_CLUBS, JACK_SPADES, TEN_HEARTS, KING_HEARTS, JACK_CLUBS];
+/// let comparator = SkatCardComparator{game: SkatGame::Suit(Suit::Hearts)};
+/// hand.sort_by(|l, r| comparator.compare(r, l));
+/// assert_eq!(hand, vec![JACK_CLUBS, JACK_SPADES, TEN_HEARTS, KING_HEARTS, ACE_CLUBS, SEVEN_DIAMONDS]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SkatCardComparator{
+    pub game: SkatGame
+}
+
+impl Comparator<Card> for SkatCardComparator{
+    fn compare(&self, l: &Card, r: &Card) -> Ordering {
+        if self.game == SkatGame::Null{
+            return ComparatorDHSC{}.compare(&l.suit(), &r.suit())
+                .then_with(|| FigureComparator{}.compare(&l.figure(), &r.figure()))
+        }
+        match (self.game.is_trump(l), self.game.is_trump(r)){
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (true, true) => CARD_COMPARATOR_SKAT.cmp_figure_suit(l, r),
+            (false, false) => CARD_COMPARATOR_SKAT.cmp_suit_figure(l, r)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::*;
+    use crate::games::skat::{points, Matadors, SkatGame, SkatModifiers, SKAT_DECK, SKAT_TOTAL_POINTS};
+    use crate::set::CardSetStd;
+    use crate::suits::Suit;
+    use crate::trick::Trick;
+    use crate::card_set;
+
+    #[test]
+    fn deck_points(){
+        assert_eq!(points(&SKAT_DECK), SKAT_TOTAL_POINTS);
+    }
+
+    #[test]
+    fn trick_winner_suit_game(){
+        let game = SkatGame::Suit(Suit::Spades);
+        let mut trick = Trick::<Card, 3>::new(1);
+        trick.add_card(JACK_HEARTS).unwrap();
+        trick.add_card(ACE_SPADES).unwrap();
+        trick.add_card(JACK_CLUBS).unwrap();
+        assert_eq!(game.trick_winner(&trick), Some(0));
+        let mut trick = Trick::<Card, 3>::new(0);
+        trick.add_card(KING_HEARTS).unwrap();
+        trick.add_card(ACE_CLUBS).unwrap();
+        trick.add_card(TEN_HEARTS).unwrap();
+        assert_eq!(game.trick_winner(&trick), Some(2));
+        let hand = card_set![JACK_DIAMONDS, SEVEN_SPADES, ACE_HEARTS];
+        let mut trick = Trick::<Card, 3>::new(0);
+        trick.add_card(JACK_SPADES).unwrap();
+        assert_eq!(game.legal_moves(&hand, &trick), card_set![JACK_DIAMONDS, SEVEN_SPADES]);
+    }
+
+    #[test]
+    fn game_values(){
+        let all_trumps = SkatGame::Suit(Suit::Clubs).trump_set();
+        let game = SkatGame::Suit(Suit::Clubs);
+        assert_eq!(game.matadors(&all_trumps), Some(Matadors::With(11)));
+        let schwarz = SkatModifiers{hand: true, schneider: true, schneider_announced: true,
+            schwarz: true, schwarz_announced: true, ouvert: true};
+        assert_eq!(game.game_value(&all_trumps, &schwarz), 12 * 18);
+        assert_eq!(SkatGame::Grand.matadors(&CardSetStd::empty()), Some(Matadors::Without(4)));
+        assert_eq!(SkatGame::Grand.game_value(&CardSetStd::empty(), &SkatModifiers::default()), 24 * 5);
+        assert_eq!(SkatGame::Null.game_value(&all_trumps, &SkatModifiers{hand: true, ouvert: true, ..Default::default()}), 59);
+    }
+}
@@ -0,0 +1,325 @@
+//! Module with rules of partnership Spades: bidding (including nil), play with spades as trumps and scoring with bags.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use std::fmt::{Display, Formatter};
+use crate::cards::Card;
+use crate::error::GameError;
+use crate::set::{CardSetStd, HAND_OF_SPADES};
+use crate::suits::Suit;
+use crate::trick::{legal_moves, Trick, TrickRound};
+
+/// Number of players in Spades, players `0` and `2` play against `1` and `3`
+pub const SPADES_PLAYERS: usize = 4;
+/// Maximal number of tricks that can be bid
+pub const MAX_SPADES_BID: u8 = 13;
+/// Points for successful nil bid (and penalty for failed one)
+pub const NIL_BONUS: i32 = 100;
+/// Number of overtricks (bags) which cost [`BAGS_PENALTY`]
+pub const BAGS_LIMIT: u32 = 10;
+/// Penalty for collecting [`BAGS_LIMIT`] bags
+pub const BAGS_PENALTY: i32 = 100;
+
+/// Bid of single player in Spades.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpadesBid{
+    /// Player declares to take no tricks
+    Nil,
+    /// Player declares to take given number of tricks (at least one)
+    Tricks(u8)
+}
+
+impl Display for SpadesBid{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self{
+            SpadesBid::Nil => write!(f, "Nil"),
+            SpadesBid::Tricks(n) => write!(f, "{n}")
+        }
+    }
+}
+
+/// Partnership of player.
+pub fn team_of(player: usize) -> usize{
+    player % 2
+}
+
+/// Accumulated score of a partnership across rounds.
+/// ```
+/// use karty::games::spades::TeamScore;
+/// let mut score = TeamScore::default();
+/// score.add_round(42, 4);
+/// score.add_round(81, 7);
+/// assert_eq!(score, TeamScore{points: 23, bags: 1});
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TeamScore{
+    pub points: i32,
+    pub bags: u32,
+}
+
+impl TeamScore{
+    /// Adds result of round, every [`BAGS_LIMIT`] collected bags cost [`BAGS_PENALTY`].
+    pub fn add_round(&mut self, points: i32, bags: u32){
+        self.points += points;
+        self.bags += bags;
+        while self.bags >= BAGS_LIMIT{
+            self.bags -= BAGS_LIMIT;
+            self.points -= BAGS_PENALTY;
+        }
+    }
+}
+
+/// State of single deal of Spades.
+/// Players bid in turn starting left of dealer, then player left of dealer leads the first trick.
+/// Spades are trumps and cannot be led until broken (played to some trick), unless player has nothing else.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::spades::{SpadesBid, SpadesRound};
+/// let mut round = SpadesRound::new([
+///     card_set![ACE_SPADES, TWO_HEARTS],
+///     card_set![KING_HEARTS, THREE_CLUBS],
+///     card_set![QUEEN_HEARTS, FOUR_CLUBS],
+///     card_set![JACK_HEARTS, FIVE_CLUBS]], 3);
+/// assert_eq!(round.current_bidder(), Some(0));
+/// for bid in [SpadesBid::Tricks(2), SpadesBid::Nil, SpadesBid::Tricks(1), SpadesBid::Nil]{
+///     round.bid(bid).unwrap();
+/// }
+/// assert_eq!(round.legal_moves(), card_set![TWO_HEARTS]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpadesRound{
+    dealer: usize,
+    bids: [Option<SpadesBid>; SPADES_PLAYERS],
+    play: TrickRound<SPADES_PLAYERS>,
+    spades_broken: bool,
+}
+
+impl SpadesRound{
+    pub fn new(hands: [CardSetStd; SPADES_PLAYERS], dealer: usize) -> Self{
+        Self{
+            dealer,
+            bids: [None; SPADES_PLAYERS],
+            play: TrickRound::new(hands, (dealer + 1) % SPADES_PLAYERS),
+            spades_broken: false
+        }
+    }
+
+    pub fn dealer(&self) -> usize{
+        self.dealer
+    }
+
+    pub fn bids(&self) -> &[Option<SpadesBid>; SPADES_PLAYERS]{
+        &self.bids
+    }
+
+    pub fn hand(&self, player: usize) -> &CardSetStd{
+        self.play.hand(player)
+    }
+
+    pub fn trick(&self) -> &Trick<Card, SPADES_PLAYERS>{
+        self.play.trick()
+    }
+
+    /// Playing phase of round: hands, tricks and taken cards.
+    pub fn trick_round(&self) -> &TrickRound<SPADES_PLAYERS>{
+        &self.play
+    }
+
+    pub fn spades_broken(&self) -> bool{
+        self.spades_broken
+    }
+
+    /// Player expected to bid, `None` when bidding is over.
+    pub fn current_bidder(&self) -> Option<usize>{
+        (1..=SPADES_PLAYERS).map(|i| (self.dealer + i) % SPADES_PLAYERS)
+            .find(|&p| self.bids[p].is_none())
+    }
+
+    pub fn is_bidding(&self) -> bool{
+        self.current_bidder().is_some()
+    }
+
+    /// Makes bid of current bidder.
+    pub fn bid(&mut self, bid: SpadesBid) -> Result<(), GameError>{
+        let player = self.current_bidder().ok_or(GameError::WrongPhase)?;
+        if let SpadesBid::Tricks(n) = bid{
+            if n == 0 || n > MAX_SPADES_BID{
+                return Err(GameError::WrongBid(format!("{n} tricks")))
+            }
+        }
+        self.bids[player] = Some(bid);
+        Ok(())
+    }
+
+    /// Player expected to play card, `None` during bidding or when round is finished.
+    pub fn current_player(&self) -> Option<usize>{
+        match self.is_bidding(){
+            true => None,
+            false => self.play.current_player()
+        }
+    }
+
+    pub fn is_finished(&self) -> bool{
+        !self.is_bidding() && self.play.is_finished()
+    }
+
+    /// Cards that current player may legally play, empty when no one is to play.
+    pub fn legal_moves(&self) -> CardSetStd{
+        let player = match self.current_player(){
+            None => return CardSetStd::empty(),
+            Some(p) => p
+        };
+        let hand = self.play.hand(player);
+        let trick = self.play.trick();
+        if trick.is_empty() && !self.spades_broken{
+            let not_spades = hand.difference(&HAND_OF_SPADES);
+            if !not_spades.is_empty(){
+                return not_spades
+            }
+        }
+        legal_moves(hand, trick)
+    }
+
+    /// Plays card of current player.
+    /// # Returns:
+    /// `Some(winner)` if the card completed a trick, `None` otherwise.
+    pub fn play(&mut self, card: Card) -> Result<Option<usize>, GameError>{
+        if self.is_bidding(){
+            return Err(GameError::WrongPhase)
+        }
+        let legal = self.legal_moves();
+        let result = self.play.play(card, &legal, Some(Suit::Spades))?;
+        if HAND_OF_SPADES.contains(&card){
+            self.spades_broken = true;
+        }
+        Ok(result)
+    }
+
+    /// Points and bags of partnership in this round.
+    /// Partnership scores ten points per trick bid if it took at least as many tricks
+    /// and one point per every overtrick (bag), otherwise it loses ten points per trick bid.
+    /// Nil bid scores [`NIL_BONUS`] if player takes no trick, or loses it otherwise.
+    /// Tricks of nil bidder do not count towards partner's bid, but are counted as bags.
+    /// ```
+    /// use karty::games::spades::{SpadesBid, SpadesRound};
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// let mut round = SpadesRound::new([
+    ///     card_set![TWO_HEARTS], card_set![THREE_HEARTS], card_set![ACE_HEARTS], card_set![FOUR_HEARTS]], 3);
+    /// for bid in [SpadesBid::Tricks(1), SpadesBid::Nil, SpadesBid::Nil, SpadesBid::Tricks(2)]{
+    ///     round.bid(bid).unwrap();
+    /// }
+    /// for card in [TWO_HEARTS, THREE_HEARTS, ACE_HEARTS, FOUR_HEARTS]{
+    ///     round.play(card).unwrap();
+    /// }
+    /// assert_eq!(round.round_score(0), (-100 - 10, 1));
+    /// assert_eq!(round.round_score(1), (100 - 20, 0));
+    /// ```
+    /// # Panics:
+    /// When team is not `0` or `1`.
+    pub fn round_score(&self, team: usize) -> (i32, u32){
+        let team = match team{
+            legit @ (0 | 1) => legit,
+            e => panic!("Invalid spades team {e:?}")
+        };
+        let mut points = 0;
+        let mut bags = 0;
+        let mut contract = 0u32;
+        let mut tricks = 0u32;
+        for player in (0..SPADES_PLAYERS).filter(|&p| team_of(p) == team){
+            let taken = self.play.tricks_won(player) as u32;
+            match self.bids[player]{
+                Some(SpadesBid::Nil) => {
+                    points += match taken{
+                        0 => NIL_BONUS,
+                        _ => -NIL_BONUS
+                    };
+                    bags += taken;
+                },
+                Some(SpadesBid::Tricks(n)) => {
+                    contract += n as u32;
+                    tricks += taken;
+                },
+                None => {}
+            }
+        }
+        if tricks >= contract{
+            let overtricks = tricks - contract;
+            points += (10 * contract + overtricks) as i32;
+            bags += overtricks;
+        } else {
+            points -= (10 * contract) as i32;
+        }
+        (points, bags)
+    }
+
+    /// Adds results of this round to partnerships' scores.
+    pub fn apply_score(&self, scores: &mut [TeamScore; 2]){
+        for (team, score) in scores.iter_mut().enumerate(){
+            let (points, bags) = self.round_score(team);
+            score.add_round(points, bags);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::error::GameError;
+    use crate::games::spades::{SpadesBid, SpadesRound, TeamScore};
+
+    #[test]
+    fn bidding_and_breaking_spades(){
+        let mut round = SpadesRound::new([
+            card_set![TWO_HEARTS, TWO_SPADES],
+            card_set![ACE_HEARTS, THREE_SPADES],
+            card_set![ACE_SPADES, KING_SPADES],
+            card_set![KING_HEARTS, QUEEN_HEARTS]], 0);
+        assert_eq!(round.play(ACE_HEARTS), Err(GameError::WrongPhase));
+        assert_eq!(round.current_bidder(), Some(1));
+        assert!(matches!(round.bid(SpadesBid::Tricks(14)), Err(GameError::WrongBid(_))));
+        for bid in [SpadesBid::Tricks(1), SpadesBid::Tricks(2), SpadesBid::Nil, SpadesBid::Nil]{
+            round.bid(bid).unwrap();
+        }
+        assert_eq!(round.bid(SpadesBid::Nil), Err(GameError::WrongPhase));
+        assert_eq!(round.current_player(), Some(1));
+        assert_eq!(round.legal_moves(), card_set![ACE_HEARTS]);
+        round.play(ACE_HEARTS).unwrap();
+        assert_eq!(round.legal_moves(), card_set![ACE_SPADES, KING_SPADES]);
+        round.play(KING_SPADES).unwrap();
+        round.play(KING_HEARTS).unwrap();
+        assert_eq!(round.play(TWO_HEARTS), Ok(Some(2)));
+        assert!(round.spades_broken());
+        round.play(ACE_SPADES).unwrap();
+        round.play(QUEEN_HEARTS).unwrap();
+        round.play(TWO_SPADES).unwrap();
+        assert_eq!(round.play(THREE_SPADES), Ok(Some(2)));
+        assert!(round.is_finished());
+        let mut scores = [TeamScore::default(); 2];
+        round.apply_score(&mut scores);
+        assert_eq!(scores[0], TeamScore{points: 100 + 20, bags: 0});
+        assert_eq!(scores[1], TeamScore{points: 100 - 10, bags: 0});
+    }
+
+    #[test]
+    fn bags_penalty(){
+        let mut score = TeamScore{points: 250, bags: 8};
+        score.add_round(63, 3);
+        assert_eq!(score, TeamScore{points: 213, bags: 1});
+    }
+
+    #[test]
+    #[should_panic]
+    fn only_two_teams(){
+        let round = SpadesRound::new([card_set![TWO_HEARTS], card_set![THREE_HEARTS], card_set![ACE_HEARTS], card_set![FOUR_HEARTS]], 0);
+        round.round_score(2);
+    }
+}
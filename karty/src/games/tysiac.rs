@@ -0,0 +1,168 @@
+//! Module with rules of Tysiąc (1000): 24-card deck, `A 10 K Q J 9` order, card points and marriages switching trump.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::cards::Card;
+use crate::figures::AceTenFigureComparator;
+use crate::games::marriage::marriage_of;
+use crate::set::CardSetStd;
+use crate::suits::Suit;
+use crate::trick::{Trick, TrickRules};
+use crate::valuation::{CardValuation, ACE_TEN_POINTS};
+
+/// Number of points needed to win the game
+pub const WINNING_SCORE: u32 = 1000;
+/// Sum of card points in Tysiąc deck
+pub const TYSIAC_TOTAL_POINTS: u32 = 120;
+
+/// Set of 24 cards used in Tysiąc (nine to ace in every suit).
+/// ```
+/// use karty::games::tysiac::TYSIAC_DECK;
+/// use karty::set::CardSet;
+/// use karty::cards::*;
+/// assert_eq!(TYSIAC_DECK.len(), 24);
+/// assert!(TYSIAC_DECK.contains(&NINE_HEARTS));
+/// assert!(!TYSIAC_DECK.contains(&EIGHT_HEARTS));
+/// ```
+pub const TYSIAC_DECK: CardSetStd = {
+    const SUIT: u64 = 0x3f << 7;
+    CardSetStd{cards: SUIT | (SUIT << 13) | (SUIT << 26) | (SUIT << 39)}
+};
+
+/// Card points: ace 11, ten 10, king 4, queen 3, jack 2, other cards 0.
+/// ```
+/// use karty::cards::*;
+/// use karty::games::tysiac::card_points;
+/// assert_eq!(card_points(&TEN_SPADES), 10);
+/// assert_eq!(card_points(&NINE_SPADES), 0);
+/// ```
+pub fn card_points(card: &Card) -> u32{
+    ACE_TEN_POINTS.value(card)
+}
+
+/// Sum of card points of cards in set.
+pub fn points(cards: &CardSetStd) -> u32{
+    ACE_TEN_POINTS.set_value(cards)
+}
+
+/// Bonus for declaring marriage: ♥ 100, ♦ 80, ♣ 60, ♠ 40.
+pub fn marriage_bonus(suit: Suit) -> u32{
+    match suit{
+        Suit::Hearts => 100,
+        Suit::Diamonds => 80,
+        Suit::Clubs => 60,
+        Suit::Spades => 40
+    }
+}
+
+/// Rounds points scored in deal to the nearest ten (fives are rounded up).
+/// ```
+/// use karty::games::tysiac::round_points;
+/// assert_eq!(round_points(84), 80);
+/// assert_eq!(round_points(85), 90);
+/// ```
+pub fn round_points(points: u32) -> u32{
+    (points + 5) / 10 * 10
+}
+
+/// Rules of playing tricks in Tysiąc: player must follow suit and beat the trick if able,
+/// when void must play trump (beating trick if it is already trumped).
+/// Trump is set by declaring marriage.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::tysiac::TysiacPlay;
+/// use karty::suits::Suit;
+/// use karty::trick::Trick;
+/// let mut play = TysiacPlay::default();
+/// let hand = card_set![KING_HEARTS, QUEEN_HEARTS, NINE_CLUBS];
+/// let trick = Trick::<Card, 3>::new(0);
+/// assert_eq!(play.declare_marriage(&hand, &trick, &QUEEN_HEARTS), Some(100));
+/// assert_eq!(play.trump(), Some(Suit::Hearts));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TysiacPlay{
+    trump: Option<Suit>,
+}
+
+impl TysiacPlay{
+    pub fn trump(&self) -> Option<Suit>{
+        self.trump
+    }
+
+    /// Rules of trick play under current trump.
+    pub fn rules(&self) -> TrickRules{
+        TrickRules::new(self.trump).with_must_trump(true).with_must_overtake(true)
+    }
+
+    /// Returns cards from hand that can be legally played to trick.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::games::tysiac::TysiacPlay;
+    /// use karty::trick::Trick;
+    /// let hand = card_set![TEN_HEARTS, QUEEN_HEARTS, NINE_CLUBS];
+    /// let mut trick = Trick::<Card, 3>::new(0);
+    /// trick.add_card(KING_HEARTS).unwrap();
+    /// assert_eq!(TysiacPlay::default().legal_moves(&hand, &trick), card_set![TEN_HEARTS]);
+    /// ```
+    pub fn legal_moves<const N: usize>(&self, hand: &CardSetStd, trick: &Trick<Card, N>) -> CardSetStd{
+        self.rules().legal_moves_by(hand, trick, &AceTenFigureComparator{})
+    }
+
+    /// Returns player taking the trick (currently, if trick is not complete).
+    pub fn trick_winner<const N: usize>(&self, trick: &Trick<Card, N>) -> Option<usize>{
+        trick.winner_by(self.trump, &AceTenFigureComparator{})
+    }
+
+    /// Declares marriage when card is led to empty trick: if card is king or queen and hand holds its partner,
+    /// suit becomes trump and bonus is returned. Otherwise returns `None` and trump is not changed.
+    pub fn declare_marriage<const N: usize>(&mut self, hand: &CardSetStd, trick: &Trick<Card, N>, card: &Card) -> Option<u32>{
+        if !trick.is_empty(){
+            return None
+        }
+        let suit = marriage_of(hand, card)?;
+        self.trump = Some(suit);
+        Some(marriage_bonus(suit))
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::games::tysiac::{points, TysiacPlay, TYSIAC_DECK, TYSIAC_TOTAL_POINTS};
+    use crate::suits::Suit;
+    use crate::trick::Trick;
+
+    #[test]
+    fn deck_points(){
+        assert_eq!(points(&TYSIAC_DECK), TYSIAC_TOTAL_POINTS);
+    }
+
+    #[test]
+    fn trump_switching(){
+        let mut play = TysiacPlay::default();
+        let mut trick = Trick::<Card, 3>::new(0);
+        trick.add_card(ACE_CLUBS).unwrap();
+        trick.add_card(NINE_SPADES).unwrap();
+        assert_eq!(play.trick_winner(&trick), Some(0));
+        let hand = card_set![KING_SPADES, QUEEN_SPADES, KING_DIAMONDS, QUEEN_DIAMONDS];
+        assert_eq!(play.declare_marriage(&hand, &trick, &KING_SPADES), None);
+        let empty = Trick::<Card, 3>::new(1);
+        assert_eq!(play.declare_marriage(&hand, &empty, &KING_SPADES), Some(40));
+        assert_eq!(play.trick_winner(&trick), Some(1));
+        assert_eq!(play.declare_marriage(&hand, &empty, &QUEEN_DIAMONDS), Some(80));
+        assert_eq!(play.trump(), Some(Suit::Diamonds));
+        assert_eq!(play.trick_winner(&trick), Some(0));
+        let hand = card_set![NINE_DIAMONDS, TEN_DIAMONDS, JACK_HEARTS];
+        let mut trick = Trick::<Card, 3>::new(0);
+        trick.add_card(ACE_CLUBS).unwrap();
+        trick.add_card(JACK_DIAMONDS).unwrap();
+        assert_eq!(play.legal_moves(&hand, &trick), card_set![TEN_DIAMONDS]);
+    }
+}
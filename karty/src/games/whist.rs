@@ -0,0 +1,139 @@
+//! Module with rules of partnership Whist: trick play with trump suit and scoring over book.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use crate::cards::Card;
+use crate::error::GameError;
+use crate::set::CardSetStd;
+use crate::suits::Suit;
+use crate::trick::{legal_moves, Trick, TrickRound};
+
+/// Number of players in Whist, players `0` and `2` play against `1` and `3`
+pub const WHIST_PLAYERS: usize = 4;
+/// Number of tricks partnership must take before scoring points
+pub const BOOK: usize = 6;
+
+/// State of single deal of Whist.
+/// Player left of dealer leads the first trick, players must follow suit if able.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::games::whist::WhistRound;
+/// use karty::suits::Suit;
+/// let mut round = WhistRound::new([
+///     card_set![ACE_HEARTS, TWO_CLUBS],
+///     card_set![KING_HEARTS, THREE_CLUBS],
+///     card_set![TWO_SPADES, FOUR_CLUBS],
+///     card_set![QUEEN_HEARTS, FIVE_CLUBS]], 3, Suit::Spades);
+/// round.play(ACE_HEARTS).unwrap();
+/// assert_eq!(round.legal_moves(), card_set![KING_HEARTS]);
+/// round.play(KING_HEARTS).unwrap();
+/// round.play(TWO_SPADES).unwrap();
+/// assert_eq!(round.play(QUEEN_HEARTS), Ok(Some(2)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhistRound{
+    dealer: usize,
+    trump: Suit,
+    play: TrickRound<WHIST_PLAYERS>,
+}
+
+impl WhistRound{
+    pub fn new(hands: [CardSetStd; WHIST_PLAYERS], dealer: usize, trump: Suit) -> Self{
+        Self{
+            dealer,
+            trump,
+            play: TrickRound::new(hands, (dealer + 1) % WHIST_PLAYERS)
+        }
+    }
+
+    pub fn dealer(&self) -> usize{
+        self.dealer
+    }
+
+    pub fn trump(&self) -> Suit{
+        self.trump
+    }
+
+    pub fn hand(&self, player: usize) -> &CardSetStd{
+        self.play.hand(player)
+    }
+
+    pub fn trick(&self) -> &Trick<Card, WHIST_PLAYERS>{
+        self.play.trick()
+    }
+
+    /// Playing phase of round: hands, tricks and taken cards.
+    pub fn trick_round(&self) -> &TrickRound<WHIST_PLAYERS>{
+        &self.play
+    }
+
+    pub fn current_player(&self) -> Option<usize>{
+        self.play.current_player()
+    }
+
+    pub fn is_finished(&self) -> bool{
+        self.play.is_finished()
+    }
+
+    /// Cards that current player may legally play, empty when round is finished.
+    pub fn legal_moves(&self) -> CardSetStd{
+        match self.current_player(){
+            None => CardSetStd::empty(),
+            Some(p) => legal_moves(self.play.hand(p), self.play.trick())
+        }
+    }
+
+    /// Plays card of current player.
+    /// # Returns:
+    /// `Some(winner)` if the card completed a trick, `None` otherwise.
+    pub fn play(&mut self, card: Card) -> Result<Option<usize>, GameError>{
+        let legal = self.legal_moves();
+        self.play.play(card, &legal, Some(self.trump))
+    }
+
+    /// Tricks taken by partnership (`0` for players `0` and `2`, `1` for players `1` and `3`).
+    /// # Panics:
+    /// When team is not `0` or `1`.
+    pub fn team_tricks(&self, team: usize) -> usize{
+        match team{
+            legit @ (0 | 1) => self.play.tricks_won(legit) + self.play.tricks_won(legit + 2),
+            e => panic!("Invalid whist team {e:?}")
+        }
+    }
+
+    /// Points of partnership in this round: one for every trick over [`BOOK`].
+    /// # Panics:
+    /// When team is not `0` or `1`.
+    pub fn round_points(&self, team: usize) -> usize{
+        self.team_tricks(team).saturating_sub(BOOK)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::games::whist::{WhistRound, BOOK};
+    use crate::set::{HAND_OF_CLUBS, HAND_OF_DIAMONDS, HAND_OF_HEARTS, HAND_OF_SPADES};
+    use crate::suits::Suit;
+
+    #[test]
+    fn trumps_take_all(){
+        let mut round = WhistRound::new([HAND_OF_CLUBS, HAND_OF_HEARTS, HAND_OF_SPADES, HAND_OF_DIAMONDS], 0, Suit::Spades);
+        while let Some(card) = round.legal_moves().into_iter().next(){
+            round.play(card).unwrap();
+        }
+        assert!(round.is_finished());
+        assert_eq!(round.team_tricks(0), 13);
+        assert_eq!(round.round_points(0), 13 - BOOK);
+        assert_eq!(round.round_points(1), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn only_two_teams(){
+        let round = WhistRound::new([HAND_OF_CLUBS, HAND_OF_HEARTS, HAND_OF_SPADES, HAND_OF_DIAMONDS], 0, Suit::Spades);
+        round.team_tricks(2);
+    }
+}
@@ -1,8 +1,21 @@
 //! Crate providing playing cards, which can be use in standard games like contract bridge or poker.
 //!
 //!
+//! # Features:
+//! Feature `std` is enabled by default. Without it the core of crate (symbols, cards, bitset card sets,
+//! [`SuitMap`](suits::SuitMap), [`valuation`], errors) is `no_std`, feature `alloc` adds parts requiring allocation.
+//! Game rules, tricks, rendering and other optional features require `std`.
+//! Feature `ndarray` adds conversions between [`encoding`] and `ndarray` arrays.
+//! Features `proptest` and `arbitrary` enable module `testing` for property-based testing.
+//!
 //! # Licence:
 //! MIT: [https://mit-license.org/](https://mit-license.org/)
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
 /// Module dedicated to whole distinguished cards
 pub mod cards;
@@ -24,6 +37,28 @@ pub mod random;
 pub mod set;
 /// Crate errors
 pub mod error;
+/// Text rendering of cards, hands and deals
+#[cfg(feature = "std")]
+pub mod render;
+/// Primitives of trick-taking games
+#[cfg(feature = "std")]
+pub mod trick;
+/// Rules of particular games
+#[cfg(feature = "std")]
+pub mod games;
+/// Point values of cards
+pub mod valuation;
+/// Tarot (78-card) deck: cards, points and sets
+pub mod tarot;
+/// Encoding of cards and sets into fixed-shape numeric buffers (e.g. observations for learning agents)
+pub mod encoding;
+/// Mapping between card-play actions of policies and cards, legal action masks
+pub mod action;
+/// Compact binary codec of deals, card sequences and card sets, independent of serde
+pub mod codec;
+/// Property-based testing support: `proptest` strategies and `arbitrary` implementations
+#[cfg(any(feature = "proptest", feature = "arbitrary"))]
+pub mod testing;
 
 
 #[cfg(feature = "speedy")]
@@ -32,3 +67,37 @@ pub use speedy;
 
 
 
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests;
+
+/// Crate is compiled as `no_std` here, `std` is linked only for the test harness.
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests{
+    use core::str::FromStr;
+    use crate::cards::{Card, ACE_SPADES, KING_HEARTS, TWO_CLUBS};
+    use crate::error::{CardError, CardSetErrorGen};
+    use crate::set::{CardSet, CardSetStd};
+    use crate::suits::{Suit, SuitMap, SuitOrder};
+    use crate::symbol::SymbolMap;
+    use crate::valuation::CardValuation;
+
+    #[test]
+    fn core_without_std(){
+        let mut set = CardSetStd::empty();
+        set.insert_card(ACE_SPADES).unwrap();
+        set.insert_card(KING_HEARTS).unwrap();
+        assert_eq!(set.insert_card(ACE_SPADES), Err(CardSetErrorGen::CardDuplicated(ACE_SPADES)));
+        assert_eq!(set.only_in_suit(&Suit::Spades).len(), 1);
+        let mut suit_map = SuitMap::new_symmetric(0u8);
+        for card in set{
+            suit_map[card.suit] += 1;
+        }
+        assert_eq!(suit_map[Suit::Hearts], 1);
+        let symbol_map = SymbolMap::<Card, usize, 52>::from_fn(|c| c.usize_index());
+        assert_eq!(symbol_map[TWO_CLUBS], 0);
+        assert_eq!(SuitOrder::from_str("SHDC").map(|o| o.rank(&Suit::Spades)), Ok(0));
+        assert_eq!(SuitOrder::from_str("SHD"), Err(CardError::InvalidOrder));
+        assert_eq!(crate::valuation::BRIDGE_HCP.set_value(&set), 7);
+    }
+}
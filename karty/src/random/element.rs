@@ -29,7 +29,6 @@ impl<R: Rng, F: FigureTrait + RandomSymbol<R>, S: SuitTrait + RandomSymbol<R>> R
 
 #[cfg(test)]
 mod test{
-    use crate::symbol::CardSymbol;
     use crate::figures::Figure;
     use crate::random::RandomSymbol;
     use crate::suits::Suit;
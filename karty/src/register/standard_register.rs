@@ -2,7 +2,7 @@
 use crate::register::register_trait::{Register};
 use crate::cards::Card;
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CardRegister {
     memory: u64
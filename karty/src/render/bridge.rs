@@ -0,0 +1,97 @@
+use crate::render::{render_suit_lines, RenderStyle};
+use crate::set::CardSetStd;
+
+const DIAGRAM_GAP: usize = 2;
+
+/// Renders hand with one suit per line (spades, hearts, diamonds, clubs), cards in suit from the highest.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::render::{render_hand, RenderStyle};
+/// let hand = card_set![ACE_SPADES, KING_SPADES, TEN_SPADES, TWO_SPADES, QUEEN_HEARTS, NINE_HEARTS, ACE_CLUBS];
+/// assert_eq!(render_hand(&hand, RenderStyle::Plain), "♠ AKT2\n♥ Q9\n♦ -\n♣ A");
+/// ```
+pub fn render_hand(hand: &CardSetStd, style: RenderStyle) -> String{
+    render_suit_lines(hand, style).map(|(line, _)| line).join("\n")
+}
+
+/// Renders classic bridge diagram: North on top, West on the left, East on the right and South at the bottom.
+/// All three columns have the same width, determined by the longest suit line in the deal.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::render::{render_bridge_diagram, RenderStyle};
+/// let north = card_set![ACE_SPADES, KING_SPADES, TWO_HEARTS, ACE_DIAMONDS];
+/// let east = card_set![QUEEN_SPADES, ACE_HEARTS, KING_HEARTS, QUEEN_HEARTS];
+/// let south = card_set![JACK_SPADES, KING_DIAMONDS, ACE_CLUBS, KING_CLUBS];
+/// let west = card_set![TWO_SPADES, THREE_DIAMONDS, FOUR_DIAMONDS, TWO_CLUBS];
+/// let diagram = render_bridge_diagram(&north, &east, &south, &west, RenderStyle::Plain);
+/// assert_eq!(diagram, concat!(
+///     "       ♠ AK\n",
+///     "       ♥ 2\n",
+///     "       ♦ A\n",
+///     "       ♣ -\n",
+///     "♠ 2           ♠ Q\n",
+///     "♥ -           ♥ AKQ\n",
+///     "♦ 43          ♦ -\n",
+///     "♣ 2           ♣ -\n",
+///     "       ♠ J\n",
+///     "       ♥ -\n",
+///     "       ♦ K\n",
+///     "       ♣ AK"));
+/// ```
+pub fn render_bridge_diagram(north: &CardSetStd, east: &CardSetStd, south: &CardSetStd, west: &CardSetStd,
+                             style: RenderStyle) -> String{
+    let [north, east, south, west] = [north, east, south, west]
+        .map(|hand| render_suit_lines(hand, style));
+    let column_width = [&north, &east, &south, &west].iter()
+        .flat_map(|lines| lines.iter().map(|(_, width)| *width))
+        .max()
+        .unwrap_or(0) + DIAGRAM_GAP;
+    let indent = " ".repeat(column_width);
+
+    let mut lines = Vec::with_capacity(12);
+    lines.extend(north.iter().map(|(line, _)| format!("{indent}{line}")));
+    lines.extend(west.iter().zip(east.iter()).map(|((west_line, west_width), (east_line, _))|{
+        let padding = " ".repeat(2 * column_width - west_width);
+        format!("{west_line}{padding}{east_line}")
+    }));
+    lines.extend(south.iter().map(|(line, _)| format!("{indent}{line}")));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[cfg(feature = "parse")]
+mod tests{
+    use std::str::FromStr;
+    use crate::render::{render_bridge_diagram, render_hand, RenderStyle};
+    use crate::set::CardSetStd;
+
+    #[test]
+    fn hand_ansi(){
+        let hand = CardSetStd::from_str("A.K.Q.J").unwrap();
+        assert_eq!(render_hand(&hand, RenderStyle::Ansi),
+                   "♠ A\n\x1b[31m♥\x1b[0m K\n\x1b[31m♦\x1b[0m Q\n♣ J");
+    }
+
+    #[test]
+    fn diagram_ansi_alignment(){
+        let north = CardSetStd::from_str("AKQJT98765432...").unwrap();
+        let east = CardSetStd::from_str(".AKQJT98765432..").unwrap();
+        let south = CardSetStd::from_str("..AKQJT98765432.").unwrap();
+        let west = CardSetStd::from_str("...AKQJT98765432").unwrap();
+        let plain = render_bridge_diagram(&north, &east, &south, &west, RenderStyle::Plain);
+        let ansi = render_bridge_diagram(&north, &east, &south, &west, RenderStyle::Ansi);
+        assert_eq!(ansi.replace("\x1b[31m", "").replace("\x1b[0m", ""), plain);
+        assert_eq!(plain.lines().nth(4).unwrap(), format!("♠ -{}♠ -", " ".repeat(31)));
+    }
+
+    #[test]
+    fn diagram_empty_hands(){
+        let empty = CardSetStd::empty();
+        let diagram = render_bridge_diagram(&empty, &empty, &empty, &empty, RenderStyle::Plain);
+        assert_eq!(diagram.lines().count(), 12);
+        assert_eq!(diagram.lines().next().unwrap(), "     ♠ -");
+        assert_eq!(diagram.lines().nth(4).unwrap(), "♠ -       ♠ -");
+    }
+}
@@ -0,0 +1,71 @@
+//! Module with text rendering of cards and hands, suitable for terminals and logs.
+//! Output is deterministic (no trailing whitespace, fixed suit order), so it can be used in snapshot tests.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+mod bridge;
+mod poker;
+
+pub use bridge::*;
+pub use poker::*;
+
+use crate::cards::{Card, Card2SymTrait};
+use crate::set::{CardSetStd, HandSuitedTrait};
+use crate::suits::Suit;
+
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Style of rendered text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderStyle{
+    /// Plain text with Unicode suit symbols.
+    #[default]
+    Plain,
+    /// Like [`Plain`](RenderStyle::Plain), but hearts and diamonds are coloured red with ANSI escape codes.
+    Ansi
+}
+
+impl RenderStyle{
+    fn paint(&self, suit: Suit, text: &str) -> String{
+        match (self, suit){
+            (RenderStyle::Ansi, Suit::Hearts | Suit::Diamonds) => format!("{ANSI_RED}{text}{ANSI_RESET}"),
+            _ => text.to_string()
+        }
+    }
+}
+
+/// Renders card in short form: figure character followed by suit symbol.
+/// ```
+/// use karty::cards::{KING_HEARTS, TEN_SPADES};
+/// use karty::render::{render_card, RenderStyle};
+/// assert_eq!(render_card(&TEN_SPADES, RenderStyle::Plain), "T♠");
+/// assert_eq!(render_card(&KING_HEARTS, RenderStyle::Plain), "K♥");
+/// assert_eq!(render_card(&KING_HEARTS, RenderStyle::Ansi), "\x1b[31mK♥\x1b[0m");
+/// ```
+pub fn render_card(card: &Card, style: RenderStyle) -> String{
+    style.paint(card.suit(), &format!("{}{:#}", card.figure().repr_char(), card.suit()))
+}
+
+/// Renders cards of hand in one suit, highest first, preceded by suit symbol, e.g. `♠ AQT2`.
+/// Void is rendered as `-`.
+/// Returns rendered line with it's visible width (not counting ANSI escape codes).
+fn render_suit_line(hand: &CardSetStd, suit: Suit, style: RenderStyle) -> (String, usize){
+    let figures: String = hand.suit_iterator(&suit).rev()
+        .map(|c| c.figure().repr_char())
+        .collect();
+    let figures = match figures.is_empty(){
+        true => String::from("-"),
+        false => figures
+    };
+    let width = figures.chars().count() + 2;
+    (format!("{} {}", style.paint(suit, &format!("{suit:#}")), figures), width)
+}
+
+/// Renders lines of hand in order spades, hearts, diamonds, clubs.
+fn render_suit_lines(hand: &CardSetStd, style: RenderStyle) -> [(String, usize); 4]{
+    [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs]
+        .map(|suit| render_suit_line(hand, suit, style))
+}
@@ -0,0 +1,32 @@
+use crate::cards::Card;
+use crate::render::{render_card, RenderStyle};
+
+/// Renders sequence of cards separated with single spaces. Empty sequence is rendered as `-`.
+/// ```
+/// use karty::cards::{ACE_SPADES, TWO_CLUBS};
+/// use karty::render::{render_cards, RenderStyle};
+/// assert_eq!(render_cards(&[ACE_SPADES, TWO_CLUBS], RenderStyle::Plain), "A♠ 2♣");
+/// assert_eq!(render_cards(&[], RenderStyle::Plain), "-");
+/// ```
+pub fn render_cards(cards: &[Card], style: RenderStyle) -> String{
+    match cards.is_empty(){
+        true => String::from("-"),
+        false => cards.iter()
+            .map(|c| render_card(c, style))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// Renders poker line: hole cards, then board cards, separated with `|`.
+/// Cards are rendered in given order.
+/// ```
+/// use karty::cards::*;
+/// use karty::render::{render_poker_line, RenderStyle};
+/// assert_eq!(render_poker_line(&[ACE_SPADES, KING_HEARTS], &[QUEEN_DIAMONDS, JACK_CLUBS, TEN_SPADES], RenderStyle::Plain),
+///     "A♠ K♥ | Q♦ J♣ T♠");
+/// assert_eq!(render_poker_line(&[ACE_SPADES, KING_HEARTS], &[], RenderStyle::Plain), "A♠ K♥ | -");
+/// ```
+pub fn render_poker_line(hole: &[Card], board: &[Card], style: RenderStyle) -> String{
+    format!("{} | {}", render_cards(hole, style), render_cards(board, style))
+}
@@ -0,0 +1,129 @@
+//! Round trip tests of every public type implementing serde traits, in RON, JSON (feature `serde_json`)
+//! and postcard (feature `serde_postcard`).
+use core::fmt::Debug;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::card_set;
+use crate::cards::*;
+use crate::figures::{Figure, FigureOrder, NumberFigure};
+use crate::set::{CardSet, CardSetGeneric, CardSetStd};
+use crate::set::serde_repr::{AsCardList, AsDotted, AsMask, AsSuitMap};
+use crate::suits::{Suit, SuitMap, SuitOrder};
+use crate::symbol::compact::Compact;
+use crate::symbol::SymbolMap;
+use crate::tarot::{TarotCard, TarotCardSet, TarotRank};
+use crate::trick::TrickRules;
+
+fn assert_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T){
+    let ron = ron::to_string(value).unwrap();
+    assert_eq!(&ron::from_str::<T>(&ron).unwrap(), value, "RON: {ron}");
+    #[cfg(feature = "serde_json")]
+    {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value, "JSON: {json}");
+    }
+    #[cfg(feature = "serde_postcard")]
+    {
+        let mut buffer = [0u8; 4096];
+        let serialized = postcard::to_slice(value, &mut buffer).unwrap();
+        assert_eq!(&postcard::from_bytes::<T>(serialized).unwrap(), value, "postcard: {serialized:?}");
+    }
+}
+
+#[test]
+fn symbols(){
+    assert_round_trip(&Suit::Diamonds);
+    assert_round_trip(&NumberFigure::new(7));
+    assert_round_trip(&Figure::Queen);
+    assert_round_trip(&FigureOrder::default());
+    assert_round_trip(&SuitOrder::default());
+    assert_round_trip(&STANDARD_DECK.to_vec());
+    assert_round_trip(&TarotRank::Knight);
+    assert_round_trip(&[TarotCard::Excuse, TarotCard::Trump(21), TarotCard::Suited(TarotRank::Queen, Suit::Hearts)]);
+    for card in STANDARD_DECK{
+        assert_round_trip(&Compact(card));
+    }
+    assert_round_trip(&Compact(Figure::Jack));
+    assert_round_trip(&Compact(Suit::Clubs));
+}
+
+#[test]
+fn sets_and_maps(){
+    let set = card_set![ACE_SPADES, TEN_HEARTS, TWO_DIAMONDS, KING_CLUBS];
+    for set in [set, CardSetStd::empty(), CardSetStd::from_cards(&STANDARD_DECK)]{
+        assert_round_trip(&set);
+        assert_round_trip(&AsDotted(set));
+        assert_round_trip(&AsMask(set));
+        assert_round_trip(&AsCardList(set));
+        assert_round_trip(&AsSuitMap(set));
+    }
+    assert_round_trip(&CardSetGeneric::from_iterator([ACE_SPADES, TWO_CLUBS, NINE_HEARTS].into_iter()));
+    assert_round_trip(&CardSetGeneric::from_iterator([TarotCard::Excuse, TarotCard::Trump(1)].into_iter()));
+    assert_round_trip(&TarotCardSet::from_iterator([TarotCard::Excuse, TarotCard::Trump(1)].into_iter()));
+    assert_round_trip(&SuitMap::new(1u8, 2, 3, 4));
+    assert_round_trip(&SymbolMap::<Suit, _, 4>::new([0.5f32, 1.0, 1.5, 2.0]));
+    assert_round_trip(&TrickRules::new(Some(Suit::Hearts)));
+}
+
+#[test]
+fn card_set_generic_is_sorted(){
+    let set = CardSetGeneric::from_iterator([ACE_SPADES, TWO_CLUBS, NINE_HEARTS, TEN_CLUBS].into_iter());
+    let serialized = ron::to_string(&set).unwrap();
+    assert_eq!(ron::from_str::<Vec<Card>>(&serialized).unwrap(), vec![TWO_CLUBS, TEN_CLUBS, NINE_HEARTS, ACE_SPADES]);
+    assert!(ron::from_str::<CardSetGeneric<Card>>(&ron::to_string(&[TWO_CLUBS, TWO_CLUBS]).unwrap()).is_err());
+}
+
+#[cfg(feature = "register")]
+#[test]
+fn register(){
+    use crate::register::{CardRegister, Register};
+    use crate::games::blackjack::{CardCounter, CountingSystem};
+    let mut register = CardRegister::default();
+    register.register(KING_HEARTS);
+    assert_round_trip(&register);
+    let mut counter = CardCounter::new(CountingSystem::KnockOut, 2);
+    counter.register(FIVE_CLUBS);
+    assert_round_trip(&counter);
+}
+
+#[test]
+fn games(){
+    use crate::games::blackjack::{Action, HandValue, StrategyTable};
+    use crate::games::bridge::{Auction, Bid, Call, Contract, Doubling, Seat, Strain, Vulnerability};
+    use crate::games::hearts::PassDirection;
+    use crate::games::rummy::{AcePosition, Meld, MeldKind, MeldRules};
+    use crate::games::schnapsen::SchnapsenPlay;
+    use crate::games::skat::{Matadors, SkatGame, SkatModifiers};
+    use crate::games::spades::{SpadesBid, TeamScore};
+    use crate::games::tysiac::TysiacPlay;
+
+    assert_round_trip(&[Action::Hit, Action::Stand, Action::Double, Action::Split]);
+    assert_round_trip(&StrategyTable::default());
+    assert_round_trip(&HandValue{total: 17, soft: true});
+
+    let bid = Bid::new(3, Strain::Suit(Suit::Hearts)).unwrap();
+    let mut auction = Auction::new(Seat::East);
+    auction.add_call(Call::Bid(bid)).unwrap();
+    auction.add_call(Call::Double).unwrap();
+    auction.add_call(Call::Pass).unwrap();
+    assert_round_trip(&auction);
+    assert_round_trip(&Call::Bid(Bid::new(7, Strain::NoTrump).unwrap()));
+    assert_round_trip(&Contract::new(bid, Seat::West, Doubling::Redoubled));
+    assert_round_trip(&[Vulnerability::None, Vulnerability::NorthSouth, Vulnerability::EastWest, Vulnerability::Both]);
+
+    assert_round_trip(&PassDirection::Across);
+    assert_round_trip(&[SpadesBid::Nil, SpadesBid::Tricks(4)]);
+    assert_round_trip(&TeamScore{points: -120, bags: 3});
+
+    assert_round_trip(&AcePosition::High);
+    assert_round_trip(&Meld{kind: MeldKind::Run(Suit::Spades), cards: card_set![TWO_SPADES, THREE_SPADES], jokers: 1});
+    assert_round_trip(&Meld{kind: MeldKind::Set(Figure::King), cards: card_set![KING_SPADES, KING_CLUBS, KING_HEARTS], jokers: 0});
+    assert_round_trip(&MeldRules::new(AcePosition::Both).with_min_run(4));
+
+    assert_round_trip(&[SkatGame::Suit(Suit::Clubs), SkatGame::Grand, SkatGame::Null]);
+    assert_round_trip(&[Matadors::With(2), Matadors::Without(3)]);
+    assert_round_trip(&SkatModifiers{schneider: true, ..Default::default()});
+
+    assert_round_trip(&SchnapsenPlay::new(Suit::Diamonds));
+    assert_round_trip(&TysiacPlay::default());
+}
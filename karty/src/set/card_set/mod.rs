@@ -1,13 +1,13 @@
 
 
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
 use crate::cards::{Card, MASK_CLUBS, MASK_DIAMONDS, MASK_HEARTS, MASK_SPADES};
 use crate::error::{CardSetErrorGen};
-use crate::figures::Figure;
+use crate::figures::{Ace, Figure, FigureOrder, Jack, King, Queen, F10};
 use crate::set::CardSet;
 #[cfg(feature="speedy")]
 use crate::speedy::{Readable, Writable};
-use crate::suits::Suit;
+use crate::suits::{Suit, SuitOrder};
 use crate::symbol::CardSymbol;
 
 use super::HandSuitedTrait;
@@ -15,6 +15,8 @@ use super::HandSuitedTrait;
 
 #[cfg(feature = "parse")]
 mod parse;
+#[cfg(feature = "serde")]
+pub mod serde_repr;
 //const CARD_MASK_GUARD:u64 = 1<<52;
 const MASK_STACK_HAND_LEGAL: u64 = MASK_DIAMONDS | MASK_CLUBS | MASK_HEARTS | MASK_SPADES;
 //const STACK_HAND_LARGEST_MASK:u64 = 0x1<<53;
@@ -25,12 +27,121 @@ pub struct CardSetStd {
     pub(crate) cards: u64,
 }
 
+/// Mask with the lowest card (two) of every suit
+const MASK_TWOS: u64 = 1 | 1 << 13 | 1 << 26 | 1 << 39;
+
 impl CardSetStd {
 
+    /// Creates set from bit mask of cards, bits not representing any card are ignored.
+    /// Unlike [`From<u64>`] it can be used in const context.
+    /// ```
+    /// use karty::set::CardSetStd;
+    /// const LOWEST: CardSetStd = CardSetStd::from_u64(0b11);
+    /// assert_eq!(LOWEST.len(), 2);
+    /// assert_eq!(CardSetStd::from_u64(u64::MAX).len(), 52);
+    /// ```
+    pub const fn from_u64(cards: u64) -> Self{
+        Self{cards: cards & MASK_STACK_HAND_LEGAL}
+    }
 
+    /// Creates set of cards from slice, usable in const context.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::set::CardSetStd;
+    /// const BROADWAY_SPADES: CardSetStd = CardSetStd::from_cards(&[ACE_SPADES, KING_SPADES, QUEEN_SPADES, JACK_SPADES, TEN_SPADES]);
+    /// assert_eq!(BROADWAY_SPADES.len(), 5);
+    /// assert!(BROADWAY_SPADES.contains(&QUEEN_SPADES));
+    /// ```
+    pub const fn from_cards(cards: &[Card]) -> Self{
+        let mut mask = 0u64;
+        let mut i = 0;
+        while i < cards.len(){
+            mask |= cards[i].mask();
+            i += 1;
+        }
+        Self{cards: mask}
+    }
 
+    /// Set of all cards in suit.
+    /// ```
+    /// use karty::set::{CardSetStd, HAND_OF_HEARTS};
+    /// use karty::suits::Suit;
+    /// assert_eq!(CardSetStd::of_suit(Suit::Hearts), HAND_OF_HEARTS);
+    /// ```
+    pub const fn of_suit(suit: Suit) -> Self{
+        Self{cards: Self::suit_mask(suit)}
+    }
+
+    /// Set of all cards with figure.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::figures::Figure;
+    /// use karty::set::CardSetStd;
+    /// const ACES: CardSetStd = CardSetStd::of_figure(Figure::Ace);
+    /// assert_eq!(ACES, CardSetStd::from_cards(&[ACE_SPADES, ACE_HEARTS, ACE_DIAMONDS, ACE_CLUBS]));
+    /// ```
+    pub const fn of_figure(figure: Figure) -> Self{
+        Self{cards: MASK_TWOS << figure.usize_index()}
+    }
+
+    /// Const version of [`CardSet::empty`].
+    pub const fn empty() -> Self{
+        Self{cards: 0u64}
+    }
+
+    /// Const version of [`CardSet::contains`].
+    pub const fn contains(&self, card: &Card) -> bool{
+        card.mask() & self.cards != 0
+    }
+
+    /// Const version of [`CardSet::len`].
+    pub const fn len(&self) -> usize{
+        self.cards.count_ones() as usize
+    }
 
-    fn suit_mask(suit: Suit) -> u64{
+    /// Const version of [`CardSet::is_empty`].
+    pub const fn is_empty(&self) -> bool{
+        self.cards == 0
+    }
+
+    /// Const version of [`CardSet::union`].
+    /// ```
+    /// use karty::set::{CardSetStd, HAND_OF_CLUBS, HAND_OF_SPADES};
+    /// const BLACK: CardSetStd = HAND_OF_SPADES.union(&HAND_OF_CLUBS);
+    /// assert_eq!(BLACK.len(), 26);
+    /// ```
+    pub const fn union(&self, other: &Self) -> Self{
+        Self{cards: self.cards | other.cards}
+    }
+
+    /// Const version of [`CardSet::intersection`].
+    /// ```
+    /// use karty::cards::ACE_SPADES;
+    /// use karty::set::{CardSetStd, ACES, HAND_OF_SPADES};
+    /// const ACE: CardSetStd = ACES.intersection(&HAND_OF_SPADES);
+    /// assert_eq!(ACE, CardSetStd::from_cards(&[ACE_SPADES]));
+    /// ```
+    pub const fn intersection(&self, other: &Self) -> Self{
+        Self{cards: self.cards & other.cards}
+    }
+
+    /// Returns set with card added (set is unchanged if card is already in it).
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::set::CardSetStd;
+    /// const PAIR: CardSetStd = CardSetStd::empty().with_card(&ACE_SPADES).with_card(&ACE_HEARTS);
+    /// assert_eq!(PAIR.len(), 2);
+    /// ```
+    pub const fn with_card(&self, card: &Card) -> Self{
+        Self{cards: self.cards | card.mask()}
+    }
+
+    /// Returns set with card removed (set is unchanged if card is not in it).
+    pub const fn without_card(&self, card: &Card) -> Self{
+        Self{cards: self.cards & !card.mask()}
+    }
+
+    const fn suit_mask(suit: Suit) -> u64{
         match suit{
             Suit::Spades => MASK_SPADES,
             Suit::Hearts => MASK_HEARTS,
@@ -39,10 +150,54 @@ impl CardSetStd {
         }
     }
 
-    pub fn only_in_suit(&self, suit: &Suit) -> Self{
+    pub const fn only_in_suit(&self, suit: &Suit) -> Self{
         Self{cards: self.cards & Self::suit_mask(*suit)}
     }
 
+    /// Iterates over cards of set in given order: ascending by suit, then by figure.
+    /// ```
+    /// use core::str::FromStr;
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::figures::FigureOrder;
+    /// use karty::suits::SuitOrder;
+    /// let hand = card_set![ACE_DIAMONDS, TEN_SPADES, KING_SPADES, NINE_CLUBS, ACE_SPADES];
+    /// let cards: Vec<Card> = hand.iter_ordered(SuitOrder::from_str("SHCD").unwrap(),
+    ///     FigureOrder::from_str("9JQKTA").unwrap()).collect();
+    /// assert_eq!(cards, vec![KING_SPADES, TEN_SPADES, ACE_SPADES, NINE_CLUBS, ACE_DIAMONDS]);
+    /// ```
+    pub fn iter_ordered(&self, suit_order: SuitOrder, figure_order: FigureOrder) -> impl Iterator<Item = Card>{
+        let set = *self;
+        let figures = figure_order.figures();
+        suit_order.suits().into_iter()
+            .flat_map(move |suit| figures.into_iter().map(move |figure| Card::new(figure, suit)))
+            .filter(move |card| set.contains(card))
+    }
+
+    /// Returns cards of this set, that are not in the other set.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::card_set;
+    /// use karty::set::HAND_OF_HEARTS;
+    /// let hand = card_set![TWO_DIAMONDS, JACK_HEARTS, KING_CLUBS];
+    /// assert_eq!(hand.difference(&HAND_OF_HEARTS), card_set![TWO_DIAMONDS, KING_CLUBS]);
+    /// ```
+    pub const fn difference(&self, other: &Self) -> Self{
+        Self{cards: self.cards & !other.cards}
+    }
+
+    /// Returns cards in suit of given card, that are higher than it.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::card_set;
+    /// let hand = card_set![TWO_DIAMONDS, JACK_CLUBS, KING_CLUBS, ACE_CLUBS, FOUR_CLUBS];
+    /// assert_eq!(hand.only_higher_in_suit(&TEN_CLUBS), card_set![JACK_CLUBS, KING_CLUBS, ACE_CLUBS]);
+    /// assert_eq!(hand.only_higher_in_suit(&ACE_CLUBS), card_set![]);
+    /// ```
+    pub const fn only_higher_in_suit(&self, card: &Card) -> Self{
+        Self{cards: self.cards & Self::suit_mask(card.suit) & !((card.mask() << 1) - 1)}
+    }
+
 
     /// ```
     /// use karty::cards::{*};
@@ -115,87 +270,7 @@ impl From<CardSetStd> for u64{
 
 impl From<u64> for CardSetStd {
     fn from(cards: u64) -> Self {
-        Self{cards: cards & MASK_STACK_HAND_LEGAL}
-    }
-}
-
-#[cfg(feature = "serde")]
-mod serde{
-    use std::fmt::Formatter;
-    use nom::Finish;
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
-    use smallvec::SmallVec;
-    use serde::de::{Error, Visitor};
-    use crate::set::{CardSetStd, HandSuitedTrait};
-    use crate::set::card_set::parse::parse_card_set;
-    use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
-
-    impl Serialize for CardSetStd {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-            /*let cards_in_suits = [
-                self.suit_iterator(&Spades).fold(String::new(), | acc, s|{
-
-                })
-            ]*/
-            let cards_in_suits = [Spades, Hearts, Diamonds, Clubs].iter().map(|suit|{
-                self.suit_iterator(suit).rev().fold(String::new(), |acc, c|{
-                    acc + &c.figure.repr_char().to_string()
-                })
-            }).collect::<SmallVec<[String;4]>>();
-            let result = format!("{}.{}.{}.{}", cards_in_suits[0], cards_in_suits[1], cards_in_suits[2], cards_in_suits[3]);
-            serializer.serialize_str(&result)
-        }
-    }
-
-    impl<'de> Deserialize<'de> for CardSetStd {
-
-
-
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-            struct CardSetVisitor;
-
-            impl Visitor<'_> for CardSetVisitor{
-                type Value = CardSetStd;
-
-                fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                    formatter.write_str("Expected string \"<SPADES>.<HEARTS>.<DIAMONDS>.<CLUBS>\" or u64")
-                }
-
-                fn visit_u64<E>(self, _v: u64) -> Result<Self::Value, E> where E: Error {
-                    todo!() //match CardSet::fro
-                }
-                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: Error {
-                    parse_card_set(v).finish()
-                        .map(|(_i,cs)| cs)
-                        .map_err(|e| E::custom(format!("Error parsing CardSet: {e:}")))
-                }
-            }
-
-            deserializer.deserialize_str(CardSetVisitor)
-                /*.or_else(
-                deserializer.deserialize_u64(CardSetVisitor)
-            )*/
-        }
-
-    }
-
-    #[cfg(test)]
-    mod tests{
-        use crate::card_set;
-        use crate::cards::{ACE_SPADES, KING_CLUBS, KING_SPADES, TEN_CLUBS, TWO_HEARTS};
-        use crate::set::CardSetStd;
-
-        #[test]
-        fn card_set_serialize(){
-            let card_set1 = card_set![ACE_SPADES, KING_SPADES, KING_CLUBS, TEN_CLUBS, TWO_HEARTS];
-            assert_eq!(ron::to_string(&card_set1).unwrap(), "\"AK.2..KT\"");
-        }
-
-        #[test]
-        fn card_set_deserialize(){
-            let card_set1 = card_set![ACE_SPADES, KING_SPADES, KING_CLUBS, TEN_CLUBS, TWO_HEARTS];
-            assert_eq!(ron::from_str::<CardSetStd>( "\"AK.2..KT\"").unwrap(), card_set1);
-        }
+        Self::from_u64(cards)
     }
 }
 
@@ -536,23 +611,23 @@ impl CardSet for CardSetStd {
     }
 
     fn empty() -> Self {
-        Self{cards: 0u64}
+        CardSetStd::empty()
     }
 
     fn contains(&self, card: &Self::CardType) -> bool {
-        card.mask() & self.cards != 0
+        CardSetStd::contains(self, card)
     }
 
     fn len(&self) -> usize {
-        self.cards.count_ones() as usize
+        CardSetStd::len(self)
     }
 
     fn union(&self, other: &Self) -> Self {
-        Self{cards: self.cards | other.cards}
+        CardSetStd::union(self, other)
     }
 
     fn intersection(&self, other: &Self) -> Self {
-        Self{cards: self.cards & other.cards}
+        CardSetStd::intersection(self, other)
     }
 }
 
@@ -613,18 +688,17 @@ impl HandSuitedTrait for CardSetStd {
 }
 
 impl Display for CardSetStd {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let v: Vec<Card> = self.into_iter().collect();
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f,  "[")?;
         if f.alternate(){
-            for e in v.into_iter(){
+            for e in self.into_iter(){
                 write!(f, "{e:#}, ")?;
             }
 
 
         }
         else{
-            for e in v.into_iter(){
+            for e in self.into_iter(){
                 write!(f, "{e}, ")?;
             }
         }
@@ -651,14 +725,17 @@ impl FromIterator<Card> for CardSetStd {
 }
 
 
-/// Build standard [`CardSetStd`] based on list of cards
+/// Build standard [`CardSetStd`] based on list of cards, can be used in const context.
 /// ```
 /// use karty::card_set;
 /// use crate::karty::set::CardSet;
 /// use karty::cards::{QUEEN_HEARTS, ACE_SPADES, TEN_CLUBS};
+/// use karty::set::CardSetStd;
 /// let cards = card_set!{ACE_SPADES, QUEEN_HEARTS};
 /// assert!(cards.contains(&ACE_SPADES));
 /// assert!(!cards.contains(&TEN_CLUBS));
+/// const MAJOR_QUEEN: CardSetStd = card_set![QUEEN_HEARTS];
+/// assert_eq!(MAJOR_QUEEN.len(), 1);
 /// ```
 #[macro_export]
 macro_rules! card_set {
@@ -668,7 +745,7 @@ macro_rules! card_set {
             $(
                  h |= $x.mask();
             )*
-            $crate::set::CardSetStd::from(h)
+            $crate::set::CardSetStd::from_u64(h)
 
         }
     };
@@ -679,6 +756,26 @@ pub const HAND_OF_HEARTS: CardSetStd = CardSetStd {cards: MASK_HEARTS};
 pub const HAND_OF_DIAMONDS: CardSetStd = CardSetStd {cards: MASK_DIAMONDS};
 pub const HAND_OF_CLUBS: CardSetStd = CardSetStd {cards: MASK_CLUBS};
 
+/// All aces
+pub const ACES: CardSetStd = CardSetStd::of_figure(Ace);
+/// All kings
+pub const KINGS: CardSetStd = CardSetStd::of_figure(King);
+/// All queens
+pub const QUEENS: CardSetStd = CardSetStd::of_figure(Queen);
+/// All jacks
+pub const JACKS: CardSetStd = CardSetStd::of_figure(Jack);
+/// All tens
+pub const TENS: CardSetStd = CardSetStd::of_figure(F10);
+/// Face cards: kings, queens and jacks
+pub const FACE_CARDS: CardSetStd = KINGS.union(&QUEENS).union(&JACKS);
+/// Honours (as in bridge): aces, kings, queens, jacks and tens
+/// ```
+/// use karty::set::{HONOURS, FACE_CARDS, ACES, TENS};
+/// assert_eq!(HONOURS.len(), 20);
+/// assert_eq!(HONOURS.difference(&FACE_CARDS), ACES.union(&TENS));
+/// ```
+pub const HONOURS: CardSetStd = FACE_CARDS.union(&ACES).union(&TENS);
+
 
 
 #[cfg(test)]
@@ -709,7 +806,65 @@ mod tests{
         assert_eq!(card_set![QUEEN_SPADES].cards, hand.cards);
     }
 
+    #[test]
+    fn const_figure_sets(){
+        use crate::figures::FIGURES;
+        use crate::set::HandSuitedTrait;
+        use crate::suits::SUITS;
+        let mut all = CardSetStd::empty();
+        for figure in FIGURES{
+            let set = CardSetStd::of_figure(figure);
+            assert_eq!(set.len(), 4);
+            assert!(set.into_iter().all(|c| c.figure == figure));
+            assert!(SUITS.iter().all(|s| set.contains_in_suit(s)));
+            all = all.union(&set);
+        }
+        assert_eq!(all.len(), 52);
+        assert_eq!(crate::set::HONOURS.into_iter().map(|c| c.figure.power()).min(), Some(10));
+    }
+
 
 
 }
 
+#[cfg(all(test, feature = "proptest"))]
+mod proptests{
+    use proptest::prelude::*;
+    use crate::cards::STANDARD_DECK;
+    use crate::set::{CardSet, CardSetStd};
+    use crate::testing::{card_set, deal};
+
+    proptest!{
+        #[test]
+        fn mask_and_set_consistency(set in any::<CardSetStd>(), other in card_set(0..=20)){
+            let mask = u64::from(set);
+            prop_assert_eq!(set.len(), mask.count_ones() as usize);
+            for card in STANDARD_DECK{
+                prop_assert_eq!(set.contains(&card), mask & card.mask() != 0);
+                prop_assert_eq!(set.union(&other).contains(&card), set.contains(&card) || other.contains(&card));
+                prop_assert_eq!(set.intersection(&other).contains(&card), set.contains(&card) && other.contains(&card));
+                prop_assert_eq!(set.difference(&other).contains(&card), set.contains(&card) && !other.contains(&card));
+            }
+            prop_assert_eq!(CardSetStd::from_iterator(set.into_iter()), set);
+            prop_assert_eq!(CardSetStd::from_u64(mask), set);
+        }
+
+        #[test]
+        fn iteration_is_ascending(set in any::<CardSetStd>()){
+            let indices: Vec<usize> = set.into_iter().map(|c| c.usize_index()).collect();
+            prop_assert!(indices.windows(2).all(|w| w[0] < w[1]));
+            prop_assert_eq!(set.into_iter().rev().count(), set.len());
+        }
+
+        #[test]
+        fn deal_hands_are_disjoint(hands in deal::<4>(13)){
+            let mut all = CardSetStd::empty();
+            for hand in hands{
+                prop_assert_eq!(hand.len(), 13);
+                prop_assert!(all.intersection(&hand).is_empty());
+                all = all.union(&hand);
+            }
+            prop_assert_eq!(all.len(), 52);
+        }
+    }
+}
@@ -6,52 +6,31 @@ use crate::cards::{Card, Card2SymTrait};
 use crate::error::CardSetError;
 use crate::figures::{parse_figure};
 use crate::set::{CardSetStd, CardSet};
+use crate::suits::Suit;
 use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
 
+/// Parses figures of cards in one suit, e.g. `AKT2`.
+pub(crate) fn parse_suit_figures(suit: Suit) -> impl FnMut(&str) -> IResult<&str, CardSetStd>{
+    move |s| fold_many0(
+        parse_figure,
+        CardSetStd::empty,
+        move |mut set: CardSetStd, fig|{
+            set.insert_card_noerr(Card::from_figure_and_suit(fig, suit));
+            set
+        }
+    ).parse(s)
+}
+
 pub(crate) fn parse_card_set(s: &str) -> IResult<&str, CardSetStd>{
 
     (
-        fold_many0(
-            parse_figure,
-            CardSetStd::empty,
-            |mut set: CardSetStd, fig|{
-                set.insert_card_noerr(Card::from_figure_and_suit(fig, Spades));
-                set
-
-            }
-        ),
+        parse_suit_figures(Spades),
         tag("."),
-        fold_many0(
-            parse_figure,
-            CardSetStd::empty,
-            |mut set: CardSetStd, fig|{
-                set.insert_card_noerr(Card::from_figure_and_suit(fig, Hearts));
-                set
-
-            }
-        ),
+        parse_suit_figures(Hearts),
         tag("."),
-        fold_many0(
-            parse_figure,
-            CardSetStd::empty,
-            |mut set: CardSetStd, fig|{
-                set.insert_card_noerr(Card::from_figure_and_suit(fig, Diamonds));
-                set
-
-            }
-        ),
+        parse_suit_figures(Diamonds),
         tag("."),
-        fold_many0(
-            parse_figure,
-            CardSetStd::empty,
-            |mut set: CardSetStd, fig|{
-                set.insert_card_noerr(Card::from_figure_and_suit(fig, Clubs));
-                set
-
-            }
-        ),
-
-
+        parse_suit_figures(Clubs),
     ).parse(s)
         .map(| (rem,(s, _, h, _, d, _, c))|
             (rem, (s.union(&h).union(&d).union(&c))))
@@ -107,4 +86,27 @@ mod tests{
         let card_vec: Vec<Card> = card_set.into_iter().collect();
         assert_eq!(card_vec, [TWO_CLUBS, ACE_CLUBS, SIX_SPADES, EIGHT_SPADES, TEN_SPADES, ACE_SPADES]);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptests{
+    use std::str::FromStr;
+    use proptest::prelude::*;
+    use crate::set::{CardSetStd, HandSuitedTrait};
+    use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
+
+    /// Formats set in dotted form parsed by [`CardSetStd::from_str`], e.g. `AK.Q..T2`.
+    fn dotted(set: &CardSetStd) -> String{
+        [Spades, Hearts, Diamonds, Clubs].iter()
+            .map(|suit| set.suit_iterator(suit).rev().map(|c| c.figure.repr_char()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    proptest!{
+        #[test]
+        fn dotted_round_trip(set in any::<CardSetStd>()){
+            prop_assert_eq!(CardSetStd::from_str(&dotted(&set)), Ok(set));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Module with selectable serde representations of [`CardSetStd`].
+//!
+//! Default implementation of [`Serialize`] writes dotted string `<SPADES>.<HEARTS>.<DIAMONDS>.<CLUBS>`,
+//! [`Deserialize`] accepts dotted string or `u64` mask in human readable formats (e.g. JSON, RON)
+//! and dotted string in others (e.g. postcard).
+//! Other representations can be selected with modules (for `#[serde(with = "...")]`)
+//! or with corresponding newtype wrappers:
+//!
+//! | Module | Wrapper | Example |
+//! |---|---|---|
+//! | [`dotted`] | [`AsDotted`] | `"AK.2..KT"` |
+//! | [`mask`] | [`AsMask`] | `3377699787639040` ([`CardSetStd`] as `u64`) |
+//! | [`card_list`] | [`AsCardList`] | `["TC","KC","2H","KS","AS"]` |
+//! | [`suit_map`] | [`AsSuitMap`] | `{"spades":"AK","hearts":"2","diamonds":"","clubs":"KT"}` |
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use karty::card_set;
+//! use karty::cards::*;
+//! use karty::set::CardSetStd;
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Deal{
+//!     #[serde(with = "karty::set::serde_repr::mask")]
+//!     north: CardSetStd,
+//!     #[serde(with = "karty::set::serde_repr::card_list")]
+//!     south: CardSetStd,
+//! }
+//! let deal = Deal{north: card_set![ACE_SPADES], south: card_set![TWO_CLUBS, TEN_HEARTS]};
+//! let serialized = ron::to_string(&deal).unwrap();
+//! assert_eq!(serialized, "(north:2251799813685248,south:[\"2C\",\"TH\"])");
+//! assert_eq!(ron::from_str::<Deal>(&serialized).unwrap(), deal);
+//! ```
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use core::fmt::Formatter;
+use nom::combinator::all_consuming;
+use nom::{Finish, Parser};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{Error, Unexpected, Visitor};
+use crate::cards::Card;
+use crate::error::CardSetErrorGen;
+use crate::set::{CardSet, CardSetStd, HandSuitedTrait};
+use crate::set::card_set::MASK_STACK_HAND_LEGAL;
+use crate::set::card_set::parse::{parse_card_set, parse_suit_figures};
+use crate::suits::Suit;
+use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
+use crate::symbol::compact::CompactSymbol;
+
+/// Figures of cards in suit in descending order, e.g. `AKT2`.
+fn suit_figures(set: &CardSetStd, suit: Suit) -> String{
+    set.suit_iterator(&suit).rev().map(|c| c.figure.repr_char()).collect()
+}
+
+/// Parses figures of cards in suit, whole string must be consumed.
+fn parse_figures<E: Error>(s: &str, suit: Suit) -> Result<CardSetStd, E>{
+    all_consuming(parse_suit_figures(suit)).parse(s).finish()
+        .map(|(_, set)| set)
+        .map_err(|e| E::custom(format!("Error parsing figures of {suit}: {e:}")))
+}
+
+struct CardSetVisitor;
+
+impl Visitor<'_> for CardSetVisitor{
+    type Value = CardSetStd;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("Expected string \"<SPADES>.<HEARTS>.<DIAMONDS>.<CLUBS>\" or u64")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> where E: Error {
+        match v & !MASK_STACK_HAND_LEGAL{
+            0 => Ok(CardSetStd::from_u64(v)),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: Error {
+        parse_card_set(v).finish()
+            .map(|(_i,cs)| cs)
+            .map_err(|e| E::custom(format!("Error parsing CardSet: {e:}")))
+    }
+}
+
+impl Serialize for CardSetStd {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        dotted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CardSetStd {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        // non self-describing formats (e.g. postcard) do not support deserialize_any
+        match deserializer.is_human_readable(){
+            true => deserializer.deserialize_any(CardSetVisitor),
+            false => deserializer.deserialize_str(CardSetVisitor)
+        }
+    }
+}
+
+/// Dotted string representation `<SPADES>.<HEARTS>.<DIAMONDS>.<CLUBS>`, e.g. `"AK.2..KT"`.
+pub mod dotted{
+    use serde::{Deserializer, Serializer};
+    use crate::set::CardSetStd;
+    use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
+    use super::{suit_figures, CardSetVisitor};
+
+    pub fn serialize<S: Serializer>(set: &CardSetStd, serializer: S) -> Result<S::Ok, S::Error>{
+        let dotted = [Spades, Hearts, Diamonds, Clubs].map(|suit| suit_figures(set, suit)).join(".");
+        serializer.serialize_str(&dotted)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CardSetStd, D::Error>{
+        deserializer.deserialize_str(CardSetVisitor)
+    }
+}
+
+/// Compact representation as `u64` mask of cards (see [`From<CardSetStd>`](CardSetStd) for `u64`).
+/// Masks with bits not representing any card are rejected.
+pub mod mask{
+    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::de::Visitor;
+    use crate::set::CardSetStd;
+    use super::CardSetVisitor;
+
+    pub fn serialize<S: Serializer>(set: &CardSetStd, serializer: S) -> Result<S::Ok, S::Error>{
+        serializer.serialize_u64(u64::from(*set))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CardSetStd, D::Error>{
+        CardSetVisitor.visit_u64(u64::deserialize(deserializer)?)
+    }
+}
+
+/// Sequence of cards in ascending order, every card in [compact form](crate::symbol::compact), e.g. `["2C","TH"]`.
+/// Duplicated cards are rejected.
+pub mod card_list{
+    use serde::{Deserialize, Deserializer, Serializer};
+    use super::*;
+
+    pub fn serialize<S: Serializer>(set: &CardSetStd, serializer: S) -> Result<S::Ok, S::Error>{
+        serializer.collect_seq(set.into_iter().map(|card| card.to_compact_string()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CardSetStd, D::Error>{
+        let mut set = CardSetStd::empty();
+        for card in Vec::<String>::deserialize(deserializer)?{
+            let card = Card::from_compact_str(&card)
+                .map_err(|e| D::Error::custom(format!("{e}: {card:?}")))?;
+            if set.contains(&card){
+                return Err(D::Error::custom(CardSetErrorGen::CardDuplicated(card)))
+            }
+            set.insert_card_noerr(card);
+        }
+        Ok(set)
+    }
+}
+
+/// Map (struct) of figures in suits, e.g. `{"spades":"AK","hearts":"2","diamonds":"","clubs":"KT"}`.
+pub mod suit_map{
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct SuitFigures{
+        spades: String,
+        hearts: String,
+        diamonds: String,
+        clubs: String,
+    }
+
+    pub fn serialize<S: Serializer>(set: &CardSetStd, serializer: S) -> Result<S::Ok, S::Error>{
+        SuitFigures{
+            spades: suit_figures(set, Spades),
+            hearts: suit_figures(set, Hearts),
+            diamonds: suit_figures(set, Diamonds),
+            clubs: suit_figures(set, Clubs),
+        }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CardSetStd, D::Error>{
+        let figures = SuitFigures::deserialize(deserializer)?;
+        Ok(parse_figures::<D::Error>(&figures.spades, Spades)?
+            .union(&parse_figures(&figures.hearts, Hearts)?)
+            .union(&parse_figures(&figures.diamonds, Diamonds)?)
+            .union(&parse_figures(&figures.clubs, Clubs)?))
+    }
+}
+
+macro_rules! card_set_wrapper{
+    ($(#[$meta:meta])* $name:ident, $module:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(#[serde(with = $module)] pub CardSetStd);
+
+        impl From<CardSetStd> for $name{
+            fn from(set: CardSetStd) -> Self {
+                Self(set)
+            }
+        }
+
+        impl From<$name> for CardSetStd{
+            fn from(wrapper: $name) -> Self {
+                wrapper.0
+            }
+        }
+    };
+}
+
+card_set_wrapper!(
+    /// [`CardSetStd`] (de)serialized as dotted string, see [`dotted`].
+    AsDotted, "dotted");
+card_set_wrapper!(
+    /// [`CardSetStd`] (de)serialized as `u64` mask, see [`mask`].
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::set::serde_repr::AsMask;
+    /// let set = AsMask(card_set![TWO_CLUBS, THREE_CLUBS]);
+    /// assert_eq!(ron::to_string(&set).unwrap(), "3");
+    /// assert_eq!(ron::from_str::<AsMask>("3").unwrap(), set);
+    /// ```
+    AsMask, "mask");
+card_set_wrapper!(
+    /// [`CardSetStd`] (de)serialized as sequence of cards, see [`card_list`].
+    AsCardList, "card_list");
+card_set_wrapper!(
+    /// [`CardSetStd`] (de)serialized as map of figures in suits, see [`suit_map`].
+    AsSuitMap, "suit_map");
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::{ACE_SPADES, KING_CLUBS, KING_SPADES, TEN_CLUBS, TWO_HEARTS};
+    use crate::set::CardSetStd;
+    use crate::set::serde_repr::{AsCardList, AsDotted, AsMask, AsSuitMap};
+
+    fn example() -> CardSetStd{
+        card_set![ACE_SPADES, KING_SPADES, KING_CLUBS, TEN_CLUBS, TWO_HEARTS]
+    }
+
+    #[test]
+    fn card_set_serialize(){
+        assert_eq!(ron::to_string(&example()).unwrap(), "\"AK.2..KT\"");
+    }
+
+    #[test]
+    fn card_set_deserialize(){
+        assert_eq!(ron::from_str::<CardSetStd>( "\"AK.2..KT\"").unwrap(), example());
+    }
+
+    #[test]
+    fn card_set_deserialize_u64(){
+        let card_set1 = example();
+        assert_eq!(ron::from_str::<CardSetStd>(&u64::from(card_set1).to_string()).unwrap(), card_set1);
+        assert!(ron::from_str::<CardSetStd>(&(1u64 << 60).to_string()).is_err());
+        assert!(ron::from_str::<CardSetStd>("-1").is_err());
+    }
+
+    #[test]
+    fn representations_ron(){
+        let set = example();
+        assert_eq!(ron::to_string(&AsDotted(set)).unwrap(), "\"AK.2..KT\"");
+        assert_eq!(ron::to_string(&AsMask(set)).unwrap(), u64::from(set).to_string());
+        assert_eq!(ron::to_string(&AsCardList(set)).unwrap(), "[\"TC\",\"KC\",\"2H\",\"KS\",\"AS\"]");
+        assert_eq!(ron::to_string(&AsSuitMap(set)).unwrap(), "(spades:\"AK\",hearts:\"2\",diamonds:\"\",clubs:\"KT\")");
+        assert_eq!(ron::from_str::<AsDotted>("\"AK.2..KT\"").unwrap(), AsDotted(set));
+        assert_eq!(ron::from_str::<AsMask>(&u64::from(set).to_string()).unwrap(), AsMask(set));
+        assert_eq!(ron::from_str::<AsCardList>("[\"ks\", \"A♠\", \"10 c\", \"🃞\", \"H2\"]").unwrap(), AsCardList(set));
+        assert_eq!(ron::from_str::<AsSuitMap>("(spades:\"KA\",hearts:\"2\",diamonds:\"\",clubs:\"K10\")").unwrap(), AsSuitMap(set));
+    }
+
+    #[test]
+    fn representations_reject_invalid(){
+        assert!(ron::from_str::<AsMask>("\"AK.2..KT\"").is_err());
+        assert!(ron::from_str::<AsMask>(&u64::MAX.to_string()).is_err());
+        assert!(ron::from_str::<AsDotted>("7").is_err());
+        assert!(ron::from_str::<AsCardList>("[\"AS\", \"AS\"]").is_err());
+        assert!(ron::from_str::<AsCardList>("[\"ASX\"]").is_err());
+        assert!(ron::from_str::<AsSuitMap>("(spades:\"AX\",hearts:\"\",diamonds:\"\",clubs:\"\")").is_err());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn representations_json(){
+        let set = example();
+        assert_eq!(serde_json::to_string(&AsCardList(set)).unwrap(), "[\"TC\",\"KC\",\"2H\",\"KS\",\"AS\"]");
+        assert_eq!(serde_json::to_string(&AsSuitMap(set)).unwrap(), "{\"spades\":\"AK\",\"hearts\":\"2\",\"diamonds\":\"\",\"clubs\":\"KT\"}");
+        assert_eq!(serde_json::from_str::<CardSetStd>(&u64::from(set).to_string()).unwrap(), set);
+        assert_eq!(serde_json::from_str::<CardSetStd>("\"AK.2..KT\"").unwrap(), set);
+        for json in [
+            serde_json::to_string(&AsDotted(set)).unwrap(),
+            serde_json::to_string(&AsMask(set)).unwrap(),
+        ]{
+            assert_eq!(serde_json::from_str::<CardSetStd>(&json).unwrap(), set);
+        }
+        assert_eq!(serde_json::from_str::<AsCardList>("[\"TC\",\"KC\",\"2H\",\"KS\",\"AS\"]").unwrap(), AsCardList(set));
+        assert_eq!(serde_json::from_str::<AsSuitMap>("{\"spades\":\"AK\",\"hearts\":\"2\",\"diamonds\":\"\",\"clubs\":\"KT\"}").unwrap(), AsSuitMap(set));
+    }
+
+    #[cfg(feature = "serde_postcard")]
+    fn postcard_round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) -> (T, usize){
+        let mut buffer = [0u8; 64];
+        let serialized = postcard::to_slice(value, &mut buffer).unwrap();
+        (postcard::from_bytes(serialized).unwrap(), serialized.len())
+    }
+
+    #[cfg(feature = "serde_postcard")]
+    #[test]
+    fn representations_postcard(){
+        let set = example();
+        let (deserialized, dotted_len) = postcard_round_trip(&set);
+        assert_eq!(deserialized, set);
+        assert_eq!(postcard_round_trip(&AsDotted(set)).0, AsDotted(set));
+        let (deserialized, mask_len) = postcard_round_trip(&AsMask(set));
+        assert_eq!(deserialized, AsMask(set));
+        assert_eq!(postcard_round_trip(&AsCardList(set)).0, AsCardList(set));
+        assert_eq!(postcard_round_trip(&AsSuitMap(set)).0, AsSuitMap(set));
+        // mask is stored as varint, shorter than dotted string
+        assert!(mask_len < dotted_len);
+    }
+}
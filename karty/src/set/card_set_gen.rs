@@ -91,4 +91,31 @@ impl<Crd: CardSymbol + Display> Display for CardSetGeneric<Crd>{
         }
         write!(f, "]")
     }
-}
\ No newline at end of file
+}
+/// Serialized as sequence of cards sorted by [`CardSymbol::usize_index`], so equal sets
+/// always produce the same output. Deserialization rejects duplicated cards.
+#[cfg(feature = "serde")]
+mod serde{
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::Error;
+    use crate::set::{CardSet, CardSetGeneric};
+    use crate::symbol::CardSymbol;
+
+    impl<Crd: CardSymbol + Serialize> Serialize for CardSetGeneric<Crd>{
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+            let mut cards: Vec<&Crd> = self.cards.iter().collect();
+            cards.sort_unstable_by_key(|c| c.usize_index());
+            serializer.collect_seq(cards)
+        }
+    }
+
+    impl<'de, Crd: CardSymbol + Deserialize<'de>> Deserialize<'de> for CardSetGeneric<Crd>{
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+            let mut set = Self::empty();
+            for card in Vec::<Crd>::deserialize(deserializer)?{
+                set.insert_card(card).map_err(D::Error::custom)?;
+            }
+            Ok(set)
+        }
+    }
+}
@@ -1,9 +1,11 @@
+#[cfg(feature = "std")]
 mod card_set_gen;
 mod card_set;
 mod r#trait;
 //mod card_set_gen_repeating;
 
 pub use card_set::*;
+#[cfg(feature = "std")]
 pub use card_set_gen::*;
 pub use r#trait::*;
 //#[cfg(feature = "fuzzy")]
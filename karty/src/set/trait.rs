@@ -1,5 +1,5 @@
 
-use std::fmt::{Debug};
+use core::fmt::{Debug};
 use crate::error::CardSetErrorGen;
 use crate::suits::{SuitTrait};
 use crate::symbol::CardSymbol;
@@ -62,7 +62,8 @@ pub trait CardSet: Debug + Clone + Eq + IntoIterator<Item=Self::CardType> + Debu
          hand
      }
 
-    fn to_vec(self) -> Vec<Self::CardType>{
+    #[cfg(feature = "alloc")]
+    fn to_vec(self) -> alloc::vec::Vec<Self::CardType>{
         self.into_iter().collect()
     }
 
@@ -6,8 +6,10 @@
 //!
 mod r#trait;
 mod standard;
+mod order;
 pub use crate::suits::standard::*;
 pub use r#trait::*;
+pub use order::*;
 
 #[cfg(feature = "parse")]
 pub mod parse;
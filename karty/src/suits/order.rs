@@ -0,0 +1,170 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use comparator::Comparator;
+use crate::error::CardError;
+use crate::suits::{Suit, SUITS};
+use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
+
+/// Order of suits chosen at runtime (e.g. loaded from game configuration).
+/// Suits are listed in ascending order, like in names of static comparators:
+/// `"CDHS"` is the same order as [`ComparatorCDHS`](crate::suits::ComparatorCDHS) (`Spades > Hearts > Diamonds > Clubs`).
+/// [`Default`] order is `CDHS`.
+/// ```
+/// use comparator::Comparator;
+/// use core::cmp::Ordering;
+/// use core::str::FromStr;
+/// use karty::suits::{Suit, SuitOrder};
+/// let order = SuitOrder::from_str("SHDC").unwrap();
+/// assert_eq!(order.compare(&Suit::Clubs, &Suit::Spades), Ordering::Greater);
+/// assert_eq!(order.compare(&Suit::Hearts, &Suit::Diamonds), Ordering::Less);
+/// assert_eq!(order.to_string(), "SHDC");
+/// assert_eq!(SuitOrder::from_str("♣♦♥♠").unwrap(), SuitOrder::default());
+/// assert!(SuitOrder::from_str("SHDD").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[Suit; 4]", into = "[Suit; 4]"))]
+pub struct SuitOrder([Suit; 4]);
+
+impl SuitOrder{
+    /// Creates order from suits listed in ascending order.
+    /// # Returns:
+    /// `Err` if some suit is listed more than once.
+    pub fn new(ascending: [Suit; 4]) -> Result<Self, CardError>{
+        match SUITS.iter().all(|s| ascending.contains(s)){
+            true => Ok(Self(ascending)),
+            false => Err(CardError::InvalidOrder)
+        }
+    }
+
+    /// Suits in ascending order.
+    pub fn suits(&self) -> [Suit; 4]{
+        self.0
+    }
+
+    /// Position of suit in order, `0` for the lowest.
+    pub fn rank(&self, suit: &Suit) -> usize{
+        self.0.iter().position(|s| s == suit).expect("Every suit is in order (should not happen, it is a bug).")
+    }
+}
+
+/// Validates that every suit is listed exactly once, like [`SuitOrder::new`].
+impl TryFrom<[Suit; 4]> for SuitOrder{
+    type Error = CardError;
+
+    fn try_from(ascending: [Suit; 4]) -> Result<Self, Self::Error> {
+        Self::new(ascending)
+    }
+}
+
+impl From<SuitOrder> for [Suit; 4]{
+    fn from(order: SuitOrder) -> Self {
+        order.0
+    }
+}
+
+impl Default for SuitOrder{
+    fn default() -> Self {
+        Self(SUITS)
+    }
+}
+
+impl Comparator<Suit> for SuitOrder{
+    fn compare(&self, l: &Suit, r: &Suit) -> Ordering {
+        self.rank(l).cmp(&self.rank(r))
+    }
+}
+
+fn suit_of_char(c: char) -> Option<Suit>{
+    match c.to_ascii_uppercase(){
+        'S' | '♠' | '♤' => Some(Spades),
+        'H' | '♥' | '♡' => Some(Hearts),
+        'D' | '♦' | '♢' => Some(Diamonds),
+        'C' | '♣' | '♧' => Some(Clubs),
+        _ => None
+    }
+}
+
+/// Parses order from four characters (letters `SHDC` or suit symbols), lowest suit first.
+impl FromStr for SuitOrder{
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ascending = SUITS;
+        let mut count = 0;
+        for c in s.trim().chars(){
+            let suit = suit_of_char(c).ok_or(CardError::InvalidOrder)?;
+            *ascending.get_mut(count).ok_or(CardError::InvalidOrder)? = suit;
+            count += 1;
+        }
+        match count{
+            4 => Self::new(ascending),
+            _ => Err(CardError::InvalidOrder)
+        }
+    }
+}
+
+/// Implements [`Display`]: first letters of suits, alternate form uses symbols.
+impl Display for SuitOrder{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for suit in self.0{
+            match f.alternate(){
+                true => write!(f, "{suit:#}")?,
+                false => write!(f, "{}", suit.repr_char())?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads order with the same validation as [`TryFrom`].
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for SuitOrder{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let ascending = <[Suit; 4]>::read_from(reader)?;
+        SuitOrder::new(ascending).map_err(|e| speedy::Error::custom(e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use core::str::FromStr;
+    use comparator::Comparator;
+    use crate::suits::{ComparatorCDHS, ComparatorDCHS, ComparatorDHSC, Suit, SuitOrder, SUITS};
+
+    fn assert_same_order<C: Comparator<Suit>>(order: &str, comparator: C){
+        let order = SuitOrder::from_str(order).unwrap();
+        for l in SUITS{
+            for r in SUITS{
+                assert_eq!(order.compare(&l, &r), comparator.compare(&l, &r));
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_static_comparators(){
+        assert_same_order("CDHS", ComparatorCDHS{});
+        assert_same_order("DCHS", ComparatorDCHS{});
+        assert_same_order("DHSC", ComparatorDHSC{});
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_repeated_suit(){
+        let order = SuitOrder::from_str("SHDC").unwrap();
+        assert_eq!(ron::from_str::<SuitOrder>(&ron::to_string(&order).unwrap()).unwrap(), order);
+        assert!(ron::from_str::<SuitOrder>("(Spades,Spades,Spades,Spades)").is_err());
+    }
+
+    #[cfg(feature = "speedy")]
+    #[test]
+    fn speedy_rejects_repeated_suit(){
+        use speedy::{Readable, Writable};
+        let order = SuitOrder::from_str("DHSC").unwrap();
+        assert_eq!(SuitOrder::read_from_buffer(&order.write_to_vec().unwrap()).unwrap(), order);
+        let repeated = [Suit::Spades; 4].write_to_vec().unwrap();
+        assert!(SuitOrder::read_from_buffer(&repeated).is_err());
+    }
+}
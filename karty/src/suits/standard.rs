@@ -4,8 +4,8 @@
 //! # Authors:
 //! [morovintaas](mailto:moroviintaas@gmail.com)
 //!
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
 use comparator::Comparator;
 use crate::symbol::CardSymbol;
 use crate::error::CardError;
@@ -45,8 +45,54 @@ pub enum Suit {
 }
 
 impl Suit {
+    /// First letter of suit name.
+    /// ```
+    /// use karty::suits::Suit;
+    /// assert_eq!(Suit::Diamonds.repr_char(), 'D');
+    /// ```
+    pub const fn repr_char(&self) -> char{
+        match self{
+            Spades => 'S',
+            Hearts => 'H',
+            Diamonds => 'D',
+            Clubs => 'C'
+        }
+    }
 
+    /// Const version of [`CardSymbol::usize_index`].
+    /// ```
+    /// use karty::suits::Suit;
+    /// const HEARTS_INDEX: usize = Suit::Hearts.usize_index();
+    /// assert_eq!(HEARTS_INDEX, 2);
+    /// ```
+    pub const fn usize_index(&self) -> usize {
+        match self{
+            Spades => 3,
+            Hearts => 2,
+            Diamonds => 1,
+            Clubs => 0
+        }
+    }
 
+    /// Const version of [`CardSymbol::from_usize_index`].
+    /// ```
+    /// use karty::suits::Suit;
+    /// const SPADES: Suit = match Suit::from_usize_index(3){
+    ///     Ok(suit) => suit,
+    ///     Err(_) => panic!()
+    /// };
+    /// assert_eq!(SPADES, Suit::Spades);
+    /// assert!(Suit::from_usize_index(4).is_err());
+    /// ```
+    pub const fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        match position{
+            3 => Ok(Spades),
+            2 => Ok(Hearts),
+            1 => Ok(Diamonds),
+            0 => Ok(Clubs),
+            s => Err(WrongSuitPosition(s))
+        }
+    }
 }
 
 /// Array of standard suits `[Clubs, Diamonds, Hearts, Spades]`
@@ -77,22 +123,11 @@ impl CardSymbol for Suit {
     const SYMBOL_SPACE: usize = 4;
 
     fn usize_index(&self) -> usize {
-        match self{
-            Spades => 3,
-            Hearts => 2,
-            Diamonds => 1,
-            Clubs => 0
-        }
+        Suit::usize_index(self)
     }
 
     fn from_usize_index(position: usize) -> Result<Self, CardError> {
-        match position{
-            3 => Ok(Spades),
-            2 => Ok(Hearts),
-            1 => Ok(Diamonds),
-            0 => Ok(Clubs),
-            s => Err(WrongSuitPosition(s))
-        }
+        Suit::from_usize_index(position)
     }
 }
 
@@ -100,7 +135,7 @@ impl SuitTrait for Suit {
     const NUMBER_OF_SUITS: usize = Self::SYMBOL_SPACE;
 
 }
-/// Implements [`Display`][std::fmt::Display]
+/// Implements [`Display`][core::fmt::Display]
 /// # Examples:
 /// ```
 /// use karty::suits::Suit;
@@ -109,7 +144,7 @@ impl SuitTrait for Suit {
 /// assert_eq!("♠", format!("{:#}", Suit::Spades));
 /// ```
 impl Display for Suit {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if f.alternate(){
             match self {
                 Spades => write!(f, "♠"),
@@ -187,6 +222,36 @@ impl Comparator<Suit> for ComparatorCDHS {
 }
 
 
+/// Comparator implementing ordering `Clubs > Spades > Hearts > Diamonds`, used in Skat
+/// (for example to order jacks).
+/// ```
+/// use comparator::Comparator;
+/// use karty::suits::{ComparatorDHSC, Suit};
+/// use core::cmp::Ordering;
+/// assert_eq!(ComparatorDHSC{}.compare(&Suit::Clubs, &Suit::Spades), Ordering::Greater);
+/// assert_eq!(ComparatorDHSC{}.compare(&Suit::Diamonds, &Suit::Hearts), Ordering::Less);
+/// ```
+#[derive(Default, Copy, Clone)]
+pub struct ComparatorDHSC {}
+
+impl ComparatorDHSC {
+    fn rank(suit: &Suit) -> u8{
+        match suit{
+            Clubs => 3,
+            Spades => 2,
+            Hearts => 1,
+            Diamonds => 0
+        }
+    }
+}
+
+impl Comparator<Suit> for ComparatorDHSC {
+    fn compare(&self, l: &Suit, r: &Suit) -> Ordering {
+        Self::rank(l).cmp(&Self::rank(r))
+    }
+}
+
+
 
 #[cfg(test)]
 mod tests{
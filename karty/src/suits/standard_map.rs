@@ -1,11 +1,23 @@
 
-use std::iter::Chain;
-use std::ops::{Add, Index, IndexMut};
-use crate::suits::{Suit};
+use core::cmp::Ordering;
+use core::iter::Chain;
+use core::ops::{Add, Index, IndexMut};
+use crate::suits::{Suit, SuitOrder, SUITS};
 use crate::suits::Suit::{Clubs, Diamonds, Hearts, Spades};
 
-
+/// Map of values for every suit. Map may have privileged suit (e.g. trump), which is then first
+/// in iteration order ([`iter`](Self::iter), [`iter_mut`](Self::iter_mut), [`into_iter`](IntoIterator::into_iter)),
+/// other suits follow in ascending order (clubs, diamonds, hearts, spades).
+/// ```
+/// use karty::suits::{Suit, SuitMap};
+/// let lengths = SuitMap::new(5, 3, 3, 2).with_privilege(Suit::Diamonds);
+/// assert_eq!(lengths.iter().map(|(s, l)| (s, *l)).collect::<Vec<_>>(),
+///     vec![(Suit::Diamonds, 3), (Suit::Clubs, 2), (Suit::Hearts, 3), (Suit::Spades, 5)]);
+/// assert_eq!(lengths.max_by(|a, b| a.cmp(b)), (Suit::Spades, &5));
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SuitMap<T>{
     pub spades: T,
     pub hearts: T,
@@ -46,11 +58,91 @@ impl<T> SuitMap<T>{
         self[*suit] = value;
     }
     
+    /// Sets privileged suit, which becomes first in iteration order.
     pub fn with_privilege(mut self, privileged: Suit) -> Self{
         self.privileged_suit = Some(privileged);
         self
     }
 
+    pub fn privileged_suit(&self) -> Option<Suit>{
+        self.privileged_suit
+    }
+
+    /// Suits in iteration order: privileged suit (if set) first, then the others ascending.
+    /// ```
+    /// use karty::suits::{Suit, SuitMap};
+    /// use karty::suits::Suit::*;
+    /// assert_eq!(SuitMap::new_symmetric(0).suits(), [Clubs, Diamonds, Hearts, Spades]);
+    /// assert_eq!(SuitMap::new_symmetric(0).with_privilege(Hearts).suits(), [Hearts, Clubs, Diamonds, Spades]);
+    /// ```
+    pub fn suits(&self) -> [Suit; 4]{
+        match self.privileged_suit{
+            None => SUITS,
+            Some(privileged) => {
+                let mut suits = SUITS;
+                suits[..=privileged.usize_index()].rotate_right(1);
+                suits
+            }
+        }
+    }
+
+    /// Creates map calling `f` for suits in given order (from the lowest), which matters when `f` has state.
+    /// ```
+    /// use core::str::FromStr;
+    /// use karty::suits::{SuitMap, SuitOrder};
+    /// let mut counter = 0;
+    /// let map = SuitMap::from_fn(&SuitOrder::from_str("SHDC").unwrap(), |_| { counter += 1; counter });
+    /// assert_eq!(map, SuitMap::new(1, 2, 3, 4));
+    /// ```
+    pub fn from_fn<F: FnMut(Suit) -> T>(order: &SuitOrder, mut f: F) -> Self{
+        let mut values = [None, None, None, None];
+        for suit in order.suits(){
+            values[suit.usize_index()] = Some(f(suit));
+        }
+        let [clubs, diamonds, hearts, spades] = values.map(|v| v.expect("Every suit is in order (should not happen, it is a bug)."));
+        Self{spades, hearts, diamonds, clubs, privileged_suit: None}
+    }
+
+    /// Iterates over pairs of suit and value, in iteration order (see [`suits`](Self::suits)).
+    pub fn iter(&self) -> core::array::IntoIter<(Suit, &T), 4>{
+        self.suits().map(|suit| (suit, &self[suit])).into_iter()
+    }
+
+    /// Iterates over pairs of suit and mutable value, in iteration order (see [`suits`](Self::suits)).
+    /// ```
+    /// use karty::suits::{Suit, SuitMap};
+    /// let mut map = SuitMap::new(1, 2, 3, 4);
+    /// for (suit, v) in map.iter_mut(){
+    ///     if suit != Suit::Spades{
+    ///         *v *= 10;
+    ///     }
+    /// }
+    /// assert_eq!(map, SuitMap::new(1, 20, 30, 40));
+    /// ```
+    pub fn iter_mut(&mut self) -> core::array::IntoIter<(Suit, &mut T), 4>{
+        let suits = self.suits();
+        let mut values = [Some(&mut self.clubs), Some(&mut self.diamonds), Some(&mut self.hearts), Some(&mut self.spades)];
+        suits.map(|suit| (suit, values[suit.usize_index()].take()
+            .expect("Every suit is taken once (should not happen, it is a bug)."))).into_iter()
+    }
+
+    /// Pairs values of two maps, privileged suit of this map is kept.
+    /// ```
+    /// use karty::suits::SuitMap;
+    /// let lengths = SuitMap::new(5, 3, 3, 2);
+    /// let points = SuitMap::new(10, 0, 4, 1);
+    /// assert_eq!(lengths.zip(points).map(|(l, p)| l + p), SuitMap::new(15, 3, 7, 3));
+    /// ```
+    pub fn zip<U>(self, other: SuitMap<U>) -> SuitMap<(T, U)>{
+        SuitMap::<(T, U)>{
+            spades: (self.spades, other.spades),
+            hearts: (self.hearts, other.hearts),
+            diamonds: (self.diamonds, other.diamonds),
+            clubs: (self.clubs, other.clubs),
+            privileged_suit: self.privileged_suit,
+        }
+    }
+
     pub fn new_from_f<F>(f: F) -> Self
     where F: Fn(Suit) -> T{
         Self{
@@ -72,9 +164,54 @@ impl<T> SuitMap<T>{
         }
     }
 
+    /// Like [`map`](Self::map), but function gets also suit.
+    /// ```
+    /// use karty::suits::{Suit, SuitMap};
+    /// let map = SuitMap::new(1, 2, 3, 4).map_with_suit(|s, v| match s{
+    ///     Suit::Spades => v * 2,
+    ///     _ => *v
+    /// });
+    /// assert_eq!(map, SuitMap::new(2, 2, 3, 4));
+    /// ```
+    pub fn map_with_suit<R, F: Fn(Suit, &T) -> R>(&self, f: F) -> SuitMap<R>{
+        SuitMap::<R>{
+            spades: f(Spades, &self.spades),
+            hearts: f(Hearts, &self.hearts),
+            diamonds: f(Diamonds, &self.diamonds),
+            clubs: f(Clubs, &self.clubs),
+            privileged_suit: self.privileged_suit,
+        }
+    }
+
     pub fn and<F: Fn(&T) -> bool>(&self, f: F) -> bool{
         f(&self.spades) && f(&self.hearts) && f(&self.diamonds) && f(&self.clubs)
     }
+
+    /// Checks if predicate holds for value of at least one suit.
+    /// ```
+    /// use karty::suits::SuitMap;
+    /// let lengths = SuitMap::new(5, 3, 3, 2);
+    /// assert!(lengths.or(|l| *l >= 5));
+    /// assert!(!lengths.or(|l| *l == 0));
+    /// ```
+    pub fn or<F: Fn(&T) -> bool>(&self, f: F) -> bool{
+        f(&self.spades) || f(&self.hearts) || f(&self.diamonds) || f(&self.clubs)
+    }
+
+    /// Returns suit with the greatest value with respect to comparing function.
+    /// On ties suit earlier in iteration order wins, so privileged suit is preferred.
+    /// ```
+    /// use karty::suits::{Suit, SuitMap};
+    /// let lengths = SuitMap::new(4, 4, 3, 2);
+    /// assert_eq!(lengths.max_by(|a, b| a.cmp(b)), (Suit::Hearts, &4));
+    /// assert_eq!(lengths.with_privilege(Suit::Spades).max_by(|a, b| a.cmp(b)), (Suit::Spades, &4));
+    /// ```
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> (Suit, &T){
+        self.iter().reduce(|best, challenger| match compare(challenger.1, best.1){
+            Ordering::Greater => challenger,
+            _ => best
+        }).expect("Suit map is never empty (should not happen, it is a bug).")
+    }
 }
 
 impl<T: Add<Output = T> + Copy> SuitMap<T>{
@@ -94,6 +231,25 @@ impl <T:Default> SuitMap<T>{
     }
 }
 
+/// Collects pairs of suit and value, suits missing in iterator get default value
+/// (when suit is repeated, the last value is kept).
+/// ```
+/// use karty::suits::{Suit, SuitMap};
+/// let map: SuitMap<u8> = [(Suit::Hearts, 3), (Suit::Clubs, 1)].into_iter().collect();
+/// assert_eq!(map, SuitMap::new(0, 3, 0, 1));
+/// let back: SuitMap<u8> = map.iter().map(|(s, v)| (s, *v)).collect();
+/// assert_eq!(back, map);
+/// ```
+impl<T: Default> FromIterator<(Suit, T)> for SuitMap<T>{
+    fn from_iter<I: IntoIterator<Item=(Suit, T)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (suit, value) in iter{
+            map[suit] = value;
+        }
+        map
+    }
+}
+
 
 /*
 impl<I, const C: usize> SuitMap<SmallVec<[I; C]>>{
@@ -238,11 +394,43 @@ impl<T: IntoIterator> IntoIterator for SuitMap<T>{
     type Item = <T as IntoIterator>::Item;
     type IntoIter = SuitMapIterator<T>;
 
+    /// Flattens values in iteration order (see [`suits`](SuitMap::suits)).
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::suits::{Suit, SuitMap};
+    /// let map = SuitMap::new(vec![ACE_SPADES], vec![ACE_HEARTS], vec![], vec![TWO_CLUBS]).with_privilege(Suit::Hearts);
+    /// assert_eq!(map.into_iter().collect::<Vec<Card>>(), vec![ACE_HEARTS, TWO_CLUBS, ACE_SPADES]);
+    /// ```
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter::new(self.clubs.into_iter()
-            .chain(self.diamonds.into_iter()
-                .chain(self.hearts.into_iter()
-                    .chain(self.spades))))
+        let [first, second, third, fourth] = self.suits();
+        let mut values = [Some(self.clubs), Some(self.diamonds), Some(self.hearts), Some(self.spades)];
+        let mut take = |suit: Suit| values[suit.usize_index()].take()
+            .expect("Every suit is taken once (should not happen, it is a bug).").into_iter();
+        Self::IntoIter::new(take(first)
+            .chain(take(second)
+                .chain(take(third)
+                    .chain(take(fourth)))))
 
     }
-}
\ No newline at end of file
+}
+#[cfg(all(test, any(feature = "speedy", feature = "serde")))]
+mod tests{
+    use crate::suits::{Suit, SuitMap};
+
+    #[test]
+    #[cfg(feature = "speedy")]
+    fn speedy_suit_map(){
+        use speedy::{Readable, Writable};
+        let map = SuitMap::new(5u8, 3, 3, 2).with_privilege(Suit::Hearts);
+        let serialized = map.write_to_vec().unwrap();
+        assert_eq!(SuitMap::<u8>::read_from_buffer(&serialized).unwrap(), map);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_suit_map(){
+        let map = SuitMap::new(5u8, 3, 3, 2).with_privilege(Suit::Hearts);
+        let serialized = ron::to_string(&map).unwrap();
+        assert_eq!(ron::from_str::<SuitMap<u8>>(&serialized).unwrap(), map);
+    }
+}
@@ -4,12 +4,12 @@
 //! # Authors:
 //! [morovintaas](mailto:moroviintaas@gmail.com)
 //!
-use std::fmt::Debug;
-use std::hash::Hash;
+use core::fmt::Debug;
+use core::hash::Hash;
 use crate::symbol::CardSymbol;
 
-/// Trait that is combination of [`CardSymbol`][crate::symbol::CardSymbol], [`Debug`][std::fmt::Debug]
-/// [`Ord`][std::cmp::Ord], [`Clone`][std::clone::Clone] and [`Hash`][std::hash::Hash].
+/// Trait that is combination of [`CardSymbol`][crate::symbol::CardSymbol], [`Debug`][core::fmt::Debug]
+/// [`Ord`][core::cmp::Ord], [`Clone`][core::clone::Clone] and [`Hash`][core::hash::Hash].
 /// At the moment trait is only semantic sugar. It remains after early crate development,
 /// now it is advised to use more generic super trait [`CardSymbol`][crate::symbol::CardSymbol].
 /// Trait may be marked deprecated in future and marked to remove.
@@ -17,7 +17,7 @@ use crate::symbol::CardSymbol;
 /// ```
 /// use karty::symbol::CardSymbol;
 /// use karty::error::CardError;
-/// use std::cmp::Ordering;
+/// use core::cmp::Ordering;
 /// use karty::suits::SuitTrait;
 /// // if enabled feature "random" you can derive RandomSymbol for structs implementing CardSymbol
 /// #[derive(Eq, PartialEq, Clone, Hash, Debug)]
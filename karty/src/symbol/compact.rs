@@ -0,0 +1,129 @@
+//! Module with compact string serde representation of [`Suit`], [`Figure`] and standard [`Card`]:
+//! figure and suit letters, e.g. `"K"`, `"H"` and `"KH"`. Deserialization accepts every format
+//! parsed by [`parse_suit`], [`parse_figure`] and [`parse_card`] as long as whole string is consumed.
+//! Representation can be selected with module (for `#[serde(with = "karty::symbol::compact")]`)
+//! or with newtype wrapper [`Compact`].
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use karty::cards::{Card, KING_HEARTS};
+//! use karty::suits::Suit;
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Lead{
+//!     #[serde(with = "karty::symbol::compact")]
+//!     card: Card,
+//!     #[serde(with = "karty::symbol::compact")]
+//!     trump: Suit,
+//! }
+//! let lead = Lead{card: KING_HEARTS, trump: Suit::Spades};
+//! assert_eq!(ron::to_string(&lead).unwrap(), "(card:\"KH\",trump:\"S\")");
+//! assert_eq!(ron::from_str::<Lead>("(card:\"K♥\",trump:\"spades\")").unwrap(), lead);
+//! ```
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use nom::combinator::all_consuming;
+use nom::{Finish, IResult, Parser};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use crate::cards::{Card, Card2SymTrait};
+use crate::cards::parse::parse_card;
+use crate::error::CardError;
+use crate::figures::{parse_figure, Figure};
+use crate::suits::parse::parse_suit;
+use crate::suits::Suit;
+
+/// Symbol with compact string form.
+pub trait CompactSymbol: Sized{
+    /// Compact string form, e.g. `KH` for king of hearts.
+    fn to_compact_string(&self) -> String;
+    /// Parses symbol, whole string must be consumed.
+    fn from_compact_str(s: &str) -> Result<Self, CardError>;
+}
+
+fn parse_whole<'a, T>(s: &'a str, parser: impl Fn(&'a str) -> IResult<&'a str, T>) -> Result<T, CardError>{
+    all_consuming(parser).parse(s).finish()
+        .map(|(_, symbol)| symbol)
+        .map_err(|_| CardError::ParseError)
+}
+
+impl CompactSymbol for Suit{
+    fn to_compact_string(&self) -> String {
+        self.repr_char().to_string()
+    }
+
+    fn from_compact_str(s: &str) -> Result<Self, CardError> {
+        parse_whole(s, parse_suit)
+    }
+}
+
+impl CompactSymbol for Figure{
+    fn to_compact_string(&self) -> String {
+        self.repr_char().to_string()
+    }
+
+    fn from_compact_str(s: &str) -> Result<Self, CardError> {
+        parse_whole(s, parse_figure)
+    }
+}
+
+impl CompactSymbol for Card{
+    fn to_compact_string(&self) -> String {
+        format!("{}{}", self.figure().repr_char(), self.suit().repr_char())
+    }
+
+    fn from_compact_str(s: &str) -> Result<Self, CardError> {
+        parse_whole(s, parse_card)
+    }
+}
+
+pub fn serialize<T: CompactSymbol, S: Serializer>(symbol: &T, serializer: S) -> Result<S::Ok, S::Error>{
+    serializer.serialize_str(&symbol.to_compact_string())
+}
+
+pub fn deserialize<'de, T: CompactSymbol, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>{
+    let s = String::deserialize(deserializer)?;
+    T::from_compact_str(&s).map_err(|e| D::Error::custom(format!("{e}: {s:?}")))
+}
+
+/// Symbol (de)serialized in compact string form.
+/// ```
+/// use karty::cards::TEN_CLUBS;
+/// use karty::symbol::compact::Compact;
+/// assert_eq!(ron::to_string(&Compact(TEN_CLUBS)).unwrap(), "\"TC\"");
+/// assert_eq!(ron::from_str::<Compact<_>>("\"10c\"").unwrap(), Compact(TEN_CLUBS));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent, bound = "T: CompactSymbol")]
+pub struct Compact<T>(#[serde(with = "self")] pub T);
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::{Card, STANDARD_DECK};
+    use crate::figures::FIGURES;
+    use crate::suits::SUITS;
+    use crate::symbol::compact::{Compact, CompactSymbol};
+
+    #[test]
+    fn compact_round_trip(){
+        for card in STANDARD_DECK{
+            let serialized = ron::to_string(&Compact(card)).unwrap();
+            assert_eq!(serialized.len(), 4);
+            assert_eq!(ron::from_str::<Compact<Card>>(&serialized).unwrap(), Compact(card));
+        }
+        for figure in FIGURES{
+            assert_eq!(ron::from_str::<Compact<_>>(&ron::to_string(&Compact(figure)).unwrap()).unwrap(), Compact(figure));
+        }
+        for suit in SUITS{
+            assert_eq!(ron::from_str::<Compact<_>>(&ron::to_string(&Compact(suit)).unwrap()).unwrap(), Compact(suit));
+        }
+    }
+
+    #[test]
+    fn compact_rejects_trailing_input(){
+        assert!(Card::from_compact_str("KHx").is_err());
+        assert!(ron::from_str::<Compact<Card>>("\"KH \"").is_err());
+        assert!(ron::from_str::<Compact<Card>>("7").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 use crate::symbol::CardSymbol;
 
 /// Iterator over CardSymbol space, starts with card associated with number `0` and ends on card
@@ -7,7 +7,7 @@ use crate::symbol::CardSymbol;
 /// ```
 /// use karty::suits::{Suit, Suit::*};
 /// use karty::symbol::CardSymbol;
-/// use std::iter::FromIterator;
+/// use core::iter::FromIterator;
 ///
 /// let iterator = Suit::iterator();
 /// let symbols = Vec::from_iter(iterator);
@@ -64,8 +64,8 @@ impl<E: CardSymbol> Iterator for CardSymbolIterator<E>{
             return None;
         }
         match self.iterator_position_low.cmp(&self.iterator_position_high){
-            std::cmp::Ordering::Greater => None,
-            std::cmp::Ordering::Equal =>  match self.stop{
+            core::cmp::Ordering::Greater => None,
+            core::cmp::Ordering::Equal =>  match self.stop{
                
                 true => None,
                 false => {
@@ -79,7 +79,7 @@ impl<E: CardSymbol> Iterator for CardSymbolIterator<E>{
                 
             }
                 ,
-            std::cmp::Ordering::Less => {
+            core::cmp::Ordering::Less => {
                 let element = E::from_usize_index(self.iterator_position_low).ok();
                 self.iterator_position_low = self.iterator_position_low.saturating_add(1);
                 element
@@ -117,8 +117,8 @@ impl <E: CardSymbol> DoubleEndedIterator for CardSymbolIterator<E>{
             return None;
         }
         match self.iterator_position_high.cmp(&self.iterator_position_low){
-            std::cmp::Ordering::Less => None,
-            std::cmp::Ordering::Equal =>  match self.stop{
+            core::cmp::Ordering::Less => None,
+            core::cmp::Ordering::Equal =>  match self.stop{
                
                 true => None,
                 false => {
@@ -132,7 +132,7 @@ impl <E: CardSymbol> DoubleEndedIterator for CardSymbolIterator<E>{
                 
             }
                 ,
-            std::cmp::Ordering::Greater => {
+            core::cmp::Ordering::Greater => {
                 let element = E::from_usize_index(self.iterator_position_high).ok();
                 self.iterator_position_high = self.iterator_position_high.saturating_sub(1);
                 element
@@ -1,17 +1,20 @@
 mod iterator;
 mod r#trait;
-//mod symbol_map;
+mod symbol_map;
 mod symbol_comparator;
+#[cfg(feature = "serde")]
+pub mod compact;
 
 
 pub use iterator::*;
 pub use r#trait::*;
-//pub use symbol_map::*;
+pub use symbol_map::*;
 pub use symbol_comparator::*;
 
 
 #[cfg(test)]
 mod tests{
+    use std::vec::Vec;
     use crate::symbol::CardSymbolIterator;
     use crate::figures::{*};
     use crate::suits::Suit;
@@ -1,5 +1,5 @@
-use std::cmp::Ordering;
-use std::marker::PhantomData;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
 use comparator::Comparator;
 use crate::symbol::CardSymbol;
 
@@ -1,20 +1,234 @@
-use std::ops::Index;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
 use crate::symbol::CardSymbol;
 
-#[derive(Clone, Debug)]
-pub struct SymbolMap<T, const SIZE: usize > where {
-    pub associated: [T;SIZE]
+/// Fixed-size map from symbols of type `S` ([`CardSymbol`]: suits, figures, cards or custom symbols) to values,
+/// backed by array indexed with [`CardSymbol::usize_index`]. `SIZE` must be [`CardSymbol::SYMBOL_SPACE`]
+/// of `S`, constructors panic otherwise.
+/// ```
+/// use karty::cards::*;
+/// use karty::suits::Suit;
+/// use karty::symbol::SymbolMap;
+/// let mut west = SymbolMap::<Card, f32, 52>::default();
+/// west[ACE_SPADES] = 0.5;
+/// west[KING_HEARTS] += 0.25;
+/// assert_eq!(west[ACE_SPADES], 0.5);
+/// assert_eq!(west.iter().filter(|(_, p)| **p > 0.0).count(), 2);
+/// let suit_lengths = SymbolMap::<Suit, u8, 4>::from_fn(|s| s as u8);
+/// assert_eq!(suit_lengths[Suit::Clubs], 3);
+/// ```
+/// Map can be indexed only with its own symbol type:
+/// ```compile_fail
+/// use karty::cards::Card;
+/// use karty::suits::Suit;
+/// use karty::symbol::SymbolMap;
+/// let map = SymbolMap::<Card, f32, 52>::default();
+/// let _ = map[Suit::Spades];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SymbolMap<S, T, const SIZE: usize>{
+    associated: [T; SIZE],
+    _symbol: PhantomData<S>
 }
-/*
-pub struct SymbolMap<T: CardSymbol>{
-    associated: [T; T::SYMBOL_SPACE]
-}*/
 
-impl<T, const SIZE: usize, S:CardSymbol > Index<S> for SymbolMap<T, SIZE>
-where {
+impl<S: CardSymbol, T, const SIZE: usize> SymbolMap<S, T, SIZE>{
+    /// Creates map from values in [`CardSymbol::usize_index`] order.
+    /// # Panics:
+    /// When `SIZE` differs from [`CardSymbol::SYMBOL_SPACE`] of symbol type.
+    pub fn new(associated: [T; SIZE]) -> Self{
+        assert_eq!(S::SYMBOL_SPACE, SIZE, "Symbol space must be equal to size of map");
+        Self{associated, _symbol: PhantomData}
+    }
+
+    /// Creates map with values returned by `f` for every symbol, in [`CardSymbol::usize_index`] order.
+    /// # Panics:
+    /// When `SIZE` differs from [`CardSymbol::SYMBOL_SPACE`] of symbol type.
+    pub fn from_fn<F: FnMut(S) -> T>(mut f: F) -> Self{
+        Self::new(core::array::from_fn(|i| f(Self::symbol(i))))
+    }
+
+    fn symbol(index: usize) -> S{
+        S::from_usize_index(index).expect("Index lesser than symbol space (should not happen, it is a bug).")
+    }
+
+    pub fn values(&self) -> &[T; SIZE]{
+        &self.associated
+    }
+
+    pub fn into_values(self) -> [T; SIZE]{
+        self.associated
+    }
+
+    /// Map with function applied to every value.
+    /// ```
+    /// use karty::figures::Figure;
+    /// use karty::symbol::{CardSymbol, SymbolMap};
+    /// let map = SymbolMap::<Figure, usize, 13>::from_fn(|f| f.usize_index()).map(|i| i * 2);
+    /// assert_eq!(map[Figure::Ace], 24);
+    /// ```
+    pub fn map<R, F: FnMut(&T) -> R>(&self, mut f: F) -> SymbolMap<S, R, SIZE>{
+        SymbolMap{associated: core::array::from_fn(|i| f(&self.associated[i])), _symbol: PhantomData}
+    }
+
+    /// Iterates over pairs of symbol and value in [`CardSymbol::usize_index`] order.
+    pub fn iter(&self) -> impl Iterator<Item = (S, &T)>{
+        self.associated.iter().enumerate().map(|(i, v)| (Self::symbol(i), v))
+    }
+
+    /// Iterates over pairs of symbol and mutable value in [`CardSymbol::usize_index`] order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (S, &mut T)>{
+        self.associated.iter_mut().enumerate().map(|(i, v)| (Self::symbol(i), v))
+    }
+}
+
+/// # Panics:
+/// When `SIZE` differs from [`CardSymbol::SYMBOL_SPACE`] of symbol type.
+impl<S: CardSymbol, T: Default, const SIZE: usize> Default for SymbolMap<S, T, SIZE>{
+    fn default() -> Self {
+        Self::new(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<S: CardSymbol, T, const SIZE: usize> Index<S> for SymbolMap<S, T, SIZE>{
     type Output = T;
 
     fn index(&self, index: S) -> &Self::Output {
-        &self.associated[index.position()]
+        &self.associated[index.usize_index()]
+    }
+}
+
+impl<S: CardSymbol, T, const SIZE: usize> IndexMut<S> for SymbolMap<S, T, SIZE>{
+    fn index_mut(&mut self, index: S) -> &mut Self::Output {
+        &mut self.associated[index.usize_index()]
+    }
+}
+
+#[cfg(feature = "speedy")]
+mod speedy{
+    use speedy::{Context, Readable, Reader, Writable, Writer};
+    use crate::symbol::{CardSymbol, SymbolMap};
+
+    impl<C: Context, S: CardSymbol, T: Writable<C>, const SIZE: usize> Writable<C> for SymbolMap<S, T, SIZE>{
+        fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+            for v in self.values(){
+                v.write_to(writer)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<'a, C: Context, S: CardSymbol, T: Readable<'a, C>, const SIZE: usize> Readable<'a, C> for SymbolMap<S, T, SIZE>{
+        fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+            let mut values = Vec::with_capacity(SIZE);
+            for _ in 0..SIZE{
+                values.push(T::read_from(reader)?);
+            }
+            Ok(SymbolMap::new(values.try_into().ok().expect("Exactly SIZE values were read (should not happen, it is a bug).")))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests{
+        use speedy::{Readable, Writable};
+        use crate::cards::{Card, ACE_SPADES};
+        use crate::symbol::SymbolMap;
+
+        #[test]
+        fn symbol_map_speedy(){
+            let mut map = SymbolMap::<Card, f32, 52>::default();
+            map[ACE_SPADES] = 0.75;
+            let serialized = map.write_to_vec().unwrap();
+            let deserialized = SymbolMap::<Card, f32, 52>::read_from_buffer(&serialized).unwrap();
+            assert_eq!(deserialized, map);
+            assert_eq!(deserialized.iter().last(), Some((ACE_SPADES, &0.75)));
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde{
+    use core::fmt::Formatter;
+    use core::marker::PhantomData;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::ser::SerializeSeq;
+    use crate::symbol::{CardSymbol, SymbolMap};
+
+    impl<S: CardSymbol, T: Serialize, const SIZE: usize> Serialize for SymbolMap<S, T, SIZE>{
+        fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> where Ser: Serializer {
+            let mut seq = serializer.serialize_seq(Some(SIZE))?;
+            for v in self.values(){
+                seq.serialize_element(v)?;
+            }
+            seq.end()
+        }
+    }
+
+    impl<'de, S: CardSymbol, T: Deserialize<'de>, const SIZE: usize> Deserialize<'de> for SymbolMap<S, T, SIZE>{
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+            struct SymbolMapVisitor<S, T, const SIZE: usize>(PhantomData<(S, T)>);
+
+            impl<'de, S: CardSymbol, T: Deserialize<'de>, const SIZE: usize> Visitor<'de> for SymbolMapVisitor<S, T, SIZE>{
+                type Value = SymbolMap<S, T, SIZE>;
+
+                fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+                    write!(formatter, "sequence of {SIZE} values")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: SeqAccess<'de> {
+                    let mut values = Vec::with_capacity(SIZE);
+                    while let Some(v) = seq.next_element()?{
+                        values.push(v);
+                    }
+                    let found = values.len();
+                    values.try_into()
+                        .map(SymbolMap::new)
+                        .map_err(|_| A::Error::invalid_length(found, &self))
+                }
+            }
+
+            deserializer.deserialize_seq(SymbolMapVisitor::<S, T, SIZE>(PhantomData))
+        }
     }
-}
\ No newline at end of file
+
+    #[cfg(test)]
+    mod tests{
+        use crate::suits::Suit;
+        use crate::symbol::SymbolMap;
+
+        #[test]
+        fn symbol_map_ron(){
+            let map = SymbolMap::<Suit, u8, 4>::from_fn(|s| s as u8);
+            let serialized = ron::to_string(&map).unwrap();
+            assert_eq!(serialized, "[3,2,1,0]");
+            assert_eq!(ron::from_str::<SymbolMap<Suit, u8, 4>>(&serialized).unwrap(), map);
+            assert!(ron::from_str::<SymbolMap<Suit, u8, 4>>("[3,2,1]").is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::{Card, ACE_SPADES, TWO_CLUBS};
+    use crate::suits::Suit;
+    use crate::symbol::SymbolMap;
+
+    #[test]
+    fn card_map(){
+        let mut map = SymbolMap::<Card, usize, 52>::from_fn(|c| c.usize_index());
+        assert_eq!(map[ACE_SPADES], 51);
+        for (card, v) in map.iter_mut(){
+            if card == TWO_CLUBS{
+                *v = 100;
+            }
+        }
+        assert_eq!(map.iter().next(), Some((TWO_CLUBS, &100)));
+        assert_eq!(map.iter().map(|(_, v)| *v).sum::<usize>(), 100 + (1..52).sum::<usize>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn size_must_match_symbol_space(){
+        SymbolMap::<Suit, f32, 52>::default();
+    }
+}
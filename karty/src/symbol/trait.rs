@@ -5,7 +5,7 @@
 //! [morovintaas](mailto:moroviintaas@gmail.com)
 //!
 use crate::error::CardError;
-use std::fmt::Debug;
+use core::fmt::Debug;
 use crate::symbol::CardSymbolIterator;
 
 /// Trait representing a symbol on a playing card.
@@ -16,7 +16,7 @@ use crate::symbol::CardSymbolIterator;
 /// with no associated [`CardSymbol`][crate::symbol::CardSymbol] instance.
 /// For example, implemented in this crate `FigureStd` has `13` possible instances,
 /// representing symbols (2,..., 10, Jack, Queen, King, Ace), but their associated numbers are `0..=12`.
-pub trait CardSymbol: Sized + Eq +  std::hash::Hash  + Clone + Debug{
+pub trait CardSymbol: Sized + Eq +  core::hash::Hash  + Clone + Debug{
     /// Space of symbol, holds information how many possible instances of symbol exists.
     /// For standard figure it is `13`, for ls
     /// standard suit it is `4`.
@@ -92,4 +92,37 @@ pub trait CardSymbol: Sized + Eq +  std::hash::Hash  + Clone + Debug{
         }
         //Self::from_position(self.position().checked_sub(n)).ok()
     }
-}
\ No newline at end of file
+}
+#[cfg(all(test, feature = "proptest"))]
+mod proptests{
+    use proptest::prelude::*;
+    use crate::cards::Card;
+    use crate::figures::Figure;
+    use crate::suits::Suit;
+    use crate::symbol::CardSymbol;
+    use crate::tarot::TarotCard;
+    use crate::testing::symbol;
+
+    fn check_index_round_trip<S: CardSymbol>(s: S) -> Result<(), TestCaseError>{
+        prop_assert!(s.usize_index() < S::SYMBOL_SPACE);
+        prop_assert_eq!(S::from_usize_index(s.usize_index()).ok(), Some(s));
+        Ok(())
+    }
+
+    proptest!{
+        #[test]
+        fn index_round_trip(suit in symbol::<Suit>(), figure in symbol::<Figure>(),
+                            card in symbol::<Card>(), tarot in symbol::<TarotCard>()){
+            check_index_round_trip(suit)?;
+            check_index_round_trip(figure)?;
+            check_index_round_trip(card)?;
+            check_index_round_trip(tarot)?;
+        }
+
+        #[test]
+        fn index_out_of_space(index in 52usize..1000){
+            prop_assert!(Card::from_usize_index(index).is_err());
+            prop_assert!(Suit::from_usize_index(index).is_err());
+        }
+    }
+}
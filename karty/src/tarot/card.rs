@@ -0,0 +1,277 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use crate::error::CardError;
+use crate::suits::Suit;
+use crate::symbol::CardSymbol;
+use crate::tarot::TarotRank;
+
+/// Number of trumps in tarot deck
+pub const TAROT_TRUMPS: u8 = 21;
+/// Number of suit cards in tarot deck
+const SUITED_CARDS: usize = 56;
+
+/// Card of 78-card tarot deck: 56 suit cards, 21 trumps and the Excuse (the Fool).
+/// Cards are indexed (see [`CardSymbol`]) first by suit cards (`suit * 14 + rank`), then trumps `1..=21`
+/// at `56..=76` and the Excuse at `77`. Ordering follows the index.
+/// ```
+/// use karty::tarot::{TarotCard, TarotRank};
+/// use karty::suits::Suit;
+/// use karty::symbol::CardSymbol;
+/// assert_eq!(TarotCard::SYMBOL_SPACE, 78);
+/// assert_eq!(TarotCard::Suited(TarotRank::King, Suit::Spades).usize_index(), 55);
+/// assert_eq!(TarotCard::Trump(1).usize_index(), 56);
+/// assert_eq!(TarotCard::Excuse.usize_index(), 77);
+/// assert!(TarotCard::Trump(1) > TarotCard::Suited(TarotRank::King, Suit::Spades));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawTarotCard"))]
+pub enum TarotCard{
+    Suited(TarotRank, Suit),
+    /// Trump with number `1..=21`, other numbers are invalid ([`CardSymbol::usize_index`] panics on them
+    /// and deserialization rejects them)
+    Trump(u8),
+    /// The Excuse (the Fool)
+    Excuse
+}
+
+/// Layout of [`TarotCard`] as read by deserializers, before trump number is checked.
+#[cfg(any(feature = "serde", feature = "speedy"))]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "TarotCard"))]
+enum RawTarotCard{
+    Suited(TarotRank, Suit),
+    Trump(u8),
+    Excuse
+}
+
+#[cfg(any(feature = "serde", feature = "speedy"))]
+impl TryFrom<RawTarotCard> for TarotCard{
+    type Error = CardError;
+
+    fn try_from(raw: RawTarotCard) -> Result<Self, Self::Error> {
+        match raw{
+            RawTarotCard::Suited(rank, suit) => Ok(Self::Suited(rank, suit)),
+            RawTarotCard::Trump(legit @ 1..=TAROT_TRUMPS) => Ok(Self::Trump(legit)),
+            RawTarotCard::Trump(n) => Err(CardError::InvalidTrumpNumber(n)),
+            RawTarotCard::Excuse => Ok(Self::Excuse)
+        }
+    }
+}
+
+/// Reads card with the same trump number check as serde deserialization.
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for TarotCard{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        RawTarotCard::read_from(reader)?.try_into().map_err(|e| speedy::Error::custom(e).into())
+    }
+}
+
+impl TarotCard{
+    /// Creates trump.
+    /// # Panics:
+    /// When number is not in `1..=21`.
+    pub fn trump(number: u8) -> Self{
+        match number{
+            legit @ 1..=TAROT_TRUMPS => Self::Trump(legit),
+            e => panic!("Invalid tarot trump number {e:?}")
+        }
+    }
+
+    pub fn suit(&self) -> Option<Suit>{
+        match self{
+            Self::Suited(_, suit) => Some(*suit),
+            _ => None
+        }
+    }
+
+    pub fn is_trump(&self) -> bool{
+        matches!(self, Self::Trump(_))
+    }
+
+    /// Checks if card is one of oudlers (bouts): trump 1 (petit), trump 21 and the Excuse.
+    /// ```
+    /// use karty::tarot::TarotCard;
+    /// assert!(TarotCard::Trump(21).is_oudler());
+    /// assert!(TarotCard::Excuse.is_oudler());
+    /// assert!(!TarotCard::Trump(20).is_oudler());
+    /// ```
+    pub fn is_oudler(&self) -> bool{
+        matches!(self, Self::Trump(1) | Self::Trump(TAROT_TRUMPS) | Self::Excuse)
+    }
+
+    /// Card value in half-points: oudlers and kings 9, queens 7, knights 5, jacks 3, other cards 1.
+    /// ```
+    /// use karty::tarot::{TarotCard, TarotRank};
+    /// use karty::suits::Suit;
+    /// assert_eq!(TarotCard::Excuse.half_points(), 9);
+    /// assert_eq!(TarotCard::Suited(TarotRank::Knight, Suit::Hearts).half_points(), 5);
+    /// assert_eq!(TarotCard::Trump(7).half_points(), 1);
+    /// ```
+    pub fn half_points(&self) -> u32{
+        match self{
+            c if c.is_oudler() => 9,
+            Self::Suited(TarotRank::King, _) => 9,
+            Self::Suited(TarotRank::Queen, _) => 7,
+            Self::Suited(TarotRank::Knight, _) => 5,
+            Self::Suited(TarotRank::Jack, _) => 3,
+            _ => 1
+        }
+    }
+
+    /// Card value in points (may be half-point).
+    pub fn points(&self) -> f32{
+        self.half_points() as f32 / 2.0
+    }
+
+    /// Compares cards played to a trick, where `led` is suit of the first suit card led (if any):
+    /// trumps beat other cards, suit cards in led suit beat other suit cards, the Excuse never wins.
+    pub fn cmp_in_trick(&self, other: &Self, led: Option<Suit>) -> Ordering{
+        let rank = |card: &Self| match card{
+            Self::Excuse => (0, 0),
+            Self::Trump(n) => (3, *n as usize),
+            Self::Suited(r, s) if Some(*s) == led => (2, r.usize_index()),
+            Self::Suited(r, _) => (1, r.usize_index()),
+        };
+        rank(self).cmp(&rank(other))
+    }
+}
+
+/// Returns index of card winning the trick (cards in order of play).
+/// ```
+/// use karty::tarot::{trick_winner, TarotCard, TarotRank};
+/// use karty::suits::Suit;
+/// let trick = [TarotCard::Excuse, TarotCard::Suited(TarotRank::number(3), Suit::Hearts),
+///     TarotCard::Suited(TarotRank::King, Suit::Spades), TarotCard::Suited(TarotRank::Jack, Suit::Hearts)];
+/// assert_eq!(trick_winner(&trick), Some(3));
+/// let trick = [TarotCard::Suited(TarotRank::King, Suit::Spades), TarotCard::Trump(2)];
+/// assert_eq!(trick_winner(&trick), Some(1));
+/// ```
+pub fn trick_winner(cards: &[TarotCard]) -> Option<usize>{
+    let led = cards.iter().find(|c| **c != TarotCard::Excuse).and_then(|c| c.suit());
+    cards.iter().enumerate().reduce(|best, challenger| {
+        match challenger.1.cmp_in_trick(best.1, led){
+            Ordering::Greater => challenger,
+            _ => best
+        }
+    }).map(|(i, _)| i)
+}
+
+/// Points required by taker depending on number of oudlers in their tricks: 56, 51, 41, 36.
+pub fn required_points(oudlers: usize) -> f32{
+    match oudlers{
+        0 => 56.0,
+        1 => 51.0,
+        2 => 41.0,
+        _ => 36.0
+    }
+}
+
+impl CardSymbol for TarotCard{
+    const SYMBOL_SPACE: usize = SUITED_CARDS + TAROT_TRUMPS as usize + 1;
+
+    fn usize_index(&self) -> usize {
+        match self{
+            Self::Suited(rank, suit) => suit.usize_index() * TarotRank::SYMBOL_SPACE + rank.usize_index(),
+            Self::Trump(n @ 1..=TAROT_TRUMPS) => SUITED_CARDS + *n as usize - 1,
+            Self::Trump(n) => panic!("Invalid tarot trump number {n:?}"),
+            Self::Excuse => Self::SYMBOL_SPACE - 1
+        }
+    }
+
+    fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        match position{
+            p if p < SUITED_CARDS => Ok(Self::Suited(
+                TarotRank::from_usize_index(p % TarotRank::SYMBOL_SPACE)?,
+                Suit::from_usize_index(p / TarotRank::SYMBOL_SPACE)?)),
+            p if p < Self::SYMBOL_SPACE - 1 => Ok(Self::Trump((p - SUITED_CARDS + 1) as u8)),
+            p if p == Self::SYMBOL_SPACE - 1 => Ok(Self::Excuse),
+            p => Err(CardError::WrongPosition(p))
+        }
+    }
+}
+
+impl PartialOrd for TarotCard{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TarotCard{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.usize_index().cmp(&other.usize_index())
+    }
+}
+
+/// Implements [`Display`]: alternate form is compact (`K♠`, `T21`, `EX`).
+/// ```
+/// use karty::tarot::{TarotCard, TarotRank};
+/// use karty::suits::Suit;
+/// assert_eq!(format!("{}", TarotCard::Suited(TarotRank::Knight, Suit::Hearts)), "Knight of Hearts");
+/// assert_eq!(format!("{:#}", TarotCard::Suited(TarotRank::Knight, Suit::Hearts)), "C♥");
+/// assert_eq!(format!("{}", TarotCard::Trump(21)), "Trump 21");
+/// assert_eq!(format!("{:#}", TarotCard::Trump(21)), "T21");
+/// assert_eq!(format!("{:#}", TarotCard::Excuse), "EX");
+/// ```
+impl Display for TarotCard{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (f.alternate(), self){
+            (true, Self::Suited(rank, suit)) => write!(f, "{rank:#}{suit:#}"),
+            (false, Self::Suited(rank, suit)) => write!(f, "{rank} of {suit}"),
+            (true, Self::Trump(n)) => write!(f, "T{n}"),
+            (false, Self::Trump(n)) => write!(f, "Trump {n}"),
+            (true, Self::Excuse) => write!(f, "EX"),
+            (false, Self::Excuse) => write!(f, "Excuse"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::vec::Vec;
+    use crate::symbol::{CardSymbol, CardSymbolIterator};
+    use crate::tarot::TarotCard;
+
+    #[test]
+    fn index_round_trip_and_points(){
+        let deck: Vec<TarotCard> = CardSymbolIterator::new().collect();
+        assert_eq!(deck.len(), 78);
+        for (i, card) in deck.iter().enumerate(){
+            assert_eq!(card.usize_index(), i);
+        }
+        assert_eq!(deck.iter().map(|c| c.half_points()).sum::<u32>(), 182);
+        assert_eq!(deck.iter().filter(|c| c.is_oudler()).count(), 3);
+        assert!(TarotCard::from_usize_index(78).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_trump_has_no_index(){
+        TarotCard::Trump(0).usize_index();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_trump(){
+        use crate::tarot::TarotCardSet;
+        assert_eq!(ron::from_str::<TarotCard>("Trump(21)").unwrap(), TarotCard::Trump(21));
+        assert!(ron::from_str::<TarotCard>("Trump(0)").is_err());
+        assert!(ron::from_str::<TarotCard>("Trump(22)").is_err());
+        assert!(ron::from_str::<TarotCardSet>("[Trump(0)]").is_err());
+        assert!(ron::from_str::<TarotCardSet>("[Trump(255)]").is_err());
+    }
+
+    #[cfg(feature = "speedy")]
+    #[test]
+    fn speedy_rejects_invalid_trump(){
+        use speedy::{Readable, Writable};
+        let excuse = TarotCard::Excuse.write_to_vec().unwrap();
+        assert_eq!(TarotCard::read_from_buffer(&excuse).unwrap(), TarotCard::Excuse);
+        let trump = TarotCard::Trump(7).write_to_vec().unwrap();
+        assert_eq!(TarotCard::read_from_buffer(&trump).unwrap(), TarotCard::Trump(7));
+        let invalid = TarotCard::Trump(0).write_to_vec().unwrap();
+        assert!(TarotCard::read_from_buffer(&invalid).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Module with 78-card tarot deck: four suits of 14 cards, 21 trumps and the Excuse (the Fool).
+//! Provides card symbols, ordering, point counting (in half-points) and bitset card set.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+mod rank;
+mod card;
+mod set;
+
+pub use rank::*;
+pub use card::*;
+pub use set::*;
+
+#[cfg(feature = "parse")]
+pub mod parse;
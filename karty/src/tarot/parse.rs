@@ -0,0 +1,111 @@
+//! Module containing parsing functions for [`TarotCard`].
+//! To parse cards crate [`nom`] is used.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+//! # Enable:
+//! Use feature `parse`.
+//!
+use std::str::FromStr;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{space0, u8 as parse_u8};
+use nom::combinator::{map, map_opt};
+use nom::sequence::{preceded, separated_pair};
+use nom::{IResult, Parser};
+use crate::error::CardError;
+use crate::suits::parse::parse_suit;
+use crate::tarot::{MAX_TAROT_NUMBER, MIN_TAROT_NUMBER, TarotCard, TarotRank, TAROT_TRUMPS};
+
+fn parse_excuse(s: &str) -> IResult<&str, TarotCard>{
+    alt((tag_no_case("excuse"), tag_no_case("fool"), tag_no_case("ex"), tag_no_case("*"))).parse(s)
+        .map(|(i, _)| (i, TarotCard::Excuse))
+}
+
+fn parse_trump(s: &str) -> IResult<&str, TarotCard>{
+    map_opt(
+        preceded((alt((tag_no_case("trump"), tag_no_case("t"))), space0), parse_u8),
+        |n| match n{
+            legit @ 1..=TAROT_TRUMPS => Some(TarotCard::Trump(legit)),
+            _ => None
+        }).parse(s)
+}
+
+fn parse_rank(s: &str) -> IResult<&str, TarotRank>{
+    alt((
+        map(alt((tag_no_case("queen"), tag_no_case("q"))), |_| TarotRank::Queen),
+        map(alt((tag_no_case("knight"), tag_no_case("cavalier"), tag_no_case("c"), tag_no_case("n"))),
+            |_| TarotRank::Knight),
+        map(alt((tag_no_case("king"), tag_no_case("k"))), |_| TarotRank::King),
+        map(alt((tag_no_case("jack"), tag_no_case("j"))), |_| TarotRank::Jack),
+        map(alt((tag_no_case("ten"), tag_no_case("t"))), |_| TarotRank::Number(10)),
+        map_opt(parse_u8, |n| match n{
+            legit @ MIN_TAROT_NUMBER..=MAX_TAROT_NUMBER => Some(TarotRank::Number(legit)),
+            _ => None
+        }),
+    )).parse(s)
+}
+
+fn parse_suited(s: &str) -> IResult<&str, TarotCard>{
+    map(alt((
+        separated_pair(parse_rank, (space0, tag_no_case("of"), space0), parse_suit),
+        separated_pair(parse_rank, space0, parse_suit),
+    )), |(rank, suit)| TarotCard::Suited(rank, suit)).parse(s)
+}
+
+/// Parses tarot card from `&str`. Accepted forms (case insensitive):
+/// ## suit cards:
+/// rank (`1`-`10`, `T`, `J`, `C`/`N`, `Q`, `K` or full name) followed by suit, optionally separated with `of`;
+/// ## trumps:
+/// `T` or `trump` followed by number `1..=21`;
+/// ## the Excuse:
+/// `excuse`, `fool`, `ex` or `*`.
+/// ```
+/// use karty::tarot::parse::parse_tarot_card;
+/// use karty::tarot::{TarotCard, TarotRank};
+/// use karty::suits::Suit;
+/// assert_eq!(parse_tarot_card("T21 x"), Ok((" x", TarotCard::Trump(21))));
+/// assert_eq!(parse_tarot_card("trump 7"), Ok(("", TarotCard::Trump(7))));
+/// assert_eq!(parse_tarot_card("C♥"), Ok(("", TarotCard::Suited(TarotRank::Knight, Suit::Hearts))));
+/// assert_eq!(parse_tarot_card("Th"), Ok(("", TarotCard::Suited(TarotRank::number(10), Suit::Hearts))));
+/// assert_eq!(parse_tarot_card("queen of spades"), Ok(("", TarotCard::Suited(TarotRank::Queen, Suit::Spades))));
+/// assert_eq!(parse_tarot_card("Excuse"), Ok(("", TarotCard::Excuse)));
+/// assert!(parse_tarot_card("T22").is_err());
+/// ```
+pub fn parse_tarot_card(s: &str) -> IResult<&str, TarotCard>{
+    alt((parse_excuse, parse_trump, parse_suited)).parse(s)
+}
+
+/// Parses [`TarotCard`] from str
+/// ```
+/// use std::str::FromStr;
+/// use karty::tarot::{TarotCard, TarotRank};
+/// use karty::suits::Suit;
+/// assert_eq!(TarotCard::from_str("7 d"), Ok(TarotCard::Suited(TarotRank::number(7), Suit::Diamonds)));
+/// assert_eq!(TarotCard::from_str("fool"), Ok(TarotCard::Excuse));
+/// assert!(TarotCard::from_str("11s").is_err());
+/// ```
+impl FromStr for TarotCard{
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_tarot_card(s).map(|(_, card)| card).map_err(|_e| CardError::ParseError)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::str::FromStr;
+    use crate::symbol::CardSymbolIterator;
+    use crate::tarot::TarotCard;
+
+    #[test]
+    fn display_round_trip(){
+        for card in CardSymbolIterator::<TarotCard>::new(){
+            assert_eq!(TarotCard::from_str(&format!("{card:#}")), Ok(card));
+            assert_eq!(TarotCard::from_str(&format!("{card}")), Ok(card));
+        }
+    }
+}
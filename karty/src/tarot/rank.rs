@@ -0,0 +1,171 @@
+use core::fmt::{Display, Formatter};
+use crate::error::CardError;
+use crate::symbol::CardSymbol;
+
+/// Lowest number on tarot suit card
+pub const MIN_TAROT_NUMBER: u8 = 1;
+/// Highest number on tarot suit card
+pub const MAX_TAROT_NUMBER: u8 = 10;
+
+/// Rank of tarot suit card: numbers `1..=10`, Jack, Knight, Queen and King (in ascending order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawTarotRank"))]
+pub enum TarotRank{
+    /// Number card `1..=10`, other numbers are invalid ([`CardSymbol::usize_index`] panics on them
+    /// and deserialization rejects them)
+    Number(u8),
+    Jack,
+    Knight,
+    Queen,
+    King
+}
+
+/// Layout of [`TarotRank`] as read by deserializers, before number is checked.
+#[cfg(any(feature = "serde", feature = "speedy"))]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "TarotRank"))]
+enum RawTarotRank{
+    Number(u8),
+    Jack,
+    Knight,
+    Queen,
+    King
+}
+
+#[cfg(any(feature = "serde", feature = "speedy"))]
+impl TryFrom<RawTarotRank> for TarotRank{
+    type Error = CardError;
+
+    fn try_from(raw: RawTarotRank) -> Result<Self, Self::Error> {
+        match raw{
+            RawTarotRank::Number(legit @ MIN_TAROT_NUMBER..=MAX_TAROT_NUMBER) => Ok(Self::Number(legit)),
+            RawTarotRank::Number(n) => Err(CardError::InvalidTarotNumber(n)),
+            RawTarotRank::Jack => Ok(Self::Jack),
+            RawTarotRank::Knight => Ok(Self::Knight),
+            RawTarotRank::Queen => Ok(Self::Queen),
+            RawTarotRank::King => Ok(Self::King)
+        }
+    }
+}
+
+/// Reads rank with the same number check as serde deserialization.
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for TarotRank{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        RawTarotRank::read_from(reader)?.try_into().map_err(|e| speedy::Error::custom(e).into())
+    }
+}
+
+impl TarotRank{
+    /// Creates number rank.
+    /// # Panics:
+    /// When number is not in `1..=10`.
+    pub fn number(number: u8) -> Self{
+        match number{
+            legit @ MIN_TAROT_NUMBER..=MAX_TAROT_NUMBER => Self::Number(legit),
+            e => panic!("Invalid tarot number {e:?}")
+        }
+    }
+
+    /// Checks if rank is court card (Jack, Knight, Queen or King).
+    pub fn is_court(&self) -> bool{
+        !matches!(self, Self::Number(_))
+    }
+}
+
+impl CardSymbol for TarotRank{
+    const SYMBOL_SPACE: usize = 14;
+
+    /// ```
+    /// use karty::tarot::TarotRank;
+    /// use karty::symbol::CardSymbol;
+    /// assert_eq!(TarotRank::number(1).usize_index(), 0);
+    /// assert_eq!(TarotRank::Knight.usize_index(), 11);
+    /// assert_eq!(TarotRank::from_usize_index(13).unwrap(), TarotRank::King);
+    /// ```
+    fn usize_index(&self) -> usize {
+        match self{
+            Self::Number(n @ MIN_TAROT_NUMBER..=MAX_TAROT_NUMBER) => (*n - MIN_TAROT_NUMBER) as usize,
+            Self::Number(n) => panic!("Invalid tarot number {n:?}"),
+            Self::Jack => 10,
+            Self::Knight => 11,
+            Self::Queen => 12,
+            Self::King => 13
+        }
+    }
+
+    fn from_usize_index(position: usize) -> Result<Self, CardError> {
+        match position{
+            n @ 0..=9 => Ok(Self::Number(n as u8 + MIN_TAROT_NUMBER)),
+            10 => Ok(Self::Jack),
+            11 => Ok(Self::Knight),
+            12 => Ok(Self::Queen),
+            13 => Ok(Self::King),
+            e => Err(CardError::WrongFigurePosition(e))
+        }
+    }
+}
+
+/// Implements [`Display`]: alternate form uses single characters `1`-`9`, `T` for ten,
+/// `J`, `C` (cavalier) for knight, `Q` and `K`.
+/// ```
+/// use karty::tarot::TarotRank;
+/// assert_eq!(format!("{}", TarotRank::Knight), "Knight");
+/// assert_eq!(format!("{:#}", TarotRank::Knight), "C");
+/// assert_eq!(format!("{:#}", TarotRank::number(10)), "T");
+/// ```
+impl Display for TarotRank{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (f.alternate(), self){
+            (true, Self::Number(10)) => write!(f, "T"),
+            (_, Self::Number(n)) => write!(f, "{n}"),
+            (true, Self::Jack) => write!(f, "J"),
+            (true, Self::Knight) => write!(f, "C"),
+            (true, Self::Queen) => write!(f, "Q"),
+            (true, Self::King) => write!(f, "K"),
+            (false, Self::Jack) => write!(f, "Jack"),
+            (false, Self::Knight) => write!(f, "Knight"),
+            (false, Self::Queen) => write!(f, "Queen"),
+            (false, Self::King) => write!(f, "King"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::tarot::TarotRank;
+    use crate::symbol::CardSymbol;
+
+    #[test]
+    #[should_panic]
+    fn invalid_number_has_no_index(){
+        TarotRank::Number(11).usize_index();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_number(){
+        use crate::suits::Suit;
+        use crate::tarot::TarotCard;
+        assert_eq!(ron::from_str::<TarotRank>("Number(10)").unwrap(), TarotRank::number(10));
+        assert_eq!(ron::from_str::<TarotRank>("Knight").unwrap(), TarotRank::Knight);
+        assert!(ron::from_str::<TarotRank>("Number(0)").is_err());
+        assert!(ron::from_str::<TarotRank>("Number(11)").is_err());
+        assert_eq!(ron::from_str::<TarotCard>("Suited(Number(3),Hearts)").unwrap(),
+            TarotCard::Suited(TarotRank::number(3), Suit::Hearts));
+        assert!(ron::from_str::<TarotCard>("Suited(Number(0),Hearts)").is_err());
+    }
+
+    #[cfg(feature = "speedy")]
+    #[test]
+    fn speedy_rejects_invalid_number(){
+        use speedy::{Readable, Writable};
+        let queen = TarotRank::Queen.write_to_vec().unwrap();
+        assert_eq!(TarotRank::read_from_buffer(&queen).unwrap(), TarotRank::Queen);
+        let invalid = TarotRank::Number(11).write_to_vec().unwrap();
+        assert!(TarotRank::read_from_buffer(&invalid).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+use core::fmt::{Display, Formatter};
+use crate::error::CardSetErrorGen;
+use crate::set::CardSet;
+use crate::symbol::CardSymbol;
+use crate::tarot::TarotCard;
+
+/// Mask of all 78 tarot cards
+const MASK_TAROT_DECK: u128 = (1u128 << TarotCard::SYMBOL_SPACE) - 1;
+
+/// Full tarot deck
+pub const TAROT_DECK: TarotCardSet = TarotCardSet{cards: MASK_TAROT_DECK};
+
+/// Set of tarot cards stored as bitset (78 bits), bit position is [`CardSymbol::usize_index`] of card.
+/// ```
+/// use karty::set::CardSet;
+/// use karty::tarot::{TarotCard, TarotCardSet, TarotRank, TAROT_DECK};
+/// use karty::suits::Suit;
+/// let mut set = TarotCardSet::empty();
+/// set.insert_card(TarotCard::Excuse).unwrap();
+/// set.insert_card(TarotCard::Trump(21)).unwrap();
+/// set.insert_card(TarotCard::Suited(TarotRank::King, Suit::Clubs)).unwrap();
+/// assert!(set.insert_card(TarotCard::Excuse).is_err());
+/// assert_eq!(set.len(), 3);
+/// assert_eq!(set.oudlers(), 2);
+/// assert_eq!(set.points(), 13.5);
+/// assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![
+///     TarotCard::Suited(TarotRank::King, Suit::Clubs), TarotCard::Trump(21), TarotCard::Excuse]);
+/// assert_eq!(TAROT_DECK.len(), 78);
+/// assert_eq!(TAROT_DECK.points(), 91.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Writable))]
+pub struct TarotCardSet{
+    pub(crate) cards: u128
+}
+
+impl TarotCardSet{
+    fn mask(card: &TarotCard) -> u128{
+        1u128 << card.usize_index()
+    }
+
+    /// Cards of this set that are not in other set.
+    pub fn difference(&self, other: &Self) -> Self{
+        Self{cards: self.cards & !other.cards}
+    }
+
+    /// Number of oudlers in set.
+    pub fn oudlers(&self) -> usize{
+        self.into_iter().filter(|c| c.is_oudler()).count()
+    }
+
+    /// Sum of card values in half-points.
+    pub fn half_points(&self) -> u32{
+        self.into_iter().map(|c| c.half_points()).sum()
+    }
+
+    /// Sum of card values in points.
+    pub fn points(&self) -> f32{
+        self.half_points() as f32 / 2.0
+    }
+}
+
+impl CardSet for TarotCardSet{
+    type CardType = TarotCard;
+
+    fn insert_card(&mut self, card: Self::CardType) -> Result<(), CardSetErrorGen<Self::CardType>> {
+        match self.contains(&card){
+            true => Err(CardSetErrorGen::CardDuplicated(card)),
+            false => {
+                self.cards |= Self::mask(&card);
+                Ok(())
+            }
+        }
+    }
+
+    fn remove_card(&mut self, card: &Self::CardType) -> Result<(), CardSetErrorGen<Self::CardType>> {
+        match self.contains(card){
+            true => {
+                self.cards ^= Self::mask(card);
+                Ok(())
+            },
+            false => Err(CardSetErrorGen::CardNotInSet(*card))
+        }
+    }
+
+    fn empty() -> Self {
+        Self{cards: 0u128}
+    }
+
+    fn contains(&self, card: &Self::CardType) -> bool {
+        Self::mask(card) & self.cards != 0
+    }
+
+    fn len(&self) -> usize {
+        self.cards.count_ones() as usize
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Self{cards: self.cards | other.cards}
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        Self{cards: self.cards & other.cards}
+    }
+}
+
+/// Reads set rejecting bits outside of 78-card deck.
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for TarotCardSet{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        match u128::read_from(reader)?{
+            cards if cards & !MASK_TAROT_DECK == 0 => Ok(Self{cards}),
+            _ => Err(speedy::Error::custom(crate::error::CardError::MaskSpaceViolated).into())
+        }
+    }
+}
+
+/// Iterator over cards of [`TarotCardSet`] in ascending order.
+pub struct TarotCardSetIterator{
+    remaining: u128
+}
+
+impl Iterator for TarotCardSetIterator{
+    type Item = TarotCard;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining{
+            0 => None,
+            r => {
+                let position = r.trailing_zeros() as usize;
+                self.remaining &= r - 1;
+                Some(TarotCard::from_usize_index(position)
+                    .expect("Tarot card set holds only bits of cards (should not happen, it is a bug)."))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.remaining.count_ones() as usize;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for TarotCardSetIterator{}
+
+impl IntoIterator for TarotCardSet{
+    type Item = TarotCard;
+    type IntoIter = TarotCardSetIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TarotCardSetIterator{remaining: self.cards}
+    }
+}
+
+impl FromIterator<TarotCard> for TarotCardSet{
+    fn from_iter<T: IntoIterator<Item=TarotCard>>(iter: T) -> Self {
+        Self::from_iterator(iter.into_iter())
+    }
+}
+
+/// Implements [`Display`] listing cards, alternate form uses compact card symbols.
+/// ```
+/// use karty::tarot::{TarotCard, TarotCardSet};
+/// let set: TarotCardSet = [TarotCard::Excuse, TarotCard::Trump(1)].into_iter().collect();
+/// assert_eq!(format!("{set:#}"), "[T1, EX, ]");
+/// ```
+impl Display for TarotCardSet{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+        for card in self.into_iter(){
+            match f.alternate(){
+                true => write!(f, "{card:#}, ")?,
+                false => write!(f, "{card}, ")?
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Serialized as sequence of cards in ascending order (`u128` mask is not supported by every format, e.g. RON).
+/// Deserialization rejects duplicated cards.
+#[cfg(feature = "serde")]
+mod serde{
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::Error;
+    use crate::set::CardSet;
+    use crate::tarot::{TarotCard, TarotCardSet};
+
+    impl Serialize for TarotCardSet{
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+            serializer.collect_seq(*self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TarotCardSet{
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+            let mut set = Self::empty();
+            for card in Vec::<TarotCard>::deserialize(deserializer)?{
+                set.insert_card(card).map_err(D::Error::custom)?;
+            }
+            Ok(set)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::set::CardSet;
+    use crate::symbol::CardSymbolIterator;
+    use crate::tarot::{TarotCard, TarotCardSet, TAROT_DECK};
+
+    #[test]
+    fn full_deck(){
+        let deck: TarotCardSet = CardSymbolIterator::<TarotCard>::new().collect();
+        assert_eq!(deck, TAROT_DECK);
+        assert_eq!(deck.oudlers(), 3);
+        assert_eq!(deck.half_points(), 182);
+        let trumps: TarotCardSet = (1..=21).map(TarotCard::trump).collect();
+        let rest = deck.difference(&trumps);
+        assert_eq!(rest.len(), 57);
+        assert_eq!(rest.union(&trumps), deck);
+        assert!(rest.intersection(&trumps).is_empty());
+    }
+
+    #[cfg(feature = "speedy")]
+    #[test]
+    fn speedy_rejects_bits_outside_deck(){
+        use speedy::{Readable, Writable};
+        let serialized = TAROT_DECK.write_to_vec().unwrap();
+        assert_eq!(TarotCardSet::read_from_buffer(&serialized).unwrap(), TAROT_DECK);
+        let invalid = (1u128 << 78).write_to_vec().unwrap();
+        assert!(TarotCardSet::read_from_buffer(&invalid).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use core::ops::RangeInclusive;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use crate::cards::{Card2SGen, DECK_SIZE, STANDARD_DECK};
+use crate::figures::Figure;
+use crate::set::{CardSet, CardSetGeneric, CardSetStd};
+use crate::suits::{Suit, SuitMap};
+use crate::symbol::CardSymbol;
+
+/// Takes symbol uniformly from whole [`CardSymbol::SYMBOL_SPACE`].
+/// ```
+/// use arbitrary::Unstructured;
+/// use karty::symbol::CardSymbol;
+/// use karty::tarot::TarotCard;
+/// use karty::testing::arbitrary_symbol;
+/// let mut u = Unstructured::new(&[1, 2, 3, 4]);
+/// let card: TarotCard = arbitrary_symbol(&mut u).unwrap();
+/// assert!(TarotCard::iterator().any(|c| c == card));
+/// ```
+pub fn arbitrary_symbol<S: CardSymbol>(u: &mut Unstructured) -> Result<S>{
+    let index = u.choose_index(S::SYMBOL_SPACE)?;
+    Ok(S::from_usize_index(index).expect("Index lesser than symbol space (should not happen, it is a bug)."))
+}
+
+/// Moves `n` randomly chosen cards of deck to its front (partial Fisher-Yates shuffle).
+fn shuffle_front(u: &mut Unstructured, deck: &mut [Card2SGen<Figure, Suit>; DECK_SIZE], n: usize) -> Result<()>{
+    for i in 0..n{
+        let j = i + u.choose_index(DECK_SIZE - i)?;
+        deck.swap(i, j);
+    }
+    Ok(())
+}
+
+/// Takes [`CardSetStd`] with number of cards in `len` (upper bound is clamped to [`DECK_SIZE`]).
+/// ```
+/// use arbitrary::Unstructured;
+/// use karty::testing::arbitrary_card_set;
+/// let mut u = Unstructured::new(&[7; 64]);
+/// let hand = arbitrary_card_set(&mut u, 13..=13).unwrap();
+/// assert_eq!(hand.len(), 13);
+/// ```
+pub fn arbitrary_card_set(u: &mut Unstructured, len: RangeInclusive<usize>) -> Result<CardSetStd>{
+    let (low, high) = (*len.start(), (*len.end()).min(DECK_SIZE));
+    let n = u.int_in_range(low.min(high)..=high)?;
+    let mut deck = STANDARD_DECK;
+    shuffle_front(u, &mut deck, n)?;
+    Ok(CardSetStd::from_cards(&deck[..n]))
+}
+
+/// Takes deal of `K` disjoint hands of `hand_size` cards.
+/// # Panics:
+/// When `K * hand_size` exceeds [`DECK_SIZE`].
+/// ```
+/// use arbitrary::Unstructured;
+/// use karty::set::CardSet;
+/// use karty::testing::arbitrary_deal;
+/// let mut u = Unstructured::new(&[3, 1, 4, 1, 5, 9, 2, 6]);
+/// let [n, e, s, w] = arbitrary_deal::<4>(&mut u, 13).unwrap();
+/// assert_eq!(n.union(&e).union(&s).union(&w).len(), 52);
+/// ```
+pub fn arbitrary_deal<const K: usize>(u: &mut Unstructured, hand_size: usize) -> Result<[CardSetStd; K]>{
+    assert!(K * hand_size <= DECK_SIZE, "Deal of {K} hands of {hand_size} cards does not fit in deck");
+    let mut deck = STANDARD_DECK;
+    shuffle_front(u, &mut deck, K * hand_size)?;
+    Ok(core::array::from_fn(|i| CardSetStd::from_cards(&deck[i * hand_size..(i + 1) * hand_size])))
+}
+
+impl<'a> Arbitrary<'a> for Suit{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_symbol(u)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Figure{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_symbol(u)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Card2SGen<Figure, Suit>{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_symbol(u)
+    }
+}
+
+/// Takes set of any size (bits of `u64` not representing cards are ignored),
+/// use [`arbitrary_card_set`] to constrain size.
+impl<'a> Arbitrary<'a> for CardSetStd{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(CardSetStd::from_u64(u64::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+impl<'a, Crd: CardSymbol + Arbitrary<'a>> Arbitrary<'a> for CardSetGeneric<Crd>{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut set = Self::empty();
+        for card in u.arbitrary_iter::<Crd>()?{
+            set.insert_card_noerr(card?);
+        }
+        Ok(set)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for SuitMap<T>{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(SuitMap::new(T::arbitrary(u)?, T::arbitrary(u)?, T::arbitrary(u)?, T::arbitrary(u)?))
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use arbitrary::{Arbitrary, Unstructured};
+    use crate::cards::Card;
+    use crate::set::{CardSet, CardSetGeneric};
+    use crate::suits::SuitMap;
+    use crate::testing::{arbitrary_card_set, arbitrary_deal};
+
+    #[test]
+    fn arbitrary_values_are_valid(){
+        let data: Vec<u8> = (0..=255u8).cycle().take(2048).map(|b| b.wrapping_mul(37)).collect();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..20{
+            let hand = arbitrary_card_set(&mut u, 5..=8).unwrap();
+            assert!((5..=8).contains(&hand.len()));
+        }
+        let hands = arbitrary_deal::<3>(&mut u, 17).unwrap();
+        assert_eq!(hands.iter().fold(hands[0], |acc, h| acc.union(h)).len(), 51);
+        let set = CardSetGeneric::<Card>::arbitrary(&mut u).unwrap();
+        assert!(set.len() <= 52);
+        let map = SuitMap::<u8>::arbitrary(&mut u).unwrap();
+        assert_eq!(map.map(|_| 1u8).sum(), 4);
+    }
+}
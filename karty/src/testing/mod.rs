@@ -0,0 +1,19 @@
+//! Module with support for property-based testing of code using cards.
+//! Feature `proptest` provides [`proptest`](https://docs.rs/proptest) strategies (and implementations of
+//! `proptest::arbitrary::Arbitrary`, so `any::<Card>()` works), feature `arbitrary` provides
+//! implementations of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for fuzzing.
+//! Both generate only valid values: every card set has cards from deck, hands of deal are disjoint.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "proptest")]
+pub use strategy::*;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "arbitrary")]
+pub use arbitrary_impls::*;
@@ -0,0 +1,131 @@
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::array::uniform4;
+use proptest::sample::{subsequence, SizeRange};
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+use crate::cards::{Card, Card2SGen, DECK_SIZE, STANDARD_DECK};
+use crate::figures::Figure;
+use crate::set::{CardSet, CardSetGeneric, CardSetStd};
+use crate::suits::{Suit, SuitMap};
+use crate::symbol::{CardSymbol, CardSymbolIterator};
+
+/// Strategy generating symbol uniformly from whole [`CardSymbol::SYMBOL_SPACE`]. Shrinks towards symbol of index `0`.
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+/// use karty::symbol::CardSymbol;
+/// use karty::tarot::TarotCard;
+/// use karty::testing::symbol;
+/// let mut runner = TestRunner::deterministic();
+/// let card: TarotCard = symbol().new_tree(&mut runner).unwrap().current();
+/// assert!(TarotCard::iterator().any(|c| c == card));
+/// ```
+pub fn symbol<S: CardSymbol>() -> impl Strategy<Value = S> + Clone{
+    (0..S::SYMBOL_SPACE).prop_map(|i| S::from_usize_index(i)
+        .expect("Index lesser than symbol space (should not happen, it is a bug)."))
+}
+
+/// Strategy generating standard suit.
+pub fn suit() -> impl Strategy<Value = Suit> + Clone{
+    symbol()
+}
+
+/// Strategy generating standard figure.
+pub fn figure() -> impl Strategy<Value = Figure> + Clone{
+    symbol()
+}
+
+/// Strategy generating standard card.
+pub fn card() -> impl Strategy<Value = Card> + Clone{
+    symbol()
+}
+
+/// Strategy generating [`CardSetStd`] with number of cards in `size` (which must not exceed [`DECK_SIZE`]).
+/// ```
+/// use proptest::proptest;
+/// use karty::testing::card_set;
+/// proptest!(|(hand in card_set(13))| {
+///     assert_eq!(hand.len(), 13);
+/// });
+/// ```
+pub fn card_set(size: impl Into<SizeRange>) -> impl Strategy<Value = CardSetStd>{
+    subsequence(STANDARD_DECK.to_vec(), size)
+        .prop_map(|cards| CardSetStd::from_iterator(cards.into_iter()))
+}
+
+/// Strategy generating [`CardSetGeneric`] of symbols, with number of cards in `size`
+/// (which must not exceed [`CardSymbol::SYMBOL_SPACE`]).
+pub fn card_set_generic<S: CardSymbol + 'static>(size: impl Into<SizeRange>) -> impl Strategy<Value = CardSetGeneric<S>>{
+    subsequence(CardSymbolIterator::<S>::new().collect::<Vec<S>>(), size)
+        .prop_map(|cards| CardSetGeneric::from_iterator(cards.into_iter()))
+}
+
+/// Strategy generating [`SuitMap`] with values generated by `element`.
+pub fn suit_map<T: core::fmt::Debug + Clone, St: Strategy<Value = T> + Clone>(element: St) -> impl Strategy<Value = SuitMap<T>>{
+    uniform4(element).prop_map(|[spades, hearts, diamonds, clubs]| SuitMap::new(spades, hearts, diamonds, clubs))
+}
+
+/// Strategy generating deal of `K` disjoint hands of `hand_size` cards from shuffled standard deck.
+/// # Panics:
+/// When `K * hand_size` exceeds [`DECK_SIZE`].
+/// ```
+/// use proptest::proptest;
+/// use karty::set::CardSet;
+/// use karty::testing::deal;
+/// proptest!(|(hands in deal::<4>(13))| {
+///     let [n, e, s, w] = hands;
+///     assert_eq!(n.union(&e).union(&s).union(&w).len(), 52);
+/// });
+/// ```
+pub fn deal<const K: usize>(hand_size: usize) -> impl Strategy<Value = [CardSetStd; K]>{
+    assert!(K * hand_size <= DECK_SIZE, "Deal of {K} hands of {hand_size} cards does not fit in deck");
+    Just(STANDARD_DECK.to_vec()).prop_shuffle()
+        .prop_map(move |deck| core::array::from_fn(|i|
+            CardSetStd::from_iterator(deck[i * hand_size..(i + 1) * hand_size].iter().copied())))
+}
+
+impl Arbitrary for Suit{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        suit().boxed()
+    }
+}
+
+impl Arbitrary for Figure{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        figure().boxed()
+    }
+}
+
+impl Arbitrary for Card2SGen<Figure, Suit>{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        card().boxed()
+    }
+}
+
+/// Generates sets of any size, use [`card_set`] to constrain size.
+impl Arbitrary for CardSetStd{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        card_set(0..=DECK_SIZE).boxed()
+    }
+}
+
+impl<T: Arbitrary + Clone + 'static> Arbitrary for SuitMap<T>
+where T::Strategy: Clone{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        suit_map(any::<T>()).boxed()
+    }
+}
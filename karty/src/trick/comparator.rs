@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use comparator::Comparator;
+use crate::cards::{Card, Card2SGen, Card2SymTrait};
+use crate::figures::FigureTrait;
+use crate::set::CardSetStd;
+use crate::suits::{Suit, SuitTrait};
+
+/// Comparator of cards played to a trick, where ordering depends on led suit and trump suit chosen at runtime.
+/// Trumps beat all other cards, cards in led suit beat cards of other suits. Cards of the same class
+/// (trumps, led suit, others) are compared with figure comparator. Cards of suits other than led and trump
+/// can never win a trick, among them only figures are compared.
+/// ```
+/// use comparator::Comparator;
+/// use std::cmp::Ordering;
+/// use karty::cards::*;
+/// use karty::figures::FigureComparator;
+/// use karty::suits::Suit;
+/// use karty::trick::TrickComparator;
+/// let comparator = TrickComparator::new(Suit::Hearts, Some(Suit::Spades), FigureComparator{});
+/// assert_eq!(comparator.compare(&TWO_SPADES, &ACE_HEARTS), Ordering::Greater);
+/// assert_eq!(comparator.compare(&TWO_HEARTS, &ACE_CLUBS), Ordering::Greater);
+/// assert_eq!(comparator.compare(&TWO_HEARTS, &THREE_HEARTS), Ordering::Less);
+/// let mut trick = vec![KING_HEARTS, ACE_DIAMONDS, THREE_SPADES, ACE_HEARTS];
+/// trick.sort_by(|l, r| comparator.compare(l, r));
+/// assert_eq!(trick, vec![ACE_DIAMONDS, KING_HEARTS, ACE_HEARTS, THREE_SPADES]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrickComparator<F: FigureTrait, S: SuitTrait, CF: Comparator<F>>{
+    pub led: S,
+    pub trump: Option<S>,
+    pub figure_comparator: CF,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: FigureTrait, S: SuitTrait, CF: Comparator<F>> TrickComparator<F, S, CF>{
+    pub fn new(led: S, trump: Option<S>, figure_comparator: CF) -> Self{
+        Self{led, trump, figure_comparator, _phantom: PhantomData}
+    }
+
+    /// Class of card in trick: `2` for trump, `1` for led suit, `0` otherwise.
+    fn class(&self, suit: &S) -> u8{
+        match (self.trump.as_ref() == Some(suit), suit == &self.led){
+            (true, _) => 2,
+            (false, true) => 1,
+            (false, false) => 0
+        }
+    }
+}
+
+impl<F: FigureTrait + Copy, S: SuitTrait + Copy, CF: Comparator<F>> Comparator<Card2SGen<F, S>> for TrickComparator<F, S, CF>{
+    fn compare(&self, l: &Card2SGen<F, S>, r: &Card2SGen<F, S>) -> Ordering {
+        self.class(&l.suit()).cmp(&self.class(&r.suit()))
+            .then_with(|| self.figure_comparator.compare(&l.figure(), &r.figure()))
+    }
+}
+
+/// Finds card winning among cards in set (e.g. cards played to a trick), comparing figures with their natural order.
+/// Uses only bit operations: the highest trump wins, if there is no trump the highest card in led suit wins.
+/// Returns `None` if set has neither trump nor card in led suit.
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::suits::Suit;
+/// use karty::trick::winning_card_in_set;
+/// let trick = card_set![KING_HEARTS, ACE_DIAMONDS, THREE_SPADES, ACE_HEARTS];
+/// assert_eq!(winning_card_in_set(&trick, Suit::Hearts, None), Some(ACE_HEARTS));
+/// assert_eq!(winning_card_in_set(&trick, Suit::Hearts, Some(Suit::Spades)), Some(THREE_SPADES));
+/// assert_eq!(winning_card_in_set(&trick, Suit::Clubs, None), None);
+/// ```
+pub fn winning_card_in_set(cards: &CardSetStd, led: Suit, trump: Option<Suit>) -> Option<Card>{
+    let trumps = trump.map(|t| cards.only_in_suit(&t).cards).unwrap_or(0);
+    let candidates = match trumps{
+        0 => cards.only_in_suit(&led).cards,
+        t => t
+    };
+    match candidates{
+        0 => None,
+        c => Card::from_usize_index(63 - c.leading_zeros() as usize).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use comparator::Comparator;
+    use crate::cards::*;
+    use crate::figures::{AceTenFigureComparator, FigureComparator};
+    use crate::set::{CardSet, CardSetStd};
+    use crate::suits::Suit;
+    use crate::trick::{winning_card_in_set, Trick, TrickComparator};
+
+    #[test]
+    fn agrees_with_trick_winner(){
+        let tricks = [
+            [TEN_CLUBS, KING_CLUBS, TWO_DIAMONDS, NINE_CLUBS],
+            [FOUR_HEARTS, TWO_HEARTS, ACE_SPADES, QUEEN_DIAMONDS],
+            [JACK_SPADES, TWO_DIAMONDS, FIVE_DIAMONDS, ACE_CLUBS],
+        ];
+        for trump in [None, Some(Suit::Diamonds), Some(Suit::Spades)]{
+            for cards in tricks{
+                let mut trick = Trick::<Card, 4>::new(0);
+                for c in cards{
+                    trick.add_card(c).unwrap();
+                }
+                let comparator = TrickComparator::new(cards[0].suit(), trump, FigureComparator{});
+                let best = cards.iter().copied().max_by(|l, r| comparator.compare(l, r));
+                assert_eq!(trick.winning_card(trump).copied(), best);
+                let set = CardSetStd::from_iterator(cards.into_iter());
+                assert_eq!(winning_card_in_set(&set, cards[0].suit(), trump), best);
+            }
+        }
+    }
+
+    #[test]
+    fn custom_figure_order(){
+        let comparator = TrickComparator::new(Suit::Clubs, None, AceTenFigureComparator{});
+        assert_eq!(comparator.compare(&TEN_CLUBS, &KING_CLUBS), std::cmp::Ordering::Greater);
+        assert_eq!(comparator.compare(&TEN_HEARTS, &NINE_CLUBS), std::cmp::Ordering::Less);
+    }
+}
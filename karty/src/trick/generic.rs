@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use comparator::Comparator;
+use crate::cards::Card2SymTrait;
+use crate::error::TrickErrorGen;
+
+/// Trick of `N` players. Players are numbered `0..N` and play clockwise (ascending, modulo `N`),
+/// starting with the lead player.
+/// ```
+/// use karty::cards::*;
+/// use karty::suits::Suit;
+/// use karty::trick::Trick;
+/// let mut trick = Trick::<Card, 4>::new(2);
+/// assert_eq!(trick.add_card(KING_HEARTS), Ok(2));
+/// assert_eq!(trick.add_card(ACE_HEARTS), Ok(3));
+/// assert_eq!(trick.add_card(TWO_SPADES), Ok(0));
+/// assert_eq!(trick.current_player(), Some(1));
+/// assert_eq!(trick.add_card(THREE_HEARTS), Ok(1));
+/// assert!(trick.is_complete());
+/// assert_eq!(trick.winner(None), Some(3));
+/// assert_eq!(trick.winner(Some(Suit::Spades)), Some(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trick<Crd: Card2SymTrait, const N: usize>{
+    lead: usize,
+    cards: [Option<Crd>; N],
+    count: usize,
+}
+
+impl<Crd: Card2SymTrait, const N: usize> Trick<Crd, N>{
+    /// Creates empty trick led by player `lead`.
+    /// # Panics:
+    /// When `lead` is not lesser than `N`.
+    pub fn new(lead: usize) -> Self{
+        match lead < N{
+            true => Self{lead, cards: std::array::from_fn(|_| None), count: 0},
+            false => panic!("Invalid lead player {lead} in trick of {N} players")
+        }
+    }
+
+    pub fn lead(&self) -> usize{
+        self.lead
+    }
+
+    /// Number of cards already played to trick.
+    pub fn len(&self) -> usize{
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool{
+        self.count == 0
+    }
+
+    pub fn is_complete(&self) -> bool{
+        self.count == N
+    }
+
+    /// Returns player expected to play next card, or `None` if trick is complete.
+    pub fn current_player(&self) -> Option<usize>{
+        match self.is_complete(){
+            true => None,
+            false => Some((self.lead + self.count) % N)
+        }
+    }
+
+    /// Adds card played by [`current_player`](Self::current_player).
+    /// Legality of the card is not checked here, see [`TrickRules`](crate::trick::TrickRules).
+    /// # Returns:
+    /// Number of player who played the card.
+    pub fn add_card(&mut self, card: Crd) -> Result<usize, TrickErrorGen<Crd>>{
+        let player = self.current_player().ok_or(TrickErrorGen::TrickFull)?;
+        if self.cards.iter().flatten().any(|c| c == &card){
+            return Err(TrickErrorGen::CardDuplicated(card))
+        }
+        self.cards[player] = Some(card);
+        self.count += 1;
+        Ok(player)
+    }
+
+    /// Returns card played by player, if the player has already played.
+    pub fn card_of(&self, player: usize) -> Option<&Crd>{
+        self.cards.get(player).and_then(|c| c.as_ref())
+    }
+
+    pub fn led_card(&self) -> Option<&Crd>{
+        self.cards[self.lead].as_ref()
+    }
+
+    pub fn led_suit(&self) -> Option<Crd::Suit>{
+        self.led_card().map(|c| c.suit())
+    }
+
+    /// Iterates over played cards in order of playing, with numbers of players who played them.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Crd)> + '_{
+        (0..self.count).map(|i| {
+            let player = (self.lead + i) % N;
+            (player, self.cards[player].as_ref().expect("Card missing in trick (should not happen, it is a bug)."))
+        })
+    }
+
+    /// Returns player taking the trick (currently, if trick is not complete), comparing figures with their
+    /// natural order. Card of trump suit beats cards of other suits, otherwise only cards in led suit can win.
+    /// Returns `None` for empty trick.
+    pub fn winner(&self, trump: Option<Crd::Suit>) -> Option<usize>{
+        self.winner_by(trump, &|l: &Crd::Figure, r: &Crd::Figure| l.cmp(r))
+    }
+
+    /// Like [`winner`](Self::winner), but figures are compared with provided comparator.
+    /// ```
+    /// use karty::cards::*;
+    /// use std::cmp::Ordering;
+    /// use karty::figures::{Figure, F10};
+    /// use karty::trick::Trick;
+    /// let mut trick = Trick::<Card, 3>::new(0);
+    /// trick.add_card(TEN_CLUBS).unwrap();
+    /// trick.add_card(KING_CLUBS).unwrap();
+    /// trick.add_card(NINE_CLUBS).unwrap();
+    /// assert_eq!(trick.winner(None), Some(1));
+    /// // order where ten is above king
+    /// let ten_high = |l: &Figure, r: &Figure| match (*l, *r){
+    ///     (l, r) if l == r => Ordering::Equal,
+    ///     (F10, _) => Ordering::Greater,
+    ///     (_, F10) => Ordering::Less,
+    ///     (l, r) => l.cmp(&r)
+    /// };
+    /// assert_eq!(trick.winner_by(None, &ten_high), Some(0));
+    /// ```
+    pub fn winner_by<CF: Comparator<Crd::Figure>>(&self, trump: Option<Crd::Suit>, figure_comparator: &CF) -> Option<usize>{
+        self.iter().reduce(|best, challenger| {
+            let beats = match challenger.1.suit() == best.1.suit(){
+                true => figure_comparator.compare(&challenger.1.figure(), &best.1.figure()) == Ordering::Greater,
+                false => trump.as_ref() == Some(&challenger.1.suit())
+            };
+            match beats{
+                true => challenger,
+                false => best
+            }
+        }).map(|(player, _)| player)
+    }
+
+    /// Returns card taking the trick (currently, if trick is not complete), see [`winner`](Self::winner).
+    pub fn winning_card(&self, trump: Option<Crd::Suit>) -> Option<&Crd>{
+        self.winner(trump).and_then(|player| self.card_of(player))
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::*;
+    use crate::error::TrickErrorGen;
+    use crate::suits::Suit::{Clubs, Hearts};
+    use crate::trick::Trick;
+
+    #[test]
+    fn full_and_duplicated(){
+        let mut trick = Trick::<Card, 2>::new(1);
+        assert_eq!(trick.add_card(ACE_SPADES), Ok(1));
+        assert_eq!(trick.add_card(ACE_SPADES), Err(TrickErrorGen::CardDuplicated(ACE_SPADES)));
+        assert_eq!(trick.add_card(KING_SPADES), Ok(0));
+        assert_eq!(trick.add_card(QUEEN_SPADES), Err(TrickErrorGen::TrickFull));
+        assert_eq!(trick.iter().map(|(p, c)| (p, *c)).collect::<Vec<_>>(), vec![(1, ACE_SPADES), (0, KING_SPADES)]);
+    }
+
+    #[test]
+    fn winner_with_trump(){
+        let mut trick = Trick::<Card, 4>::new(0);
+        assert_eq!(trick.winner(None), None);
+        trick.add_card(ACE_SPADES).unwrap();
+        trick.add_card(TWO_CLUBS).unwrap();
+        trick.add_card(THREE_HEARTS).unwrap();
+        trick.add_card(FIVE_CLUBS).unwrap();
+        assert_eq!(trick.winner(None), Some(0));
+        assert_eq!(trick.winner(Some(Hearts)), Some(2));
+        assert_eq!(trick.winner(Some(Clubs)), Some(3));
+        assert_eq!(trick.winning_card(Some(Clubs)), Some(&FIVE_CLUBS));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bad_lead(){
+        let _ = Trick::<Card, 4>::new(4);
+    }
+}
@@ -0,0 +1,16 @@
+//! Module with primitives of trick-taking games: [`Trick`], rules deciding which cards are legal to play
+//! [`TrickRound`] tracking playing phase of a game and [`TrickComparator`] ordering cards by led and trump suit.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+mod generic;
+mod rules;
+mod round;
+mod comparator;
+
+pub use generic::*;
+pub use rules::*;
+pub use round::*;
+pub use comparator::*;
@@ -0,0 +1,140 @@
+use crate::cards::Card;
+use crate::error::GameError;
+use crate::set::{CardSet, CardSetStd};
+use crate::suits::Suit;
+use crate::trick::Trick;
+
+/// State of playing phase of a trick-taking game with standard deck and `N` players:
+/// hands, current trick, completed tricks and cards taken by players.
+/// Winner of the trick leads the next one. Game specific rules decide which cards are legal, see
+/// [`TrickRules`](crate::trick::TrickRules).
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::trick::{legal_moves, TrickRound};
+/// let mut round = TrickRound::<2>::new([
+///     card_set![ACE_SPADES, TWO_HEARTS],
+///     card_set![KING_SPADES, THREE_HEARTS]], 0);
+/// let legal = legal_moves(round.hand(0), round.trick());
+/// assert_eq!(round.play(ACE_SPADES, &legal, None), Ok(None));
+/// let legal = legal_moves(round.hand(1), round.trick());
+/// assert_eq!(legal, card_set![KING_SPADES]);
+/// assert_eq!(round.play(KING_SPADES, &legal, None), Ok(Some(0)));
+/// assert_eq!(round.tricks_won(0), 1);
+/// assert_eq!(round.current_player(), Some(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrickRound<const N: usize>{
+    hands: [CardSetStd; N],
+    trick: Trick<Card, N>,
+    completed_tricks: Vec<Trick<Card, N>>,
+    taken: [CardSetStd; N],
+    tricks_won: [usize; N],
+}
+
+impl<const N: usize> TrickRound<N>{
+    /// Creates round with first trick led by player `lead`.
+    /// # Panics:
+    /// When `lead` is not lesser than `N`.
+    pub fn new(hands: [CardSetStd; N], lead: usize) -> Self{
+        Self{
+            hands,
+            trick: Trick::new(lead),
+            completed_tricks: Vec::new(),
+            taken: [CardSetStd::empty(); N],
+            tricks_won: [0; N]
+        }
+    }
+
+    pub fn hands(&self) -> &[CardSetStd; N]{
+        &self.hands
+    }
+
+    pub fn hand(&self, player: usize) -> &CardSetStd{
+        &self.hands[player]
+    }
+
+    /// Trick being currently played.
+    pub fn trick(&self) -> &Trick<Card, N>{
+        &self.trick
+    }
+
+    pub fn completed_tricks(&self) -> &[Trick<Card, N>]{
+        &self.completed_tricks
+    }
+
+    /// Cards from tricks taken by player.
+    pub fn taken(&self, player: usize) -> &CardSetStd{
+        &self.taken[player]
+    }
+
+    pub fn tricks_won(&self, player: usize) -> usize{
+        self.tricks_won[player]
+    }
+
+    /// Round is finished when all hands are played out.
+    pub fn is_finished(&self) -> bool{
+        self.trick.is_empty() && self.hands.iter().all(|h| h.is_empty())
+    }
+
+    /// Player expected to play next card, or `None` if round is finished.
+    pub fn current_player(&self) -> Option<usize>{
+        match self.is_finished(){
+            true => None,
+            false => self.trick.current_player()
+        }
+    }
+
+    /// Plays card from hand of [`current_player`](Self::current_player), if it is in set of legal cards.
+    /// # Returns:
+    /// `Some(winner)` if the card completed a trick, `None` otherwise.
+    pub fn play(&mut self, card: Card, legal: &CardSetStd, trump: Option<Suit>) -> Result<Option<usize>, GameError>{
+        let player = self.current_player().ok_or(GameError::RoundFinished)?;
+        if !self.hands[player].contains(&card){
+            return Err(GameError::CardNotInHand(card))
+        }
+        if !legal.contains(&card){
+            return Err(GameError::IllegalMove(card))
+        }
+        self.trick.add_card(card)?;
+        self.hands[player].remove_card(&card).expect("Card checked to be in hand (should not happen, it is a bug).");
+        if !self.trick.is_complete(){
+            return Ok(None)
+        }
+        let winner = self.trick.winner(trump).expect("Complete trick has winner (should not happen, it is a bug).");
+        let cards = CardSetStd::from_iterator(self.trick.iter().map(|(_, c)| *c));
+        self.taken[winner] = self.taken[winner].union(&cards);
+        self.tricks_won[winner] += 1;
+        let completed = std::mem::replace(&mut self.trick, Trick::new(winner));
+        self.completed_tricks.push(completed);
+        Ok(Some(winner))
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::error::GameError;
+    use crate::set::CardSetStd;
+    use crate::suits::Suit::Hearts;
+    use crate::trick::TrickRound;
+
+    #[test]
+    fn play_errors_and_finish(){
+        let mut round = TrickRound::<2>::new([
+            card_set![ACE_SPADES],
+            card_set![TWO_HEARTS]], 1);
+        let all = card_set![ACE_SPADES, TWO_HEARTS];
+        assert_eq!(round.play(ACE_SPADES, &all, None), Err(GameError::CardNotInHand(ACE_SPADES)));
+        assert_eq!(round.play(TWO_HEARTS, &CardSetStd::empty(), None), Err(GameError::IllegalMove(TWO_HEARTS)));
+        assert_eq!(round.play(TWO_HEARTS, &all, Some(Hearts)), Ok(None));
+        assert_eq!(round.play(ACE_SPADES, &all, Some(Hearts)), Ok(Some(1)));
+        assert!(round.is_finished());
+        assert_eq!(round.current_player(), None);
+        assert_eq!(round.taken(1), &all);
+        assert!(round.taken(0).is_empty());
+        assert_eq!(round.completed_tricks().len(), 1);
+        assert_eq!(round.play(ACE_SPADES, &all, None), Err(GameError::RoundFinished));
+    }
+}
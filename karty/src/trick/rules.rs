@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+use comparator::Comparator;
+use crate::cards::{Card, Card2SymTrait};
+use crate::figures::{Figure, FigureComparator};
+use crate::set::{CardSet, CardSetStd};
+use crate::suits::Suit;
+use crate::trick::Trick;
+
+/// Rules of playing to a trick with standard deck.
+/// Player must always follow suit if able. Optionally:
+/// + `must_trump` - player void in led suit must play a trump if able;
+/// + `must_overtake` - player must beat card currently winning the trick if able, with a card the player is obliged to play
+///   (in led suit, or with higher trump when trick is already trumped and player is obliged to trump).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrickRules{
+    pub trump: Option<Suit>,
+    pub must_trump: bool,
+    pub must_overtake: bool,
+}
+
+impl TrickRules{
+    pub fn new(trump: Option<Suit>) -> Self{
+        Self{trump, must_trump: false, must_overtake: false}
+    }
+
+    pub fn with_must_trump(mut self, must_trump: bool) -> Self{
+        self.must_trump = must_trump;
+        self
+    }
+
+    pub fn with_must_overtake(mut self, must_overtake: bool) -> Self{
+        self.must_overtake = must_overtake;
+        self
+    }
+
+    /// Returns cards from hand that can be legally played to trick, figures are compared in natural order
+    /// (see [`legal_moves_by`](Self::legal_moves_by)).
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::suits::Suit::Hearts;
+    /// use karty::trick::{Trick, TrickRules};
+    /// let hand = card_set![ACE_SPADES, TWO_SPADES, FOUR_HEARTS, NINE_HEARTS, KING_CLUBS];
+    /// let mut trick = Trick::<Card, 4>::new(0);
+    /// trick.add_card(TEN_DIAMONDS).unwrap();
+    /// trick.add_card(THREE_HEARTS).unwrap();
+    /// // void in diamonds, no obligation to trump
+    /// assert_eq!(TrickRules::new(Some(Hearts)).legal_moves(&hand, &trick), hand);
+    /// let rules = TrickRules::new(Some(Hearts)).with_must_trump(true).with_must_overtake(true);
+    /// assert_eq!(rules.legal_moves(&hand, &trick), card_set![FOUR_HEARTS, NINE_HEARTS]);
+    /// trick.add_card(TEN_HEARTS).unwrap();
+    /// // cannot overtrump, still must trump
+    /// assert_eq!(rules.legal_moves(&hand, &trick), card_set![FOUR_HEARTS, NINE_HEARTS]);
+    /// ```
+    pub fn legal_moves<const N: usize>(&self, hand: &CardSetStd, trick: &Trick<Card, N>) -> CardSetStd{
+        self.legal_moves_by(hand, trick, &FigureComparator{})
+    }
+
+    /// Like [`legal_moves`](Self::legal_moves), but figures are compared with provided comparator when
+    /// deciding which card wins the trick and which cards overtake it.
+    /// ```
+    /// use karty::card_set;
+    /// use karty::cards::*;
+    /// use karty::figures::AceTenFigureComparator;
+    /// use karty::trick::{Trick, TrickRules};
+    /// let hand = card_set![ACE_SPADES, TEN_SPADES, QUEEN_SPADES];
+    /// let mut trick = Trick::<Card, 3>::new(0);
+    /// trick.add_card(KING_SPADES).unwrap();
+    /// let rules = TrickRules::new(None).with_must_overtake(true);
+    /// assert_eq!(rules.legal_moves_by(&hand, &trick, &AceTenFigureComparator{}), card_set![ACE_SPADES, TEN_SPADES]);
+    /// ```
+    pub fn legal_moves_by<const N: usize, CF: Comparator<Figure>>(&self, hand: &CardSetStd, trick: &Trick<Card, N>, figure_comparator: &CF) -> CardSetStd{
+        let winning = trick.winner_by(self.trump, figure_comparator).and_then(|p| trick.card_of(p));
+        let (led_suit, winning) = match (trick.led_suit(), winning){
+            (Some(led_suit), Some(winning)) => (led_suit, *winning),
+            _ => return *hand
+        };
+        let overtaking = |cards: CardSetStd| {
+            if self.must_overtake{
+                let higher = CardSetStd::from_iterator(cards.into_iter().filter(|c| c.suit() == winning.suit()
+                    && figure_comparator.compare(&c.figure(), &winning.figure()) == Ordering::Greater));
+                if !higher.is_empty(){
+                    return higher
+                }
+            }
+            cards
+        };
+        let in_led_suit = hand.only_in_suit(&led_suit);
+        if !in_led_suit.is_empty(){
+            return overtaking(in_led_suit)
+        }
+        if self.must_trump{
+            if let Some(trump) = self.trump{
+                let trumps = hand.only_in_suit(&trump);
+                if !trumps.is_empty(){
+                    return overtaking(trumps)
+                }
+            }
+        }
+        *hand
+    }
+}
+
+/// Returns cards from hand that can be legally played to trick under basic rule: follow suit if able.
+/// Equivalent of [`TrickRules::default().legal_moves(hand, trick)`](TrickRules::legal_moves).
+/// ```
+/// use karty::card_set;
+/// use karty::cards::*;
+/// use karty::trick::{legal_moves, Trick};
+/// let hand = card_set![ACE_SPADES, TWO_SPADES, FOUR_HEARTS];
+/// let mut trick = Trick::<Card, 4>::new(3);
+/// assert_eq!(legal_moves(&hand, &trick), hand);
+/// trick.add_card(KING_SPADES).unwrap();
+/// assert_eq!(legal_moves(&hand, &trick), card_set![ACE_SPADES, TWO_SPADES]);
+/// ```
+pub fn legal_moves<const N: usize>(hand: &CardSetStd, trick: &Trick<Card, N>) -> CardSetStd{
+    TrickRules::default().legal_moves(hand, trick)
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::card_set;
+    use crate::cards::*;
+    use crate::suits::Suit::{Clubs, Spades};
+    use crate::trick::{Trick, TrickRules};
+
+    #[test]
+    fn must_overtake_in_led_suit(){
+        let hand = card_set![ACE_SPADES, TWO_SPADES, JACK_SPADES, FOUR_HEARTS];
+        let mut trick = Trick::<Card, 3>::new(0);
+        trick.add_card(TEN_SPADES).unwrap();
+        let rules = TrickRules::new(None).with_must_overtake(true);
+        assert_eq!(rules.legal_moves(&hand, &trick), card_set![ACE_SPADES, JACK_SPADES]);
+        trick.add_card(KING_SPADES).unwrap();
+        assert_eq!(rules.legal_moves(&hand, &trick), card_set![ACE_SPADES]);
+    }
+
+    #[test]
+    fn trumped_trick_releases_overtaking_in_led_suit(){
+        let hand = card_set![ACE_SPADES, TWO_SPADES, FOUR_CLUBS];
+        let mut trick = Trick::<Card, 3>::new(0);
+        trick.add_card(TEN_SPADES).unwrap();
+        trick.add_card(TWO_CLUBS).unwrap();
+        let rules = TrickRules::new(Some(Clubs)).with_must_trump(true).with_must_overtake(true);
+        assert_eq!(rules.legal_moves(&hand, &trick), card_set![ACE_SPADES, TWO_SPADES]);
+    }
+
+    #[test]
+    fn void_without_trumps(){
+        let hand = card_set![ACE_HEARTS, TWO_DIAMONDS];
+        let mut trick = Trick::<Card, 4>::new(0);
+        trick.add_card(TEN_CLUBS).unwrap();
+        let rules = TrickRules::new(Some(Spades)).with_must_trump(true);
+        assert_eq!(rules.legal_moves(&hand, &trick), hand);
+    }
+}
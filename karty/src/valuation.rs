@@ -0,0 +1,168 @@
+//! Module with point values of cards. Many games assign points to cards (card points in Skat,
+//! penalty points in Hearts, high card points in bridge...), here such assignment is data:
+//! [`ValueTable`] indexed by [`CardSymbol::usize_index`], with tables for common games.
+//! # Licence:
+//! MIT: [https://mit-license.org/](https://mit-license.org/)
+//! # Authors:
+//! [morovintaas](mailto:moroviintaas@gmail.com)
+//!
+use core::marker::PhantomData;
+use crate::cards::{Card, DECK_SIZE, QUEEN_SPADES};
+use crate::figures::Figure;
+use crate::set::CardSet;
+use crate::suits::Suit;
+use crate::symbol::CardSymbol;
+
+/// Assignment of point values to cards.
+pub trait CardValuation<Crd: CardSymbol>{
+    /// Value of single card.
+    fn value(&self, card: &Crd) -> u32;
+
+    /// Sum of values of cards.
+    /// ```
+    /// use karty::cards::*;
+    /// use karty::valuation::{CardValuation, BRIDGE_HCP};
+    /// assert_eq!(BRIDGE_HCP.total([ACE_SPADES, KING_HEARTS, TWO_CLUBS]), 7);
+    /// ```
+    fn total<I: IntoIterator<Item = Crd>>(&self, cards: I) -> u32{
+        cards.into_iter().map(|c| self.value(&c)).sum()
+    }
+
+    /// Sum of values of cards in set.
+    /// ```
+    /// use karty::set::HAND_OF_SPADES;
+    /// use karty::tarot::{TarotCard, TarotCardSet};
+    /// use karty::valuation::{CardValuation, ValueTable, BRIDGE_HCP};
+    /// assert_eq!(BRIDGE_HCP.set_value(&HAND_OF_SPADES), 10);
+    /// let trumps = ValueTable::<TarotCard, 78>::new(core::array::from_fn(|i| (i >= 56 && i < 77) as u32));
+    /// let set = TarotCardSet::from_iter([TarotCard::Trump(1), TarotCard::Trump(2), TarotCard::Excuse]);
+    /// assert_eq!(trumps.set_value(&set), 2);
+    /// ```
+    fn set_value<S: CardSet<CardType = Crd>>(&self, set: &S) -> u32{
+        self.total(set.clone())
+    }
+}
+
+/// Table of card values indexed by [`CardSymbol::usize_index`] of card.
+/// ```
+/// use karty::cards::*;
+/// use karty::card_set;
+/// use karty::valuation::{CardValuation, CardValueTable};
+/// let mut table = CardValueTable::by_figure([0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
+/// assert_eq!(table.value(&KING_DIAMONDS), 3);
+/// table.set(&TEN_HEARTS, 10);
+/// assert_eq!(table.set_value(&card_set![TEN_HEARTS, TEN_SPADES, ACE_CLUBS]), 14);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueTable<Crd: CardSymbol, const N: usize>{
+    values: [u32; N],
+    phantom: PhantomData<Crd>
+}
+
+/// Value table of standard 52-card deck
+pub type CardValueTable = ValueTable<Card, DECK_SIZE>;
+
+impl<Crd: CardSymbol, const N: usize> ValueTable<Crd, N>{
+    pub const fn new(values: [u32; N]) -> Self{
+        Self{values, phantom: PhantomData}
+    }
+
+    /// Table with value changed for card of given index, usable in constant expressions.
+    pub const fn with_index(mut self, index: usize, value: u32) -> Self{
+        self.values[index] = value;
+        self
+    }
+
+    pub fn set(&mut self, card: &Crd, value: u32){
+        self.values[card.usize_index()] = value;
+    }
+
+    pub fn values(&self) -> &[u32; N]{
+        &self.values
+    }
+}
+
+impl<Crd: CardSymbol, const N: usize> CardValuation<Crd> for ValueTable<Crd, N>{
+    fn value(&self, card: &Crd) -> u32 {
+        self.values[card.usize_index()]
+    }
+}
+
+impl CardValueTable{
+    /// Table where value depends only on figure, weights indexed from two to ace.
+    pub const fn by_figure(weights: [u32; Figure::SYMBOL_SPACE]) -> Self{
+        let mut values = [0; DECK_SIZE];
+        let mut i = 0;
+        while i < DECK_SIZE{
+            values[i] = weights[i % Figure::SYMBOL_SPACE];
+            i += 1;
+        }
+        Self::new(values)
+    }
+
+    /// Table where value depends only on suit, weights indexed clubs, diamonds, hearts, spades.
+    pub const fn by_suit(weights: [u32; Suit::SYMBOL_SPACE]) -> Self{
+        let mut values = [0; DECK_SIZE];
+        let mut i = 0;
+        while i < DECK_SIZE{
+            values[i] = weights[i / Figure::SYMBOL_SPACE];
+            i += 1;
+        }
+        Self::new(values)
+    }
+}
+
+/// Card points of ace-ten games (Skat, Tysiąc, Schnapsen): ace 11, ten 10, king 4, queen 3, jack 2.
+/// ```
+/// use karty::cards::*;
+/// use karty::valuation::{CardValuation, ACE_TEN_POINTS};
+/// assert_eq!(ACE_TEN_POINTS.value(&TEN_CLUBS), 10);
+/// assert_eq!(ACE_TEN_POINTS.value(&NINE_CLUBS), 0);
+/// ```
+pub const ACE_TEN_POINTS: CardValueTable = CardValueTable::by_figure([0, 0, 0, 0, 0, 0, 0, 0, 10, 2, 3, 4, 11]);
+
+/// High card points of contract bridge: ace 4, king 3, queen 2, jack 1.
+pub const BRIDGE_HCP: CardValueTable = CardValueTable::by_figure([0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
+
+/// Counters of Pinochle: one point for every ace, ten and king.
+pub const PINOCHLE_COUNTERS: CardValueTable = CardValueTable::by_figure([0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1]);
+
+/// Penalty for taking queen of spades in Hearts
+pub const QUEEN_OF_SPADES_PENALTY: u32 = 13;
+
+/// Penalty points of Hearts: one for every heart and [`QUEEN_OF_SPADES_PENALTY`] for queen of spades.
+/// ```
+/// use karty::cards::*;
+/// use karty::valuation::{CardValuation, HEARTS_PENALTIES};
+/// assert_eq!(HEARTS_PENALTIES.value(&QUEEN_SPADES), 13);
+/// assert_eq!(HEARTS_PENALTIES.value(&TWO_HEARTS), 1);
+/// assert_eq!(HEARTS_PENALTIES.value(&QUEEN_CLUBS), 0);
+/// ```
+pub const HEARTS_PENALTIES: CardValueTable = CardValueTable::by_suit([0, 0, 1, 0])
+    .with_index(QUEEN_SPADES.usize_index(), QUEEN_OF_SPADES_PENALTY);
+
+/// Card values of blackjack: number cards by number, figures 10, ace 11.
+pub const BLACKJACK_VALUES: CardValueTable = CardValueTable::by_figure([2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 10, 10, 11]);
+
+/// Deadwood values of Gin Rummy: ace 1, number cards by number, figures 10.
+pub const DEADWOOD_VALUES: CardValueTable = CardValueTable::by_figure([2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 10, 10, 1]);
+
+#[cfg(test)]
+mod tests{
+    use crate::cards::{Card, QUEEN_SPADES};
+    use crate::set::{CardSet, CardSetStd, HAND_OF_HEARTS};
+    use crate::symbol::CardSymbol;
+    use crate::valuation::{CardValuation, ACE_TEN_POINTS, BRIDGE_HCP, HEARTS_PENALTIES, PINOCHLE_COUNTERS};
+
+    #[test]
+    fn deck_sums(){
+        let deck = CardSetStd::from_iterator(Card::iterator());
+        assert_eq!(BRIDGE_HCP.set_value(&deck), 40);
+        assert_eq!(ACE_TEN_POINTS.set_value(&deck), 120);
+        assert_eq!(PINOCHLE_COUNTERS.set_value(&deck), 12);
+        assert_eq!(HEARTS_PENALTIES.set_value(&deck), 26);
+        assert_eq!(HEARTS_PENALTIES.total(Card::iterator()), 26);
+        assert_eq!(HEARTS_PENALTIES.set_value(&HAND_OF_HEARTS), 13);
+        assert_eq!(HEARTS_PENALTIES.value(&QUEEN_SPADES), 13);
+    }
+}